@@ -0,0 +1,106 @@
+//! Criterion benchmarks comparing this crate's encode/decode against the
+//! `cobs` and `corncobs` crates, across frame sizes and zero-byte density.
+
+use cobs_codec::{decode, encode};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+/// A payload of `len` bytes where every `period`-th byte is zero (or never,
+/// when `period` is `None`), to probe sentinel-dense vs sentinel-free input.
+fn payload(len: usize, period: Option<usize>) -> Vec<u8> {
+    (0..len)
+        .map(|i| match period {
+            Some(p) if i % p == 0 => 0,
+            _ => (i % 255 + 1) as u8,
+        })
+        .collect()
+}
+
+fn bench_encode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("encode");
+    for &(name, len, period) in &[
+        ("small/sentinel_free", 16, None),
+        ("small/sentinel_dense", 16, Some(4)),
+        ("large/sentinel_free", 64 * 1024, None),
+        ("large/sentinel_dense", 64 * 1024, Some(4)),
+    ] {
+        let data = payload(len, period);
+        group.throughput(Throughput::Bytes(len as u64));
+
+        group.bench_with_input(BenchmarkId::new("cobs_codec", name), &data, |b, data| {
+            b.iter(|| {
+                let mut out = Vec::new();
+                encode(0, data, &mut out);
+                out
+            });
+        });
+
+        group.bench_with_input(BenchmarkId::new("cobs", name), &data, |b, data| {
+            b.iter(|| cobs_crate::encode_vec(data));
+        });
+
+        group.bench_with_input(BenchmarkId::new("corncobs", name), &data, |b, data| {
+            b.iter(|| {
+                let mut out = vec![0u8; corncobs::max_encoded_len(data.len())];
+                let n = corncobs::encode_buf(data, &mut out);
+                out.truncate(n);
+                out
+            });
+        });
+    }
+    group.finish();
+}
+
+fn bench_decode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode");
+    for &(name, len, period) in &[
+        ("small/sentinel_free", 16, None),
+        ("small/sentinel_dense", 16, Some(4)),
+        ("large/sentinel_free", 64 * 1024, None),
+        ("large/sentinel_dense", 64 * 1024, Some(4)),
+    ] {
+        let data = payload(len, period);
+        let mut framed = Vec::new();
+        encode(0, &data, &mut framed);
+        let without_delimiter = &framed[..framed.len() - 1];
+        let cobs_encoded = cobs_crate::encode_vec(&data);
+        let corncobs_encoded = {
+            let mut out = vec![0u8; corncobs::max_encoded_len(data.len())];
+            let n = corncobs::encode_buf(&data, &mut out);
+            out.truncate(n);
+            out
+        };
+        group.throughput(Throughput::Bytes(len as u64));
+
+        group.bench_with_input(
+            BenchmarkId::new("cobs_codec", name),
+            &without_delimiter,
+            |b, frame| {
+                b.iter(|| decode(0, frame).unwrap());
+            },
+        );
+
+        group.bench_with_input(BenchmarkId::new("cobs", name), &cobs_encoded, |b, frame| {
+            b.iter(|| {
+                let mut out = vec![0u8; frame.len()];
+                let n = cobs_crate::decode(frame, &mut out).unwrap();
+                out.truncate(n);
+                out
+            });
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("corncobs", name),
+            &corncobs_encoded,
+            |b, frame| {
+                b.iter(|| {
+                    let mut out = vec![0u8; frame.len()];
+                    corncobs::decode_buf(frame, &mut out).unwrap()
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_encode, bench_decode);
+criterion_main!(benches);
@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Arbitrary bytes on either side of the wire are attacker-controlled; the
+// decoder must reject malformed input with a `CobsError`, never panic or
+// hang, regardless of `sentinel` or `frame`.
+fuzz_target!(|input: (u8, Vec<u8>)| {
+    let (sentinel, frame) = input;
+    let _ = cobs_codec::decode(sentinel, &frame);
+});
@@ -0,0 +1,26 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Cross-checks interop with the independent `cobs` crate: a frame this
+// crate stuffs must destuff cleanly back to the original payload through
+// `cobs`, and vice versa. Comparing raw stuffed bytes or malformed-input
+// error classification isn't useful here — the two crates make different
+// (still-valid) choices for things like an empty payload's canonical form,
+// so byte-for-byte or Ok/Err agreement on arbitrary bytes isn't the
+// contract; round-tripping through the other implementation is.
+fuzz_target!(|input: (u8, Vec<u8>)| {
+    let (sentinel, payload) = input;
+
+    let mut ours = Vec::new();
+    cobs_codec::encode(sentinel, &payload, &mut ours);
+    ours.pop(); // drop the trailing delimiter; `cobs`'s frames don't carry one
+    let via_theirs = cobs_crate::decode_vec_with_sentinel(&ours, sentinel)
+        .expect("cobs must destuff a frame this crate produced");
+    assert_eq!(via_theirs, payload, "sentinel {sentinel}: cobs decoded our frame differently");
+
+    let theirs = cobs_crate::encode_vec_with_sentinel(&payload, sentinel);
+    let via_ours = cobs_codec::decode(sentinel, &theirs)
+        .expect("this crate must destuff a frame cobs produced");
+    assert_eq!(via_ours, payload, "sentinel {sentinel}: we decoded cobs's frame differently");
+});
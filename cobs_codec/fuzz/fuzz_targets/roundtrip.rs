@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// encode -> decode must be the identity for every payload and sentinel.
+fuzz_target!(|input: (u8, Vec<u8>)| {
+    let (sentinel, payload) = input;
+    let mut frame = Vec::new();
+    cobs_codec::encode(sentinel, &payload, &mut frame);
+    // `encode` appends the trailing delimiter; it isn't part of the frame
+    // content `decode` expects.
+    let decoded = cobs_codec::decode(sentinel, &frame[..frame.len() - 1])
+        .expect("a freshly encoded frame must decode");
+    assert_eq!(decoded, payload);
+});
@@ -0,0 +1,18 @@
+#![no_main]
+
+use cobs_codec::arbitrary::ChunkPattern;
+use cobs_codec::sans_io::PushDecoder;
+use libfuzzer_sys::fuzz_target;
+
+// Feeds arbitrary bytes to a PushDecoder split across an arbitrary chunk
+// pattern, so split delivery at every possible boundary gets fuzzed too, not
+// just the whole-frame-at-once case `decode_no_panic` covers.
+fuzz_target!(|input: (u8, Vec<u8>, ChunkPattern)| {
+    let (sentinel, bytes, pattern) = input;
+    let mut decoder = PushDecoder::with_sentinel(sentinel);
+    for chunk in pattern.split(&bytes) {
+        for result in decoder.push(chunk) {
+            let _ = result;
+        }
+    }
+});
@@ -0,0 +1,57 @@
+//! `arbitrary::Arbitrary` implementations for fuzzing, behind the
+//! `arbitrary` feature, so downstream fuzzers (and this crate's own `fuzz/`
+//! targets) can generate realistic sentinels, frame configurations, and
+//! split-delivery patterns straight from an `Unstructured`, instead of
+//! hand-rolling the same boilerplate per fuzz target.
+
+use crate::{Decoder, Encoder};
+use alloc::vec::Vec;
+use arbitrary::{Arbitrary, Result, Unstructured};
+use core::num::NonZeroU8;
+
+impl<'a> Arbitrary<'a> for Encoder {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut encoder = Encoder::with_sentinel(u.arbitrary()?)
+            .with_delimiter(u.arbitrary()?)
+            .with_leading_delimiter(u.arbitrary()?);
+        if let Some(max_block) = u.arbitrary::<Option<NonZeroU8>>()? {
+            encoder = encoder.with_max_block(max_block.get().max(2));
+        }
+        Ok(encoder)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Decoder {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut decoder = Decoder::with_sentinel(u.arbitrary()?).with_strict(u.arbitrary()?);
+        if let Some(max_block) = u.arbitrary::<Option<NonZeroU8>>()? {
+            decoder = decoder.with_max_block(max_block.get().max(2));
+        }
+        Ok(decoder)
+    }
+}
+
+/// A sequence of chunk sizes for splitting an encoded frame across multiple
+/// `PushDecoder::push` (or `AsyncRead`) calls, so a fuzz target can exercise
+/// split delivery without hand-writing chunking logic.
+#[derive(Debug, Clone, Arbitrary)]
+pub struct ChunkPattern(Vec<NonZeroU8>);
+
+impl ChunkPattern {
+    /// Split `data` into pieces whose lengths cycle through this pattern,
+    /// falling back to one byte at a time if the pattern is empty.
+    pub fn split<'a>(&self, data: &'a [u8]) -> Vec<&'a [u8]> {
+        let mut chunks = Vec::new();
+        let mut rest = data;
+        let mut i = 0;
+        while !rest.is_empty() {
+            let size = self.0.get(i % self.0.len().max(1)).map_or(1, |n| n.get() as usize);
+            let take = size.min(rest.len());
+            let (chunk, tail) = rest.split_at(take);
+            chunks.push(chunk);
+            rest = tail;
+            i += 1;
+        }
+        chunks
+    }
+}
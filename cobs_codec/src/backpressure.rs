@@ -0,0 +1,86 @@
+//! A [`Sink`] wrapper that bounds how many encoded-but-unflushed bytes a slow
+//! writer is allowed to accumulate.
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_util::Sink;
+
+/// Wraps an inner `Sink<Vec<u8>>`, tracking the total length of items pushed
+/// through [`Sink::start_send`] that haven't been confirmed flushed yet.
+/// Once that total reaches `high_water_mark`, [`Sink::poll_ready`] reports
+/// [`Poll::Pending`] (after trying to flush the inner sink to make room)
+/// instead of accepting another item, so a writer that's fallen behind
+/// applies backpressure instead of letting the inner sink's own buffer grow
+/// without bound.
+pub struct BoundedSink<S> {
+    inner: S,
+    high_water_mark: usize,
+    pending_bytes: usize,
+}
+
+impl<S> BoundedSink<S> {
+    /// Wrap `inner`, capping unflushed bytes at `high_water_mark`.
+    pub fn new(inner: S, high_water_mark: usize) -> Self {
+        Self { inner, high_water_mark, pending_bytes: 0 }
+    }
+
+    /// Bytes handed to [`Sink::start_send`] since the last successful flush.
+    pub const fn pending_bytes(&self) -> usize {
+        self.pending_bytes
+    }
+
+    /// Consume the wrapper, returning the inner sink. Any unflushed bytes
+    /// are the inner sink's problem from here on.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    /// A reference to the inner sink.
+    pub const fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    /// A mutable reference to the inner sink.
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.inner
+    }
+}
+
+impl<S: Sink<Vec<u8>> + Unpin> Sink<Vec<u8>> for BoundedSink<S> {
+    type Error = S::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.get_mut();
+        if this.pending_bytes >= this.high_water_mark {
+            match Pin::new(&mut this.inner).poll_flush(cx) {
+                Poll::Ready(Ok(())) => this.pending_bytes = 0,
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => return Poll::Pending,
+            }
+            if this.pending_bytes >= this.high_water_mark {
+                return Poll::Pending;
+            }
+        }
+        Pin::new(&mut this.inner).poll_ready(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Vec<u8>) -> Result<(), Self::Error> {
+        let this = self.get_mut();
+        this.pending_bytes += item.len();
+        Pin::new(&mut this.inner).start_send(item)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.get_mut();
+        let result = Pin::new(&mut this.inner).poll_flush(cx);
+        if let Poll::Ready(Ok(())) = result {
+            this.pending_bytes = 0;
+        }
+        result
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self.get_mut().inner).poll_close(cx)
+    }
+}
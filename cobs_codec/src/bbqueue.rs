@@ -0,0 +1,79 @@
+//! A decoder for frames read out of a [`bbqueue`] ring buffer, for UART DMA
+//! pipelines that hand over one contiguous grant at a time instead of a
+//! single flat buffer. A frame that straddles the ring buffer's wrap point
+//! spans two grants; [`GrantDecoder`] carries the in-progress payload across
+//! that boundary instead of requiring the caller to copy both slices into one
+//! contiguous buffer first.
+
+use alloc::vec::Vec;
+use bbqueue::prod_cons::stream::StreamConsumer;
+use bbqueue::traits::bbqhdl::BbqHandle;
+use bbqueue::traits::coordination::ReadGrantError;
+
+use crate::{CobsError, Decoder};
+
+/// Byte-stream COBS decoder driven by a [`bbqueue::prod_cons::stream::StreamConsumer`]'s
+/// read grants rather than a byte slice or `BytesMut`. Unlike [`crate::sans_io::PushDecoder`]
+/// it consumes a whole grant at a time and releases exactly the bytes it
+/// decoded, instead of being fed one byte at a time.
+#[derive(Debug, Clone)]
+pub struct GrantDecoder {
+    sentinel: u8,
+    partial: Vec<u8>,
+}
+
+impl GrantDecoder {
+    /// Construct a grant decoder that splits on the given runtime `sentinel`.
+    pub const fn with_sentinel(sentinel: u8) -> Self {
+        Self {
+            sentinel,
+            partial: Vec::new(),
+        }
+    }
+
+    /// Number of bytes buffered for the frame currently in progress, carried
+    /// over from a prior grant that ended mid-frame.
+    pub fn pending_len(&self) -> usize {
+        self.partial.len()
+    }
+
+    /// Read the consumer's next available grant and decode every complete
+    /// frame in it, releasing the whole grant and carrying any trailing
+    /// partial frame over to the next call. Returns the decoded frames (or
+    /// the error from decoding each one) in order.
+    ///
+    /// Since a single grant never spans the ring buffer's wrap point, a frame
+    /// that straddles it arrives as two grants across two calls; the second
+    /// call's payload is prefixed with [`GrantDecoder::pending_len`] bytes
+    /// carried over from the first.
+    pub fn decode_next_grant<Q>(
+        &mut self,
+        consumer: &StreamConsumer<Q>,
+    ) -> Result<Vec<Result<Vec<u8>, CobsError>>, ReadGrantError>
+    where
+        Q: BbqHandle,
+    {
+        let grant = consumer.read()?;
+        let mut frames = Vec::new();
+        let mut start = 0;
+
+        for (i, &b) in grant.iter().enumerate() {
+            if b == self.sentinel {
+                let frame = if self.partial.is_empty() {
+                    Decoder::with_sentinel(self.sentinel).decode_frame(&grant[start..i])
+                } else {
+                    self.partial.extend_from_slice(&grant[start..i]);
+                    let frame = core::mem::take(&mut self.partial);
+                    Decoder::with_sentinel(self.sentinel).decode_frame(&frame)
+                };
+                frames.push(frame);
+                start = i + 1;
+            }
+        }
+        self.partial.extend_from_slice(&grant[start..]);
+
+        let len = grant.len();
+        grant.release(len);
+        Ok(frames)
+    }
+}
@@ -0,0 +1,93 @@
+//! Drop-in shims matching the [`cobs`](https://docs.rs/cobs) and
+//! [`corncobs`](https://docs.rs/corncobs) crates' own function names,
+//! signatures, and error types, fixed to this crate's default sentinel of
+//! `0`. A project already calling either can switch its `use` line over to
+//! [`compat::cobs`](cobs) or [`compat::corncobs`](corncobs) without
+//! rewriting call sites; anything needing a runtime-selectable sentinel or
+//! this crate's own richer [`CobsError`](crate::CobsError) should use the
+//! crate root instead.
+
+/// Matches the [`cobs`](https://docs.rs/cobs) crate's top-level functions.
+/// Frames here never carry a trailing delimiter, same as upstream: callers
+/// add their own framing on top.
+pub mod cobs {
+    use alloc::vec::Vec;
+
+    /// See [`crate::max_encoded_len`].
+    pub const fn max_encoding_length(source_len: usize) -> usize {
+        crate::max_encoded_len(source_len)
+    }
+
+    /// Stuff `frame` into `buf` and return the number of bytes written.
+    /// Panics if `buf` is too small, same as upstream.
+    pub fn encode(frame: &[u8], buf: &mut [u8]) -> usize {
+        let mut dst = Vec::new();
+        crate::Encoder::with_sentinel(0)
+            .with_delimiter(false)
+            .encode_frame_into(frame, &mut dst);
+        buf[..dst.len()].copy_from_slice(&dst);
+        dst.len()
+    }
+
+    /// Destuff `frame` into `buf` and return the number of bytes written, or
+    /// `Err(())` on malformed input or an undersized `buf`, same as
+    /// upstream's unit-error `Result`.
+    #[allow(clippy::result_unit_err)]
+    pub fn decode(frame: &[u8], buf: &mut [u8]) -> Result<usize, ()> {
+        crate::decode_to_slice(0, frame, buf).map_err(|_| ())
+    }
+
+    /// Stuff `frame` into a freshly allocated `Vec`.
+    pub fn encode_vec(frame: &[u8]) -> Vec<u8> {
+        let mut dst = Vec::new();
+        crate::Encoder::with_sentinel(0)
+            .with_delimiter(false)
+            .encode_frame_into(frame, &mut dst);
+        dst
+    }
+
+    /// Destuff `frame` into a freshly allocated `Vec`.
+    #[allow(clippy::result_unit_err)]
+    pub fn decode_vec(frame: &[u8]) -> Result<Vec<u8>, ()> {
+        crate::decode(0, frame).map_err(|_| ())
+    }
+}
+
+/// Matches the [`corncobs`](https://docs.rs/corncobs) crate's top-level
+/// functions. Unlike [`cobs`], frames here include the trailing `0`
+/// delimiter on both sides, same as upstream.
+pub mod corncobs {
+    /// The fixed frame delimiter, named to match upstream.
+    pub const ZERO: u8 = 0;
+
+    /// See [`crate::max_encoded_len`].
+    pub const fn max_encoded_len(raw_len: usize) -> usize {
+        crate::max_encoded_len(raw_len) + 1
+    }
+
+    /// Why [`decode_buf`] failed, matching upstream's two variants.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum CobsError {
+        /// `input` ran out before a delimiter was found.
+        Truncated,
+        /// `input` ended on a delimiter but didn't destuff cleanly.
+        Corrupt,
+    }
+
+    /// Stuff `input` into `out`, terminated with [`ZERO`], and return the
+    /// total number of bytes written. Panics if `out` is too small, same as
+    /// upstream.
+    pub fn encode_buf(input: &[u8], out: &mut [u8]) -> usize {
+        crate::encode_to_slice(ZERO, input, out).expect("destination buffer too small")
+    }
+
+    /// Destuff a [`ZERO`]-terminated `input` into `out` and return the
+    /// decoded length, excluding the delimiter.
+    pub fn decode_buf(input: &[u8], out: &mut [u8]) -> Result<usize, CobsError> {
+        let (&delimiter, frame) = input.split_last().ok_or(CobsError::Truncated)?;
+        if delimiter != ZERO {
+            return Err(CobsError::Truncated);
+        }
+        crate::decode_to_slice(ZERO, frame, out).map_err(|_| CobsError::Corrupt)
+    }
+}
@@ -0,0 +1,96 @@
+//! Compile-time COBS frame encoding, for baking fixed protocol messages
+//! (handshakes, pings) into flash as a `&'static [u8]` with zero runtime
+//! cost. [`cobs_frame!`] is the entry point; [`const_frame_len`] and
+//! [`const_encode_frame`] are its building blocks, exposed for callers
+//! assembling the array size themselves in a larger `const` context.
+//!
+//! Mirrors [`Encoder`](crate::Encoder)'s default-`max_block` grouping exactly,
+//! just written with `while` loops and array indexing instead of `Vec`
+//! pushes, since neither is usable in a `const fn`.
+
+use crate::DEFAULT_MAX_BLOCK;
+
+/// Exact length (payload plus delimiter, without a leading delimiter) that
+/// [`const_encode_frame`] produces for `payload`. The `const fn` counterpart
+/// of [`crate::encoded_len`] plus one, so [`cobs_frame!`] can size the array
+/// it bakes the encoding into ahead of calling [`const_encode_frame`].
+pub const fn const_frame_len(payload: &[u8]) -> usize {
+    let mut groups = 1usize;
+    let mut nonzero = 0usize;
+    let mut run = 0usize;
+    let mut i = 0;
+    while i < payload.len() {
+        if payload[i] == 0 {
+            groups += 1;
+            run = 0;
+        } else {
+            nonzero += 1;
+            run += 1;
+            if run == DEFAULT_MAX_BLOCK as usize - 1 {
+                groups += 1;
+                run = 0;
+            }
+        }
+        i += 1;
+    }
+    nonzero + groups + 1
+}
+
+/// Stuff `payload` into a `LEN`-byte array XORed with `sentinel` and
+/// terminated with the delimiter. `LEN` must equal
+/// [`const_frame_len`]`(payload)`; [`cobs_frame!`] always gets this right,
+/// but a caller building the array size by hand gets a compile-time panic
+/// instead of a truncated or zero-padded frame on a mismatch.
+pub const fn const_encode_frame<const LEN: usize>(sentinel: u8, payload: &[u8]) -> [u8; LEN] {
+    assert!(LEN == const_frame_len(payload), "LEN does not match cobs_codec::const_frame_len(payload)");
+
+    let mut out = [0u8; LEN];
+    let mut code_idx = 0;
+    let mut out_pos = 1;
+    let mut code = 1u8;
+    let mut i = 0;
+    while i < payload.len() {
+        if payload[i] == 0 {
+            out[code_idx] = code ^ sentinel;
+            code_idx = out_pos;
+            out_pos += 1;
+            code = 1;
+        } else {
+            out[out_pos] = payload[i] ^ sentinel;
+            out_pos += 1;
+            code += 1;
+            if code == DEFAULT_MAX_BLOCK {
+                out[code_idx] = code ^ sentinel;
+                code_idx = out_pos;
+                out_pos += 1;
+                code = 1;
+            }
+        }
+        i += 1;
+    }
+    out[code_idx] = code ^ sentinel;
+    out[LEN - 1] = sentinel;
+    out
+}
+
+/// Stuff a byte string into a terminated COBS frame at compile time,
+/// evaluating to a `&'static [u8]` with no runtime encoding cost — for fixed
+/// protocol messages (handshakes, pings) that would otherwise get
+/// re-encoded from the same bytes on every send.
+///
+/// ```
+/// use cobs_codec::cobs_frame;
+///
+/// const PING: &[u8] = cobs_frame!(0, b"ping");
+/// assert_eq!(cobs_codec::decode(0, &PING[..PING.len() - 1]).unwrap(), b"ping");
+/// ```
+#[macro_export]
+macro_rules! cobs_frame {
+    ($sentinel:expr, $payload:expr) => {{
+        const __COBS_FRAME_PAYLOAD: &[u8] = $payload;
+        const __COBS_FRAME_LEN: usize = $crate::const_frame::const_frame_len(__COBS_FRAME_PAYLOAD);
+        const __COBS_FRAME: [u8; __COBS_FRAME_LEN] =
+            $crate::const_frame::const_encode_frame::<__COBS_FRAME_LEN>($sentinel, __COBS_FRAME_PAYLOAD);
+        &__COBS_FRAME as &'static [u8]
+    }};
+}
@@ -0,0 +1,93 @@
+//! A COBS framing layer that also appends and verifies a CRC, so serial
+//! protocols built on COBS don't each reimplement their own checksum.
+//!
+//! The CRC is computed over the plain-domain payload, appended to it in
+//! little-endian order, and the combined buffer is COBS-stuffed as usual.
+//! Decoding destuffs first, then recomputes the CRC over everything but the
+//! trailing checksum bytes and compares it, returning
+//! [`CobsError::CrcMismatch`] on a mismatch.
+
+use alloc::vec::Vec;
+use crc::{Algorithm, Crc};
+
+use crate::{CobsError, Decoder, Encoder};
+
+/// Which CRC width to append before COBS-stuffing the frame, and the
+/// polynomial (as a `crc` crate [`Algorithm`]) to compute it with.
+#[derive(Clone, Copy)]
+pub enum CrcWidth {
+    /// A 16-bit CRC, appended as 2 little-endian bytes.
+    Crc16(&'static Algorithm<u16>),
+    /// A 32-bit CRC, appended as 4 little-endian bytes.
+    Crc32(&'static Algorithm<u32>),
+}
+
+impl CrcWidth {
+    const fn len(self) -> usize {
+        match self {
+            CrcWidth::Crc16(_) => 2,
+            CrcWidth::Crc32(_) => 4,
+        }
+    }
+}
+
+/// Wraps [`Encoder`]/[`Decoder`] to append and verify a CRC ahead of COBS
+/// stuffing, using a caller-chosen polynomial via the `crc` crate.
+#[derive(Clone, Copy)]
+pub struct CrcCobsCodec {
+    sentinel: u8,
+    width: CrcWidth,
+}
+
+impl CrcCobsCodec {
+    /// Construct a codec that frames on `sentinel` and protects each payload
+    /// with the CRC described by `width`.
+    pub const fn new(sentinel: u8, width: CrcWidth) -> Self {
+        Self { sentinel, width }
+    }
+
+    /// Append `data`'s CRC, COBS-stuff the combined buffer, and push it
+    /// (plus delimiter) onto `dst`.
+    pub fn encode_frame(&self, data: &[u8], dst: &mut Vec<u8>) {
+        let mut checksummed = Vec::with_capacity(data.len() + self.width.len());
+        checksummed.extend_from_slice(data);
+        match self.width {
+            CrcWidth::Crc16(alg) => {
+                let crc = Crc::<u16>::new(alg).checksum(data);
+                checksummed.extend_from_slice(&crc.to_le_bytes());
+            }
+            CrcWidth::Crc32(alg) => {
+                let crc = Crc::<u32>::new(alg).checksum(data);
+                checksummed.extend_from_slice(&crc.to_le_bytes());
+            }
+        }
+        Encoder::with_sentinel(self.sentinel).encode_frame_into(&checksummed, dst);
+    }
+
+    /// Destuff `frame`, split off its trailing CRC, and verify it against the
+    /// remaining payload, returning the payload on success.
+    pub fn decode_frame(&self, frame: &[u8]) -> Result<Vec<u8>, CobsError> {
+        let checksummed = Decoder::with_sentinel(self.sentinel).decode_frame(frame)?;
+        let crc_len = self.width.len();
+        if checksummed.len() < crc_len {
+            return Err(CobsError::TruncatedFrame {
+                offset: checksummed.len(),
+            });
+        }
+        let (data, crc_bytes) = checksummed.split_at(checksummed.len() - crc_len);
+        let matches = match self.width {
+            CrcWidth::Crc16(alg) => {
+                let expected = u16::from_le_bytes([crc_bytes[0], crc_bytes[1]]);
+                Crc::<u16>::new(alg).checksum(data) == expected
+            }
+            CrcWidth::Crc32(alg) => {
+                let expected = u32::from_le_bytes([crc_bytes[0], crc_bytes[1], crc_bytes[2], crc_bytes[3]]);
+                Crc::<u32>::new(alg).checksum(data) == expected
+            }
+        };
+        if !matches {
+            return Err(CobsError::CrcMismatch);
+        }
+        Ok(data.to_vec())
+    }
+}
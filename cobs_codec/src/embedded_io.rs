@@ -0,0 +1,86 @@
+//! Blocking adapters over `embedded_io::{Read, Write}`, for embedded HALs
+//! (UARTs) that have neither `bytes` nor an async executor.
+
+use alloc::vec::Vec;
+
+use embedded_io::{Read, Write};
+
+use crate::sans_io::PushDecoder;
+use crate::stream::StreamEncoder;
+use crate::EmbeddedIoError;
+
+/// Wraps an inner [`Read`], destuffing complete frames out of the bytes it
+/// produces.
+pub struct CobsEmbeddedReader<R> {
+    inner: R,
+    decoder: PushDecoder,
+}
+
+impl<R: Read> CobsEmbeddedReader<R> {
+    /// Wrap `inner`, framing on the given runtime `sentinel`.
+    pub fn new(sentinel: u8, inner: R) -> Self {
+        Self {
+            inner,
+            decoder: PushDecoder::with_sentinel(sentinel),
+        }
+    }
+
+    /// Pull bytes from the inner reader, appending the next decoded frame's
+    /// payload to `buf`. Returns `Ok(true)` once a frame was appended, or
+    /// `Ok(false)` if the inner reader hit EOF before completing one.
+    pub fn read_frame(&mut self, buf: &mut Vec<u8>) -> Result<bool, EmbeddedIoError<R::Error>> {
+        let mut byte = [0u8; 1];
+        loop {
+            if self.inner.read(&mut byte).map_err(EmbeddedIoError::Io)? == 0 {
+                return Ok(false);
+            }
+            if let Some(frame) = self.decoder.feed(byte[0]) {
+                let frame = frame.map_err(EmbeddedIoError::Cobs)?;
+                buf.extend_from_slice(&frame);
+                return Ok(true);
+            }
+        }
+    }
+
+    /// Consume the reader, returning the inner one. Any partially-received
+    /// frame is discarded.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+/// Wraps an inner [`Write`], COBS-encoding whole frames handed to
+/// [`CobsEmbeddedWriter::write_frame`] and forwarding the stuffed output.
+pub struct CobsEmbeddedWriter<W> {
+    inner: W,
+    encoder: StreamEncoder,
+    scratch: Vec<u8>,
+}
+
+impl<W: Write> CobsEmbeddedWriter<W> {
+    /// Wrap `inner`, framing on the given runtime `sentinel`.
+    pub fn new(sentinel: u8, inner: W) -> Self {
+        Self {
+            inner,
+            encoder: StreamEncoder::with_sentinel(sentinel),
+            scratch: Vec::new(),
+        }
+    }
+
+    /// Stuff `data` into a single terminated frame and write it to the inner
+    /// writer.
+    pub fn write_frame(&mut self, data: &[u8]) -> Result<(), EmbeddedIoError<W::Error>> {
+        self.scratch.clear();
+        self.encoder.start_frame();
+        self.encoder.write(data, &mut self.scratch);
+        self.encoder.finish(&mut self.scratch);
+        self.inner
+            .write_all(&self.scratch)
+            .map_err(EmbeddedIoError::Io)
+    }
+
+    /// Consume the writer, returning the inner one.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
@@ -0,0 +1,179 @@
+//! Async adapters over `embedded_io_async::{Read, Write}`, for embedded
+//! executors (embassy) that have neither `bytes` nor `futures_io`.
+//!
+//! With the `embedded-hal-async` feature, [`CobsEmbeddedAsyncFixedReader`]
+//! and [`CobsEmbeddedAsyncFixedWriter`] additionally cover firmware with no
+//! allocator at all, buffering each frame in a fixed-capacity
+//! `heapless::Vec` instead. (As of embedded-hal-async 1.0 its own `serial`
+//! module no longer exists — those UART traits moved into
+//! `embedded-io-async`, which is exactly what [`Read`]/[`Write`] here
+//! already are.)
+
+use alloc::vec::Vec;
+
+use embedded_io_async::{Read, Write};
+
+use crate::sans_io::PushDecoder;
+use crate::stream::StreamEncoder;
+use crate::EmbeddedIoError;
+
+/// Wraps an inner [`Read`], destuffing complete frames out of the bytes it
+/// produces.
+pub struct CobsEmbeddedAsyncReader<R> {
+    inner: R,
+    decoder: PushDecoder,
+}
+
+impl<R: Read> CobsEmbeddedAsyncReader<R> {
+    /// Wrap `inner`, framing on the given runtime `sentinel`.
+    pub fn new(sentinel: u8, inner: R) -> Self {
+        Self {
+            inner,
+            decoder: PushDecoder::with_sentinel(sentinel),
+        }
+    }
+
+    /// Pull bytes from the inner reader, appending the next decoded frame's
+    /// payload to `buf`. Returns `Ok(true)` once a frame was appended, or
+    /// `Ok(false)` if the inner reader hit EOF before completing one.
+    pub async fn read_frame(
+        &mut self,
+        buf: &mut Vec<u8>,
+    ) -> Result<bool, EmbeddedIoError<R::Error>> {
+        let mut byte = [0u8; 1];
+        loop {
+            if self.inner.read(&mut byte).await.map_err(EmbeddedIoError::Io)? == 0 {
+                return Ok(false);
+            }
+            if let Some(frame) = self.decoder.feed(byte[0]) {
+                let frame = frame.map_err(EmbeddedIoError::Cobs)?;
+                buf.extend_from_slice(&frame);
+                return Ok(true);
+            }
+        }
+    }
+
+    /// Consume the reader, returning the inner one. Any partially-received
+    /// frame is discarded.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+/// Wraps an inner [`Write`], COBS-encoding whole frames handed to
+/// [`CobsEmbeddedAsyncWriter::write_frame`] and forwarding the stuffed
+/// output.
+pub struct CobsEmbeddedAsyncWriter<W> {
+    inner: W,
+    encoder: StreamEncoder,
+    scratch: Vec<u8>,
+}
+
+impl<W: Write> CobsEmbeddedAsyncWriter<W> {
+    /// Wrap `inner`, framing on the given runtime `sentinel`.
+    pub fn new(sentinel: u8, inner: W) -> Self {
+        Self {
+            inner,
+            encoder: StreamEncoder::with_sentinel(sentinel),
+            scratch: Vec::new(),
+        }
+    }
+
+    /// Stuff `data` into a single terminated frame and write it to the inner
+    /// writer.
+    pub async fn write_frame(&mut self, data: &[u8]) -> Result<(), EmbeddedIoError<W::Error>> {
+        self.scratch.clear();
+        self.encoder.start_frame();
+        self.encoder.write(data, &mut self.scratch);
+        self.encoder.finish(&mut self.scratch);
+        self.inner
+            .write_all(&self.scratch)
+            .await
+            .map_err(EmbeddedIoError::Io)
+    }
+
+    /// Consume the writer, returning the inner one.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+#[cfg(feature = "embedded-hal-async")]
+use crate::heapless::{encode_heapless, PushDecoder as FixedPushDecoder};
+#[cfg(feature = "embedded-hal-async")]
+use crate::FixedFrameError;
+
+/// Like [`CobsEmbeddedAsyncReader`], but destuffs into a fixed-capacity
+/// `heapless::Vec<u8, N>` instead of growing an `alloc::vec::Vec`, for
+/// firmware with an embedded-io-async UART but no allocator at all.
+#[cfg(feature = "embedded-hal-async")]
+pub struct CobsEmbeddedAsyncFixedReader<R, const N: usize> {
+    inner: R,
+    decoder: FixedPushDecoder<N>,
+}
+
+#[cfg(feature = "embedded-hal-async")]
+impl<R: Read, const N: usize> CobsEmbeddedAsyncFixedReader<R, N> {
+    /// Wrap `inner`, framing on the given runtime `sentinel`.
+    pub fn new(sentinel: u8, inner: R) -> Self {
+        Self {
+            inner,
+            decoder: FixedPushDecoder::with_sentinel(sentinel),
+        }
+    }
+
+    /// Pull bytes from the inner reader, returning the next decoded frame's
+    /// payload, or `Ok(None)` if the inner reader hit EOF before completing
+    /// one. A frame longer than `N` bytes fails with
+    /// [`FixedFrameError::BufferTooSmall`] as soon as it overflows.
+    pub async fn read_frame(&mut self) -> Result<Option<heapless::Vec<u8, N>>, FixedFrameError<R::Error>> {
+        let mut byte = [0u8; 1];
+        loop {
+            if self.inner.read(&mut byte).await.map_err(FixedFrameError::Io)? == 0 {
+                return Ok(None);
+            }
+            if let Some(frame) = self.decoder.feed(byte[0]) {
+                return frame.map(Some).map_err(FixedFrameError::from);
+            }
+        }
+    }
+
+    /// Consume the reader, returning the inner one. Any partially-received
+    /// frame is discarded.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+/// Like [`CobsEmbeddedAsyncWriter`], but stuffs each frame into a
+/// fixed-capacity `heapless::Vec<u8, N>` instead of growing an
+/// `alloc::vec::Vec`, rejecting an oversized frame instead of allocating
+/// around it.
+#[cfg(feature = "embedded-hal-async")]
+pub struct CobsEmbeddedAsyncFixedWriter<W, const N: usize> {
+    inner: W,
+    sentinel: u8,
+}
+
+#[cfg(feature = "embedded-hal-async")]
+impl<W: Write, const N: usize> CobsEmbeddedAsyncFixedWriter<W, N> {
+    /// Wrap `inner`, framing on the given runtime `sentinel`.
+    pub fn new(sentinel: u8, inner: W) -> Self {
+        Self { inner, sentinel }
+    }
+
+    /// Stuff `data` into a single terminated frame of at most `N` bytes and
+    /// write it to the inner writer.
+    pub async fn write_frame(&mut self, data: &[u8]) -> Result<(), FixedFrameError<W::Error>> {
+        let frame = encode_heapless::<N>(self.sentinel, data).map_err(|_| FixedFrameError::BufferTooSmall)?;
+        self.inner
+            .write_all(frame.as_slice())
+            .await
+            .map_err(FixedFrameError::Io)
+    }
+
+    /// Consume the writer, returning the inner one.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
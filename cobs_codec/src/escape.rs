@@ -0,0 +1,118 @@
+//! A COBS/escape hybrid for links where more than one byte value is
+//! forbidden on the wire — for example software flow control, which
+//! reserves XON (`0x11`) and XOFF (`0x13`) in addition to the usual COBS
+//! delimiter. [`EscapedEncoder`]/[`EscapedDecoder`] COBS-stuff out the
+//! delimiter as usual via [`crate::Encoder`]/[`crate::Decoder`], then
+//! byte-stuff any other reserved value (and the escape byte itself) with a
+//! 2-byte escape sequence, SLIP-style: `byte` becomes `[escape, byte ^
+//! XOR_MASK]`.
+//!
+//! `XOR_MASK` (`0x20`) assumes every additional reserved byte and the escape
+//! byte itself are low ASCII control codes (below `0x20`), which covers
+//! XON/XOFF and similar software flow control bytes; the masked form then
+//! always lands at or above `0x20`, so it can never collide with an
+//! unescaped reserved byte.
+
+use crate::{CobsError, Decoder, Encoder};
+use alloc::vec::Vec;
+
+const XOR_MASK: u8 = 0x20;
+
+/// COBS-stuffs a frame, then escapes any additional reserved byte (or the
+/// escape byte itself) that survives stuffing.
+#[derive(Debug, Clone)]
+pub struct EscapedEncoder {
+    encoder: Encoder,
+    escape: u8,
+    reserved_mask: u32,
+}
+
+impl EscapedEncoder {
+    /// Construct an encoder that frames on `sentinel` via COBS, and escapes
+    /// `escape` and every byte in `reserved` wherever they'd otherwise
+    /// appear in the stuffed body.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `escape` or any byte in `reserved` is `0x20` or greater.
+    pub fn new(sentinel: u8, escape: u8, reserved: &[u8]) -> Self {
+        assert!(escape < XOR_MASK, "escape byte must be below 0x20");
+        let mut reserved_mask = 0u32;
+        for &r in reserved {
+            assert!(r < XOR_MASK, "reserved bytes must be below 0x20");
+            reserved_mask |= 1 << r;
+        }
+        Self {
+            encoder: Encoder::with_sentinel(sentinel),
+            escape,
+            reserved_mask,
+        }
+    }
+
+    fn needs_escape(&self, b: u8) -> bool {
+        b == self.escape || (b < XOR_MASK && self.reserved_mask & (1 << b) != 0)
+    }
+
+    /// Stuff `data`, escape the reserved bytes out of the result, and append
+    /// a single terminated frame to `dst`.
+    pub fn encode_frame_into(&self, data: &[u8], dst: &mut Vec<u8>) {
+        let mut stuffed = Vec::new();
+        self.encoder.encode_frame_into(data, &mut stuffed);
+        // The trailing delimiter is the literal sentinel, not part of the
+        // escaped body, so it's copied through untouched.
+        let (body, delimiter) = stuffed.split_at(stuffed.len() - 1);
+        for &b in body {
+            if self.needs_escape(b) {
+                dst.push(self.escape);
+                dst.push(b ^ XOR_MASK);
+            } else {
+                dst.push(b);
+            }
+        }
+        dst.extend_from_slice(delimiter);
+    }
+}
+
+/// Reverses [`EscapedEncoder`]'s escaping, then COBS-destuffs the result via
+/// [`crate::Decoder`].
+#[derive(Debug, Clone)]
+pub struct EscapedDecoder {
+    decoder: Decoder,
+    escape: u8,
+}
+
+impl EscapedDecoder {
+    /// Construct a decoder that splits on `sentinel` via COBS, after
+    /// reversing `escape`-prefixed escape sequences.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `escape` is `0x20` or greater.
+    pub const fn new(sentinel: u8, escape: u8) -> Self {
+        assert!(escape < XOR_MASK, "escape byte must be below 0x20");
+        Self {
+            decoder: Decoder::with_sentinel(sentinel),
+            escape,
+        }
+    }
+
+    /// Reverse the escaping on `frame` (without its trailing delimiter), then
+    /// destuff the result.
+    pub fn decode_frame(&self, frame: &[u8]) -> Result<Vec<u8>, CobsError> {
+        let mut unescaped = Vec::with_capacity(frame.len());
+        let mut i = 0;
+        while i < frame.len() {
+            if frame[i] == self.escape {
+                let Some(&escaped) = frame.get(i + 1) else {
+                    return Err(CobsError::TruncatedFrame { offset: frame.len() });
+                };
+                unescaped.push(escaped ^ XOR_MASK);
+                i += 2;
+            } else {
+                unescaped.push(frame[i]);
+                i += 1;
+            }
+        }
+        self.decoder.decode_frame(&unescaped)
+    }
+}
@@ -0,0 +1,121 @@
+//! A C ABI for host tools and firmware that can't link this crate directly.
+//! Build the shared library with
+//! `cargo rustc --features ffi --crate-type cdylib` and generate a header
+//! with `cbindgen` (see `cbindgen.toml`) so C/C++ callers share the exact
+//! same stuffing/destuffing implementation used by the Rust side of this
+//! repo's interop tests.
+//!
+//! [`cobs_encode`]/[`cobs_decode`] mirror the allocation-free
+//! [`crate::encode_to_slice`]/[`crate::decode_to_slice`] primitives for
+//! one-shot framing. [`CobsDecoder`] wraps [`crate::sans_io::PushDecoder`]
+//! behind an opaque handle for callers (interrupt-driven UART RX handlers,
+//! incrementally-filled host buffers) that can only hand over one byte or
+//! chunk at a time.
+
+use crate::sans_io::PushDecoder;
+use crate::{decode_to_slice, encode_to_slice};
+use alloc::boxed::Box;
+use core::slice;
+
+/// Stuff `src_len` bytes at `src` and write a single terminated frame into
+/// `dst` (capacity `dst_len`), without allocating. Returns the number of
+/// bytes written, or `-1` if `dst` isn't big enough.
+///
+/// # Safety
+///
+/// `src` must be valid for reads of `src_len` bytes and `dst` for writes of
+/// `dst_len` bytes; either pointer may be null only when its paired length
+/// is `0`.
+#[no_mangle]
+pub unsafe extern "C" fn cobs_encode(
+    sentinel: u8,
+    src: *const u8,
+    src_len: usize,
+    dst: *mut u8,
+    dst_len: usize,
+) -> isize {
+    let src = if src_len == 0 { &[] } else { slice::from_raw_parts(src, src_len) };
+    let dst = if dst_len == 0 { &mut [] } else { slice::from_raw_parts_mut(dst, dst_len) };
+    match encode_to_slice(sentinel, src, dst) {
+        Ok(written) => written as isize,
+        Err(_) => -1,
+    }
+}
+
+/// Destuff a single frame's content (without the trailing delimiter) at
+/// `frame`/`frame_len` into `dst` (capacity `dst_len`), without allocating.
+/// Returns the number of bytes written, or `-1` on a malformed frame or an
+/// undersized `dst`.
+///
+/// # Safety
+///
+/// Same pointer/length contract as [`cobs_encode`].
+#[no_mangle]
+pub unsafe extern "C" fn cobs_decode(
+    sentinel: u8,
+    frame: *const u8,
+    frame_len: usize,
+    dst: *mut u8,
+    dst_len: usize,
+) -> isize {
+    let frame = if frame_len == 0 { &[] } else { slice::from_raw_parts(frame, frame_len) };
+    let dst = if dst_len == 0 { &mut [] } else { slice::from_raw_parts_mut(dst, dst_len) };
+    match decode_to_slice(sentinel, frame, dst) {
+        Ok(written) => written as isize,
+        Err(_) => -1,
+    }
+}
+
+/// Opaque incremental decoder handle, fed one byte at a time. Construct with
+/// [`cobs_decoder_new`], feed bytes with [`cobs_decoder_feed`], and release
+/// with [`cobs_decoder_free`].
+pub struct CobsDecoder(PushDecoder);
+
+/// Construct a decoder handle that splits on `sentinel`. Must be released
+/// with [`cobs_decoder_free`].
+#[no_mangle]
+pub extern "C" fn cobs_decoder_new(sentinel: u8) -> *mut CobsDecoder {
+    Box::into_raw(Box::new(CobsDecoder(PushDecoder::with_sentinel(sentinel))))
+}
+
+/// Feed one byte to `decoder`. Returns the number of bytes written to `dst`
+/// if `byte` completed a frame, `0` if it didn't, or `-1` if the completed
+/// frame failed to decode or didn't fit in `dst`.
+///
+/// # Safety
+///
+/// `decoder` must be a live, non-null handle from [`cobs_decoder_new`]; `dst`
+/// must be valid for writes of `dst_len` bytes (or null if `dst_len` is `0`).
+#[no_mangle]
+pub unsafe extern "C" fn cobs_decoder_feed(
+    decoder: *mut CobsDecoder,
+    byte: u8,
+    dst: *mut u8,
+    dst_len: usize,
+) -> isize {
+    let frame = match (*decoder).0.feed(byte) {
+        None => return 0,
+        Some(Err(_)) => return -1,
+        Some(Ok(frame)) => frame,
+    };
+    if frame.len() > dst_len {
+        return -1;
+    }
+    let dst = if dst_len == 0 { &mut [] } else { slice::from_raw_parts_mut(dst, dst_len) };
+    dst[..frame.len()].copy_from_slice(&frame);
+    frame.len() as isize
+}
+
+/// Release a decoder handle created by [`cobs_decoder_new`]. A no-op if
+/// `decoder` is null.
+///
+/// # Safety
+///
+/// `decoder` must either be null or a live handle from [`cobs_decoder_new`]
+/// that hasn't already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn cobs_decoder_free(decoder: *mut CobsDecoder) {
+    if !decoder.is_null() {
+        drop(Box::from_raw(decoder));
+    }
+}
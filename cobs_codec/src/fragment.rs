@@ -0,0 +1,126 @@
+//! A fragmentation/reassembly layer for links with a small maximum frame
+//! size: [`FragmentingEncoder`] splits a payload larger than a configured
+//! MTU into multiple COBS frames, and [`ReassemblingDecoder`] stitches
+//! successive frames back into the original payload.
+//!
+//! Each fragment's COBS payload is a 2-byte continuation header followed by
+//! up to `mtu` bytes of the original data:
+//! - byte 0: a sequence number, incrementing (and wrapping) by one per
+//!   fragment of the message, used to detect a dropped or reordered
+//!   fragment.
+//! - byte 1: `1` if more fragments follow, `0` if this is the last one.
+//!
+//! A payload that fits within the MTU on its own is still sent as a single
+//! one-fragment message, so callers don't need to special-case small
+//! payloads.
+
+use crate::{CobsError, Decoder, Encoder};
+use alloc::vec::Vec;
+
+const HEADER_LEN: usize = 2;
+
+/// Splits payloads larger than `mtu` bytes into multiple COBS frames, each
+/// prefixed with a small continuation header.
+#[derive(Debug, Clone, Copy)]
+pub struct FragmentingEncoder {
+    sentinel: u8,
+    mtu: usize,
+}
+
+impl FragmentingEncoder {
+    /// Construct an encoder that frames on `sentinel` and fits at most `mtu`
+    /// payload bytes (plus the continuation header) into each frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mtu` is `0`, since no fragment could carry the header.
+    pub const fn new(sentinel: u8, mtu: usize) -> Self {
+        assert!(mtu > 0, "fragment MTU must be at least 1 byte");
+        Self { sentinel, mtu }
+    }
+
+    /// Split `data` into one or more fragments and append each as its own
+    /// terminated COBS frame to `dst`.
+    pub fn encode_into(&self, data: &[u8], dst: &mut Vec<u8>) {
+        let encoder = Encoder::with_sentinel(self.sentinel);
+        let mut seq: u8 = 0;
+        let mut chunks = data.chunks(self.mtu).peekable();
+        // An empty payload still needs to go out as a single, empty-bodied
+        // fragment so the reassembler has something to yield.
+        if chunks.peek().is_none() {
+            let header = [seq, 0];
+            encoder.encode_frame_into(&header, dst);
+            return;
+        }
+        while let Some(chunk) = chunks.next() {
+            let more = chunks.peek().is_some();
+            let mut fragment = Vec::with_capacity(HEADER_LEN + chunk.len());
+            fragment.push(seq);
+            fragment.push(more as u8);
+            fragment.extend_from_slice(chunk);
+            encoder.encode_frame_into(&fragment, dst);
+            seq = seq.wrapping_add(1);
+        }
+    }
+}
+
+/// Reassembles messages split by [`FragmentingEncoder`] from successive COBS
+/// frames, buffering fragments until the one marked as the last arrives.
+#[derive(Debug, Clone)]
+pub struct ReassemblingDecoder {
+    decoder: Decoder,
+    buffer: Vec<u8>,
+    next_seq: u8,
+}
+
+impl ReassemblingDecoder {
+    /// Construct a decoder that splits on the given runtime `sentinel`.
+    pub const fn with_sentinel(sentinel: u8) -> Self {
+        Self {
+            decoder: Decoder::with_sentinel(sentinel),
+            buffer: Vec::new(),
+            next_seq: 0,
+        }
+    }
+
+    /// Feed one COBS frame's content (without the trailing delimiter).
+    /// Returns the reassembled payload once the final fragment of a message
+    /// arrives, or `None` while a message is still being collected. A
+    /// fragment whose sequence number doesn't follow the last one abandons
+    /// whatever message was in progress and reports
+    /// [`CobsError::FragmentGap`] — unless the new fragment is itself
+    /// numbered `0`, in which case it's taken as the start of the next
+    /// message and buffered immediately rather than also being discarded.
+    pub fn decode_frame(&mut self, frame: &[u8]) -> Result<Option<Vec<u8>>, CobsError> {
+        let fragment = self.decoder.decode_frame(frame)?;
+        if fragment.len() < HEADER_LEN {
+            return Err(CobsError::TruncatedFrame {
+                offset: fragment.len(),
+            });
+        }
+        let (seq, more) = (fragment[0], fragment[1] != 0);
+        if seq != self.next_seq {
+            self.buffer.clear();
+            // A fragment numbered 0 is unambiguously the start of a brand
+            // new message, so buffer it instead of erroring it away too:
+            // otherwise one dropped fragment costs not just the message it
+            // belonged to, but also the very next message, which would
+            // otherwise have to be resent.
+            if seq != 0 {
+                let expected = self.next_seq;
+                self.next_seq = 0;
+                return Err(CobsError::FragmentGap {
+                    expected,
+                    found: seq,
+                });
+            }
+        }
+        self.buffer.extend_from_slice(&fragment[HEADER_LEN..]);
+        self.next_seq = seq.wrapping_add(1);
+        if more {
+            return Ok(None);
+        }
+        self.next_seq = 0;
+        Ok(Some(core::mem::take(&mut self.buffer)))
+    }
+}
@@ -0,0 +1,142 @@
+//! Runtime helpers shared by the code generated by `#[derive(CobsFrame)]`.
+//!
+//! Fixed-size scalars are serialized as little-endian bytes; variable-size
+//! fields are length-prefixed with an LEB128 varint. The reader guards every
+//! access against a buffer that ran out mid-field.
+
+use crate::CobsError;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Append `value` to `buf` as an unsigned LEB128 varint.
+pub fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        buf.push(byte);
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+/// Length-prefix `data` with a varint and append it to `buf`.
+pub fn write_var_bytes(buf: &mut Vec<u8>, data: &[u8]) {
+    write_varint(buf, data.len() as u64);
+    buf.extend_from_slice(data);
+}
+
+/// Sequential reader over a decoded frame payload.
+pub struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    /// Wrap the payload a [`Decoder`](crate::Decoder) yielded.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn truncated(&self) -> CobsError {
+        CobsError::TruncatedFrame {
+            offset: self.data.len(),
+        }
+    }
+
+    /// Read the next `n` bytes, or fail if fewer remain.
+    pub fn read_bytes(&mut self, n: usize) -> Result<&'a [u8], CobsError> {
+        let end = self.pos + n;
+        if end > self.data.len() {
+            return Err(self.truncated());
+        }
+        let slice = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    /// Read exactly `N` bytes into a fixed array.
+    pub fn read_array<const N: usize>(&mut self) -> Result<[u8; N], CobsError> {
+        let mut array = [0u8; N];
+        array.copy_from_slice(self.read_bytes(N)?);
+        Ok(array)
+    }
+
+    /// Read an unsigned LEB128 varint, guarding against a length field that the
+    /// frame was truncated in the middle of.
+    pub fn read_varint(&mut self) -> Result<u64, CobsError> {
+        let mut value = 0u64;
+        let mut shift = 0u32;
+        loop {
+            let byte = self.read_bytes(1)?[0];
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+            shift += 7;
+            if shift >= 64 {
+                return Err(self.truncated());
+            }
+        }
+    }
+
+    /// Read a varint-prefixed byte string.
+    pub fn read_var_bytes(&mut self) -> Result<Vec<u8>, CobsError> {
+        let len = self.read_varint()? as usize;
+        Ok(self.read_bytes(len)?.to_vec())
+    }
+
+    /// Read a varint-prefixed UTF-8 string.
+    pub fn read_string(&mut self) -> Result<String, CobsError> {
+        let start = self.pos;
+        let bytes = self.read_var_bytes()?;
+        String::from_utf8(bytes).map_err(|_| CobsError::InvalidUtf8 { offset: start })
+    }
+
+    /// Number of bytes not yet read.
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.pos
+    }
+
+    /// Read every remaining byte.
+    pub fn read_rest(&mut self) -> &'a [u8] {
+        let rest = &self.data[self.pos..];
+        self.pos = self.data.len();
+        rest
+    }
+}
+
+/// A fixed-size field serialized as little-endian bytes.
+pub trait Scalar: Sized {
+    /// Append the little-endian encoding to `buf`.
+    fn write_le(&self, buf: &mut Vec<u8>);
+    /// Read the little-endian encoding from `reader`.
+    fn read_le(reader: &mut Reader<'_>) -> Result<Self, CobsError>;
+}
+
+macro_rules! impl_scalar {
+    ($($ty:ty),* $(,)?) => {$(
+        impl Scalar for $ty {
+            fn write_le(&self, buf: &mut Vec<u8>) {
+                buf.extend_from_slice(&self.to_le_bytes());
+            }
+            fn read_le(reader: &mut Reader<'_>) -> Result<Self, CobsError> {
+                Ok(<$ty>::from_le_bytes(reader.read_array::<{ ::core::mem::size_of::<$ty>() }>()?))
+            }
+        }
+    )*};
+}
+
+impl_scalar!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl Scalar for bool {
+    fn write_le(&self, buf: &mut Vec<u8>) {
+        buf.push(*self as u8);
+    }
+    fn read_le(reader: &mut Reader<'_>) -> Result<Self, CobsError> {
+        Ok(reader.read_bytes(1)?[0] != 0)
+    }
+}
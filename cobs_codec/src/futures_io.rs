@@ -0,0 +1,85 @@
+//! Async adapters over `futures_io::{AsyncRead, AsyncWrite}`, for codecs
+//! running on executors other than tokio (smol, async-std).
+
+use alloc::vec::Vec;
+use std::io;
+
+use futures_io::{AsyncRead, AsyncWrite};
+use futures_util::{AsyncReadExt, AsyncWriteExt};
+
+use crate::sans_io::PushDecoder;
+use crate::stream::StreamEncoder;
+
+/// Wraps an inner [`AsyncRead`], destuffing complete frames out of the bytes
+/// it produces.
+pub struct CobsAsyncReader<R> {
+    inner: R,
+    decoder: PushDecoder,
+}
+
+impl<R: AsyncRead + Unpin> CobsAsyncReader<R> {
+    /// Wrap `inner`, framing on the given runtime `sentinel`.
+    pub fn new(sentinel: u8, inner: R) -> Self {
+        Self {
+            inner,
+            decoder: PushDecoder::with_sentinel(sentinel),
+        }
+    }
+
+    /// Pull bytes from the inner reader, appending the next decoded frame's
+    /// payload to `buf`. Returns `Ok(true)` once a frame was appended, or
+    /// `Ok(false)` if the inner reader hit EOF before completing one.
+    pub async fn read_frame(&mut self, buf: &mut Vec<u8>) -> io::Result<bool> {
+        let mut byte = [0u8; 1];
+        loop {
+            if self.inner.read(&mut byte).await? == 0 {
+                return Ok(false);
+            }
+            if let Some(frame) = self.decoder.feed(byte[0]) {
+                let frame = frame.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                buf.extend_from_slice(&frame);
+                return Ok(true);
+            }
+        }
+    }
+
+    /// Consume the reader, returning the inner one. Any partially-received
+    /// frame is discarded.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+/// Wraps an inner [`AsyncWrite`], COBS-encoding whole frames handed to
+/// [`CobsAsyncWriter::write_frame`] and forwarding the stuffed output.
+pub struct CobsAsyncWriter<W> {
+    inner: W,
+    encoder: StreamEncoder,
+    scratch: Vec<u8>,
+}
+
+impl<W: AsyncWrite + Unpin> CobsAsyncWriter<W> {
+    /// Wrap `inner`, framing on the given runtime `sentinel`.
+    pub fn new(sentinel: u8, inner: W) -> Self {
+        Self {
+            inner,
+            encoder: StreamEncoder::with_sentinel(sentinel),
+            scratch: Vec::new(),
+        }
+    }
+
+    /// Stuff `data` into a single terminated frame and write it to the
+    /// inner writer.
+    pub async fn write_frame(&mut self, data: &[u8]) -> io::Result<()> {
+        self.scratch.clear();
+        self.encoder.start_frame();
+        self.encoder.write(data, &mut self.scratch);
+        self.encoder.finish(&mut self.scratch);
+        self.inner.write_all(&self.scratch).await
+    }
+
+    /// Consume the writer, returning the inner one.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
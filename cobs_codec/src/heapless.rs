@@ -0,0 +1,203 @@
+//! Fixed-capacity COBS encode/decode built on `heapless::Vec`, for targets
+//! where `alloc` itself is unavailable. [`encode_heapless`]/[`decode_heapless`]
+//! are the owned-buffer counterparts of [`crate::encode_to_slice`]/
+//! [`crate::decode_to_slice`]; [`PushDecoder`] is the fixed-capacity
+//! counterpart of [`crate::sans_io::PushDecoder`]; [`FixedDecoder`] is a
+//! [`crate::Decoder`]-style stateful decoder with its sentinel and capacity
+//! fixed at compile time instead of chosen at runtime.
+
+use core::fmt;
+
+use crate::{decode_to_slice, encode_to_slice, BufferTooSmall, CobsError, DecodeToSliceError};
+
+/// Stuff `src` into a fixed-capacity buffer, for targets that cannot
+/// allocate at all. Equivalent to [`crate::encode_to_slice`] sized to `N`
+/// bytes, but returns an owned, self-contained buffer instead of requiring
+/// the caller to pass one in.
+pub fn encode_heapless<const N: usize>(
+    sentinel: u8,
+    src: &[u8],
+) -> Result<heapless::Vec<u8, N>, BufferTooSmall> {
+    let mut dst = heapless::Vec::<u8, N>::new();
+    dst.resize(N, 0).ok();
+    let written = encode_to_slice(sentinel, src, &mut dst)?;
+    dst.truncate(written);
+    Ok(dst)
+}
+
+/// Destuff `frame`'s content (without the trailing delimiter) into a
+/// fixed-capacity buffer. Equivalent to [`crate::decode_to_slice`] sized to
+/// `N` bytes, but returns an owned, self-contained buffer instead of
+/// requiring the caller to pass one in.
+pub fn decode_heapless<const N: usize>(
+    sentinel: u8,
+    frame: &[u8],
+) -> Result<heapless::Vec<u8, N>, DecodeToSliceError> {
+    let mut dst = heapless::Vec::<u8, N>::new();
+    dst.resize(N, 0).ok();
+    let written = decode_to_slice(sentinel, frame, &mut dst)?;
+    dst.truncate(written);
+    Ok(dst)
+}
+
+/// Byte-at-a-time COBS decoder whose in-progress frame lives in a
+/// fixed-capacity `heapless::Vec<u8, N>` instead of growing an
+/// [`alloc::vec::Vec`] like [`crate::sans_io::PushDecoder`]. Feed it bytes as
+/// they arrive; it reports a completed frame (or the error from decoding it)
+/// each time the sentinel is seen. A frame longer than `N` bytes is reported
+/// as [`DecodeToSliceError::BufferTooSmall`] exactly once, as soon as it
+/// overflows; like [`Decoder::with_max_frame_len`](crate::Decoder::with_max_frame_len),
+/// the rest of that oversized frame is then discarded silently, and decoding
+/// resumes cleanly once its sentinel is seen.
+#[derive(Debug, Clone)]
+pub struct PushDecoder<const N: usize> {
+    sentinel: u8,
+    buf: heapless::Vec<u8, N>,
+    overflowed: bool,
+}
+
+impl<const N: usize> PushDecoder<N> {
+    /// Construct a push decoder that splits on the given runtime `sentinel`.
+    pub const fn with_sentinel(sentinel: u8) -> Self {
+        Self {
+            sentinel,
+            buf: heapless::Vec::new(),
+            overflowed: false,
+        }
+    }
+
+    /// Feed a single byte. Returns `Some` with the decoded frame (or the
+    /// error from decoding it) once `byte` completes a frame.
+    pub fn feed(&mut self, byte: u8) -> Option<Result<heapless::Vec<u8, N>, DecodeToSliceError>> {
+        if self.overflowed {
+            if byte == self.sentinel {
+                self.overflowed = false;
+            }
+            return None;
+        }
+        if byte == self.sentinel {
+            let frame = core::mem::replace(&mut self.buf, heapless::Vec::new());
+            Some(decode_heapless::<N>(self.sentinel, &frame))
+        } else if self.buf.push(byte).is_err() {
+            self.buf.clear();
+            self.overflowed = true;
+            Some(Err(DecodeToSliceError::BufferTooSmall))
+        } else {
+            None
+        }
+    }
+
+    /// Feed a chunk of bytes, collecting every frame (or decode error)
+    /// completed along the way, in order.
+    pub fn push(&mut self, chunk: &[u8]) -> alloc::vec::Vec<Result<heapless::Vec<u8, N>, DecodeToSliceError>> {
+        chunk.iter().filter_map(|&b| self.feed(b)).collect()
+    }
+
+    /// Number of bytes buffered for the frame currently in progress.
+    pub fn pending_len(&self) -> usize {
+        self.buf.len()
+    }
+}
+
+/// Error from [`FixedDecoder::feed`]: either a framing error, or a frame that
+/// exceeded the decoder's fixed-capacity `CAP` bytes.
+#[derive(Debug)]
+pub enum FixedDecoderError {
+    /// A framing error while destuffing the frame.
+    Cobs(CobsError),
+    /// The frame exceeded the decoder's fixed-capacity `CAP` bytes.
+    FrameTooLarge,
+}
+
+impl fmt::Display for FixedDecoderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FixedDecoderError::Cobs(e) => write!(f, "{e}"),
+            FixedDecoderError::FrameTooLarge => write!(f, "frame exceeded the fixed-capacity buffer"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FixedDecoderError {}
+
+impl From<DecodeToSliceError> for FixedDecoderError {
+    fn from(err: DecodeToSliceError) -> Self {
+        match err {
+            DecodeToSliceError::Cobs(e) => FixedDecoderError::Cobs(e),
+            DecodeToSliceError::BufferTooSmall => FixedDecoderError::FrameTooLarge,
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for FixedDecoderError {
+    fn format(&self, fmt: defmt::Formatter) {
+        match self {
+            FixedDecoderError::Cobs(e) => defmt::write!(fmt, "{}", e),
+            FixedDecoderError::FrameTooLarge => defmt::write!(fmt, "frame exceeded the fixed-capacity buffer"),
+        }
+    }
+}
+
+/// A [`crate::Decoder`]-style stateful decoder whose sentinel and
+/// fixed-capacity `heapless::Vec<u8, CAP>` buffer are both baked in as const
+/// generics instead of chosen at runtime, for embedded targets that want a
+/// compile-time bound on decoder memory use and no heap at all. Feed it bytes
+/// as they arrive the same way as [`PushDecoder`]; a frame longer than `CAP`
+/// bytes is reported as [`FixedDecoderError::FrameTooLarge`] exactly once, as
+/// soon as it overflows, and the rest of that oversized frame is then
+/// discarded silently until its sentinel is seen, same as [`PushDecoder`].
+#[derive(Debug, Clone)]
+pub struct FixedDecoder<const SENTINEL: u8, const CAP: usize> {
+    buf: heapless::Vec<u8, CAP>,
+    overflowed: bool,
+}
+
+impl<const SENTINEL: u8, const CAP: usize> Default for FixedDecoder<SENTINEL, CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const SENTINEL: u8, const CAP: usize> FixedDecoder<SENTINEL, CAP> {
+    /// Construct a decoder that splits on the compile-time `SENTINEL`.
+    pub const fn new() -> Self {
+        Self {
+            buf: heapless::Vec::new(),
+            overflowed: false,
+        }
+    }
+
+    /// Feed a single byte. Returns `Some` with the decoded frame (or the
+    /// error from decoding it) once `byte` completes a frame.
+    pub fn feed(&mut self, byte: u8) -> Option<Result<heapless::Vec<u8, CAP>, FixedDecoderError>> {
+        if self.overflowed {
+            if byte == SENTINEL {
+                self.overflowed = false;
+            }
+            return None;
+        }
+        if byte == SENTINEL {
+            let frame = core::mem::replace(&mut self.buf, heapless::Vec::new());
+            Some(decode_heapless::<CAP>(SENTINEL, &frame).map_err(FixedDecoderError::from))
+        } else if self.buf.push(byte).is_err() {
+            self.buf.clear();
+            self.overflowed = true;
+            Some(Err(FixedDecoderError::FrameTooLarge))
+        } else {
+            None
+        }
+    }
+
+    /// Feed a chunk of bytes, collecting every frame (or decode error)
+    /// completed along the way, in order.
+    pub fn push(&mut self, chunk: &[u8]) -> alloc::vec::Vec<Result<heapless::Vec<u8, CAP>, FixedDecoderError>> {
+        chunk.iter().filter_map(|&b| self.feed(b)).collect()
+    }
+
+    /// Number of bytes buffered for the frame currently in progress.
+    pub fn pending_len(&self) -> usize {
+        self.buf.len()
+    }
+}
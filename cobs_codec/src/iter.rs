@@ -0,0 +1,230 @@
+//! Pull-based iterator adapters for stuffing and destuffing a single frame,
+//! for piping a COBS stage into an iterator-based parsing pipeline without an
+//! intermediate buffer owned by the caller.
+
+use crate::CobsError;
+
+/// Adapt `src` into an iterator that lazily yields the stuffed bytes of a
+/// single COBS frame, including the trailing delimiter. Equivalent to
+/// [`crate::encode`], but pull-based: nothing is produced until the consumer
+/// asks for it.
+///
+/// Each group is still gathered internally before it can be emitted — the
+/// group's code byte has to be written before its data, but isn't known
+/// until the group ends — so this buffers at most 254 bytes at a time, not
+/// the whole frame.
+pub fn encode_iter<I: Iterator<Item = u8>>(sentinel: u8, src: I) -> EncodeIter<I> {
+    EncodeIter {
+        sentinel,
+        src,
+        group: [0; 254],
+        group_len: 0,
+        idx: 0,
+        state: EncodeState::NeedGroup,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GroupEnd {
+    ZeroByte,
+    FullRun,
+    EndOfInput,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EncodeState {
+    NeedGroup,
+    EmitCode(GroupEnd),
+    EmitData(GroupEnd),
+    EmitDelimiter,
+    Done,
+}
+
+/// Iterator returned by [`encode_iter`].
+#[derive(Debug, Clone)]
+pub struct EncodeIter<I> {
+    sentinel: u8,
+    src: I,
+    group: [u8; 254],
+    group_len: usize,
+    idx: usize,
+    state: EncodeState,
+}
+
+impl<I: Iterator<Item = u8>> EncodeIter<I> {
+    fn fill_group(&mut self) -> GroupEnd {
+        self.group_len = 0;
+        let mut code: u8 = 1;
+        let end = loop {
+            match self.src.next() {
+                Some(0) => break GroupEnd::ZeroByte,
+                Some(b) => {
+                    self.group[self.group_len] = b;
+                    self.group_len += 1;
+                    code += 1;
+                    if code == 0xFF {
+                        break GroupEnd::FullRun;
+                    }
+                }
+                None => break GroupEnd::EndOfInput,
+            }
+        };
+        self.group[self.group_len..].fill(0);
+        end
+    }
+
+    fn code(&self) -> u8 {
+        self.group_len as u8 + 1
+    }
+}
+
+impl<I: Iterator<Item = u8>> Iterator for EncodeIter<I> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        loop {
+            match self.state {
+                EncodeState::NeedGroup => {
+                    let end = self.fill_group();
+                    self.state = EncodeState::EmitCode(end);
+                }
+                EncodeState::EmitCode(end) => {
+                    self.idx = 0;
+                    self.state = EncodeState::EmitData(end);
+                    return Some(self.code() ^ self.sentinel);
+                }
+                EncodeState::EmitData(end) => {
+                    if self.idx < self.group_len {
+                        let b = self.group[self.idx] ^ self.sentinel;
+                        self.idx += 1;
+                        return Some(b);
+                    }
+                    self.state = match end {
+                        GroupEnd::EndOfInput => EncodeState::EmitDelimiter,
+                        GroupEnd::ZeroByte | GroupEnd::FullRun => EncodeState::NeedGroup,
+                    };
+                }
+                EncodeState::EmitDelimiter => {
+                    self.state = EncodeState::Done;
+                    return Some(self.sentinel);
+                }
+                EncodeState::Done => return None,
+            }
+        }
+    }
+}
+
+/// Adapt `src` into an iterator that lazily destuffs a single COBS frame,
+/// stopping once the sentinel delimiter is consumed. Equivalent to
+/// [`crate::decode`], but pull-based: bytes are requested from `src` only as
+/// the consumer asks for decoded output, and nothing beyond the delimiter is
+/// touched.
+///
+/// Yields `Err` (and then stops) if `src` is malformed or runs out before the
+/// delimiter appears.
+pub fn decode_iter<I: Iterator<Item = u8>>(sentinel: u8, src: I) -> DecodeIter<I> {
+    DecodeIter {
+        sentinel,
+        src: src.peekable(),
+        offset: 0,
+        remaining: 0,
+        block_full: false,
+        state: DecodeState::NeedCode,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DecodeState {
+    NeedCode,
+    EmitData,
+    Done,
+    Errored,
+}
+
+/// Iterator returned by [`decode_iter`].
+#[derive(Debug, Clone)]
+pub struct DecodeIter<I: Iterator<Item = u8>> {
+    sentinel: u8,
+    src: core::iter::Peekable<I>,
+    offset: usize,
+    remaining: usize,
+    block_full: bool,
+    state: DecodeState,
+}
+
+impl<I: Iterator<Item = u8>> Iterator for DecodeIter<I> {
+    type Item = Result<u8, CobsError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.state {
+                DecodeState::Done | DecodeState::Errored => return None,
+                DecodeState::NeedCode => match self.src.next() {
+                    None => {
+                        self.state = DecodeState::Errored;
+                        return Some(Err(CobsError::TruncatedFrame {
+                            offset: self.offset,
+                        }));
+                    }
+                    Some(b) if b == self.sentinel => {
+                        self.state = DecodeState::Done;
+                        return None;
+                    }
+                    Some(b) => {
+                        let offset = self.offset;
+                        self.offset += 1;
+                        let code = b ^ self.sentinel;
+                        if code == 0 {
+                            self.state = DecodeState::Errored;
+                            return Some(Err(CobsError::InvalidCodeByte { offset }));
+                        }
+                        self.remaining = code as usize - 1;
+                        self.block_full = code == 0xFF;
+                        self.state = DecodeState::EmitData;
+                    }
+                },
+                DecodeState::EmitData => {
+                    if self.remaining > 0 {
+                        match self.src.next() {
+                            None => {
+                                self.state = DecodeState::Errored;
+                                return Some(Err(CobsError::TruncatedFrame {
+                                    offset: self.offset,
+                                }));
+                            }
+                            Some(b) if b == self.sentinel => {
+                                self.state = DecodeState::Errored;
+                                return Some(Err(CobsError::UnexpectedSentinel {
+                                    offset: self.offset,
+                                }));
+                            }
+                            Some(b) => {
+                                self.offset += 1;
+                                self.remaining -= 1;
+                                return Some(Ok(b ^ self.sentinel));
+                            }
+                        }
+                    } else if self.block_full {
+                        self.state = DecodeState::NeedCode;
+                    } else {
+                        match self.src.peek() {
+                            Some(&b) if b == self.sentinel => {
+                                self.state = DecodeState::NeedCode;
+                            }
+                            Some(_) => {
+                                self.state = DecodeState::NeedCode;
+                                return Some(Ok(0));
+                            }
+                            None => {
+                                self.state = DecodeState::Errored;
+                                return Some(Err(CobsError::TruncatedFrame {
+                                    offset: self.offset,
+                                }));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
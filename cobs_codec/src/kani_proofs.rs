@@ -0,0 +1,103 @@
+//! Kani proof harnesses, compiled only under `cargo kani` (which sets
+//! `#[cfg(kani)]` automatically — this module isn't reachable from a normal
+//! build or `cargo test`). Model-checks the two invariants the proptest
+//! suite in `tests/properties.rs` can only sample: that `decode(encode(x))`
+//! is the identity and that an encoded frame never contains the sentinel,
+//! both for every payload up to `MAX_LEN` bytes and every sentinel value.
+//!
+//! The `*_never_panics` harnesses below feed arbitrary (not necessarily
+//! validly-stuffed) bytes straight into the decode path, since Kani flags any
+//! reachable panic, arithmetic overflow, or out-of-bounds index as a proof
+//! failure on its own — a gateway decoding bytes off the wire hits exactly
+//! this "arbitrary attacker-controlled frame" case, not just frames this
+//! crate encoded itself.
+//!
+//! Run with `cargo kani --harness roundtrip_is_identity` (or any other
+//! harness name below) from this crate's directory.
+
+use crate::{Decoder, Encoder};
+use alloc::vec::Vec;
+
+/// Kept small: Kani's bounded model checker explores every payload length
+/// and byte value up to this bound, so proof time grows quickly with it.
+const MAX_LEN: usize = 4;
+
+fn any_payload() -> ([u8; MAX_LEN], usize) {
+    let data: [u8; MAX_LEN] = kani::any();
+    let len: usize = kani::any();
+    kani::assume(len <= MAX_LEN);
+    (data, len)
+}
+
+#[kani::proof]
+fn roundtrip_is_identity() {
+    let (data, len) = any_payload();
+    let payload = &data[..len];
+    let sentinel: u8 = kani::any();
+
+    let mut frame = Vec::new();
+    Encoder::with_sentinel(sentinel).encode_frame_into(payload, &mut frame);
+    frame.pop(); // drop the trailing delimiter
+
+    let decoded = Decoder::with_sentinel(sentinel).decode_frame(&frame).unwrap();
+    assert_eq!(decoded, payload);
+}
+
+#[kani::proof]
+fn encoded_content_never_contains_the_sentinel() {
+    let (data, len) = any_payload();
+    let payload = &data[..len];
+    let sentinel: u8 = kani::any();
+
+    let mut frame = Vec::new();
+    Encoder::with_sentinel(sentinel).encode_frame_into(payload, &mut frame);
+    frame.pop(); // drop the trailing delimiter
+
+    assert!(!frame.contains(&sentinel));
+}
+
+#[kani::proof]
+fn encode_frame_into_never_panics() {
+    let (data, len) = any_payload();
+    let payload = &data[..len];
+    let sentinel: u8 = kani::any();
+
+    let mut frame = Vec::new();
+    Encoder::with_sentinel(sentinel).encode_frame_into(payload, &mut frame);
+}
+
+/// `frame` here is arbitrary bytes, not necessarily anything an [`Encoder`]
+/// would have produced — the decode path has to stay panic-free on whatever
+/// a peer (or an attacker) sends, not just on its own output.
+#[kani::proof]
+fn decode_frame_never_panics() {
+    let (frame, len) = any_payload();
+    let sentinel: u8 = kani::any();
+
+    let _ = Decoder::with_sentinel(sentinel).decode_frame(&frame[..len]);
+}
+
+#[kani::proof]
+fn decode_in_place_never_panics() {
+    let (mut frame, len) = any_payload();
+    let sentinel: u8 = kani::any();
+
+    let _ = Decoder::with_sentinel(sentinel).decode_in_place(&mut frame[..len]);
+}
+
+#[kani::proof]
+fn validate_never_panics() {
+    let (frame, len) = any_payload();
+    let sentinel: u8 = kani::any();
+
+    let _ = Decoder::with_sentinel(sentinel).validate(&frame[..len]);
+}
+
+#[kani::proof]
+fn decode_to_slice_never_panics() {
+    let (frame, len) = any_payload();
+    let sentinel: u8 = kani::any();
+    let mut dst = [0u8; MAX_LEN];
+
+    let _ = crate::decode_to_slice(sentinel, &frame[..len], &mut dst);
+}
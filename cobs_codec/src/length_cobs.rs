@@ -0,0 +1,52 @@
+//! A COBS framing layer that also embeds a varint length header inside each
+//! frame, so byte loss that plain COBS framing alone would happily
+//! misinterpret as a shorter (but still well-formed) frame gets caught
+//! instead.
+//!
+//! The payload is varint length-prefixed before the combined buffer is
+//! COBS-stuffed as usual. Decoding destuffs first, reads the length prefix,
+//! and cross-checks it against the number of payload bytes that actually
+//! followed, returning [`CobsError::LengthMismatch`] on a mismatch.
+
+use alloc::vec::Vec;
+
+use crate::frame::{write_varint, Reader};
+use crate::{CobsError, Decoder, Encoder};
+
+/// Wraps [`Encoder`]/[`Decoder`] to varint length-prefix each payload ahead
+/// of COBS stuffing, so decode can cross-check the destuffed length against
+/// the payload bytes it actually recovered.
+#[derive(Debug, Clone, Copy)]
+pub struct LengthCobsCodec {
+    sentinel: u8,
+}
+
+impl LengthCobsCodec {
+    /// Construct a codec that frames on the given runtime `sentinel`.
+    pub const fn new(sentinel: u8) -> Self {
+        Self { sentinel }
+    }
+
+    /// Varint length-prefix `data`, COBS-stuff the combined buffer, and push
+    /// it (plus delimiter) onto `dst`.
+    pub fn encode_frame(&self, data: &[u8], dst: &mut Vec<u8>) {
+        let mut prefixed = Vec::with_capacity(data.len() + 5);
+        write_varint(&mut prefixed, data.len() as u64);
+        prefixed.extend_from_slice(data);
+        Encoder::with_sentinel(self.sentinel).encode_frame_into(&prefixed, dst);
+    }
+
+    /// Destuff `frame`, read its length prefix, and cross-check it against
+    /// the payload bytes that actually followed it, returning the payload on
+    /// success.
+    pub fn decode_frame(&self, frame: &[u8]) -> Result<Vec<u8>, CobsError> {
+        let prefixed = Decoder::with_sentinel(self.sentinel).decode_frame(frame)?;
+        let mut reader = Reader::new(&prefixed);
+        let expected = reader.read_varint()? as usize;
+        let got = reader.remaining();
+        if expected != got {
+            return Err(CobsError::LengthMismatch { expected, got });
+        }
+        Ok(reader.read_rest().to_vec())
+    }
+}
@@ -0,0 +1,2515 @@
+//! Consistent Overhead Byte Stuffing with a runtime-selectable sentinel.
+//!
+//! [`Encoder`] and [`Decoder`] frame a byte stream by stuffing out every
+//! occurrence of a chosen sentinel byte and terminating each frame with it.
+//! The sentinel used to be frozen through a const-generic parameter; it is now
+//! carried as a plain field so it can be picked at runtime with
+//! [`Encoder::with_sentinel`] / [`Decoder::with_sentinel`]. The const-generic
+//! constructors are retained and delegate to those.
+//!
+//! The byte-stuffing state machine itself only needs `alloc`, so it compiles
+//! with `#![no_std]` for embedded targets. The `std` feature (on by default)
+//! pulls in [`CobsError::Io`]; the `tokio` feature additionally wires
+//! [`Encoder`]/[`Decoder`] into `tokio_util::codec` over `bytes::BytesMut` and
+//! is required by `#[derive(CobsFrame)]`. Disable both for a Cortex-M target
+//! that only needs the raw stuff/unstuff primitives.
+//!
+//! The default build is `unsafe_code`-free, enforced with `#![forbid]`. The
+//! `unsafe-fast` feature trades that guarantee for an unchecked-index decode
+//! loop on the hot path; the `ffi` feature necessarily carries its own
+//! `unsafe extern "C" fn`s for the C ABI. Both lift the forbid.
+//!
+//! The encode and decode hot paths (stuffing, destuffing, `validate`,
+//! `decode_to_slice`, `decode_in_place`) report malformed input as a
+//! [`CobsError`] rather than panicking — `kani_proofs` model-checks this
+//! against arbitrary, not-necessarily-valid frame bytes, since a decoder
+//! facing a live link has to survive whatever a peer actually sends. Those
+//! harnesses run against the default (checked-index) decode loop; nothing in
+//! this repo yet runs `cargo kani` with `unsafe-fast` enabled, so the
+//! no-panic guarantee is unverified for that feature's unchecked-index loop.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(
+    not(any(feature = "unsafe-fast", feature = "ffi")),
+    forbid(unsafe_code)
+)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+#[cfg(feature = "tokio")]
+use tokio_util::codec;
+
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+#[cfg(feature = "futures")]
+pub mod backpressure;
+#[cfg(feature = "bbqueue")]
+pub mod bbqueue;
+#[cfg(feature = "compat")]
+pub mod compat;
+pub mod const_frame;
+#[cfg(feature = "crc")]
+pub mod crc_cobs;
+#[cfg(feature = "embedded-io")]
+pub mod embedded_io;
+#[cfg(feature = "embedded-io-async")]
+pub mod embedded_io_async;
+pub mod escape;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod fragment;
+pub mod frame;
+#[cfg(feature = "futures")]
+pub mod futures_io;
+#[cfg(feature = "heapless")]
+pub mod heapless;
+pub mod iter;
+pub mod length_cobs;
+#[cfg(kani)]
+mod kani_proofs;
+#[cfg(feature = "std")]
+pub mod reader;
+pub mod reduced;
+pub mod sans_io;
+pub mod scramble;
+pub mod sequence;
+#[cfg(feature = "serialport")]
+pub mod serialport;
+pub mod stream;
+#[cfg(feature = "stream-ext")]
+pub mod stream_ext;
+pub mod tagged;
+#[cfg(all(feature = "tokio", feature = "tokio-io"))]
+pub mod testing;
+#[cfg(feature = "tokio-io")]
+pub mod tokio_io;
+#[cfg(feature = "tokio-serial")]
+pub mod tokio_serial;
+#[cfg(feature = "postcard")]
+pub mod typed;
+#[cfg(feature = "vectors")]
+pub mod vectors;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod word;
+#[cfg(feature = "std")]
+pub mod writer;
+pub mod zpe;
+
+#[cfg(any(feature = "tokio", feature = "asynchronous-codec"))]
+pub use bytes;
+pub use cobs_derive::CobsFrame;
+
+/// Errors surfaced by [`Decoder`] while destuffing a frame. Each decode variant
+/// carries the byte offset within the stream at which the invariant was broken,
+/// so callers can log precisely what went wrong and decide whether to resync
+/// (see [`Decoder::with_max_frame_len`]) or abort. Marked `#[non_exhaustive]`
+/// so a future diagnostic variant doesn't become a breaking change.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CobsError {
+    /// A group claimed more bytes than preceded the delimiter: the sentinel
+    /// arrived earlier than the code byte promised.
+    UnexpectedSentinel { offset: usize },
+    /// The stream ended in the middle of a frame, with no terminating sentinel.
+    TruncatedFrame { offset: usize },
+    /// A code byte of `0`, which is never valid mid-frame.
+    InvalidCodeByte { offset: usize },
+    /// A varint-prefixed string field that was not valid UTF-8.
+    InvalidUtf8 { offset: usize },
+    /// A well-formed frame whose code bytes weren't the minimal (canonical)
+    /// encoding of its payload, rejected by [`Decoder::with_strict`].
+    NonCanonicalEncoding { offset: usize },
+    /// An in-progress frame exceeded [`Decoder::with_max_frame_len`]'s limit
+    /// before a delimiter arrived; the buffered bytes were discarded.
+    #[cfg(feature = "tokio")]
+    FrameTooLong { limit: usize },
+    /// Two delimiters arrived back to back with [`Decoder::with_empty_frames`]
+    /// set to [`EmptyFrames::Error`].
+    #[cfg(feature = "tokio")]
+    EmptyFrame { offset: usize },
+    /// An I/O error propagated from the underlying transport. Only available
+    /// with the `std` feature.
+    #[cfg(feature = "std")]
+    Io(io::Error),
+    /// A [`crc_cobs::CrcCobsCodec`]-framed payload's trailing CRC did not
+    /// match the data it was supposed to protect.
+    #[cfg(feature = "crc")]
+    CrcMismatch,
+    /// [`fragment::ReassemblingDecoder`] received a fragment whose sequence
+    /// number didn't follow the previous one, meaning a fragment was lost,
+    /// duplicated, or reordered in transit.
+    FragmentGap { expected: u8, found: u8 },
+    /// [`sequence::SequencedDecoder`] received a frame whose sequence number
+    /// didn't follow the previous one, meaning one or more whole frames were
+    /// dropped (or duplicated/reordered) in transit.
+    FrameLost { expected: u32, got: u32 },
+    /// A [`length_cobs::LengthCobsCodec`]-framed payload's varint length
+    /// header didn't match the number of payload bytes that actually
+    /// followed it, meaning bytes were lost or gained in a way plain COBS
+    /// framing alone can't detect.
+    LengthMismatch { expected: usize, got: usize },
+    /// [`tokio_io::read_frame_with_timeout`] or [`serialport::recv_frame`]
+    /// gave up waiting on a partial frame and discarded the `buffered` bytes
+    /// seen so far, rather than leaving a device that browned out mid-frame
+    /// to poison the stream until the next delimiter happens to arrive on
+    /// its own.
+    #[cfg(any(feature = "tokio-time", feature = "serialport"))]
+    Stalled { buffered: usize },
+}
+
+impl CobsError {
+    /// Byte offset within the stream at which the problem was detected.
+    pub fn offset(&self) -> usize {
+        match self {
+            CobsError::UnexpectedSentinel { offset }
+            | CobsError::TruncatedFrame { offset }
+            | CobsError::InvalidCodeByte { offset }
+            | CobsError::InvalidUtf8 { offset }
+            | CobsError::NonCanonicalEncoding { offset } => *offset,
+            #[cfg(feature = "tokio")]
+            CobsError::FrameTooLong { .. } => 0,
+            #[cfg(feature = "tokio")]
+            CobsError::EmptyFrame { offset } => *offset,
+            #[cfg(feature = "std")]
+            CobsError::Io(_) => 0,
+            #[cfg(feature = "crc")]
+            CobsError::CrcMismatch => 0,
+            CobsError::FragmentGap { .. } => 0,
+            CobsError::FrameLost { .. } => 0,
+            CobsError::LengthMismatch { .. } => 0,
+            #[cfg(any(feature = "tokio-time", feature = "serialport"))]
+            CobsError::Stalled { .. } => 0,
+        }
+    }
+
+    /// Shift the reported offset by `base`, turning a buffer-relative offset
+    /// into an absolute stream offset once earlier frames have been drained.
+    pub fn offset_by(self, base: usize) -> Self {
+        match self {
+            CobsError::UnexpectedSentinel { offset } => CobsError::UnexpectedSentinel {
+                offset: offset + base,
+            },
+            CobsError::TruncatedFrame { offset } => CobsError::TruncatedFrame {
+                offset: offset + base,
+            },
+            CobsError::InvalidCodeByte { offset } => CobsError::InvalidCodeByte {
+                offset: offset + base,
+            },
+            CobsError::InvalidUtf8 { offset } => CobsError::InvalidUtf8 {
+                offset: offset + base,
+            },
+            CobsError::NonCanonicalEncoding { offset } => CobsError::NonCanonicalEncoding {
+                offset: offset + base,
+            },
+            #[cfg(feature = "tokio")]
+            CobsError::EmptyFrame { offset } => CobsError::EmptyFrame {
+                offset: offset + base,
+            },
+            #[cfg(feature = "tokio")]
+            other @ CobsError::FrameTooLong { .. } => other,
+            #[cfg(feature = "std")]
+            other @ CobsError::Io(_) => other,
+            #[cfg(feature = "crc")]
+            other @ CobsError::CrcMismatch => other,
+            other @ CobsError::FragmentGap { .. } => other,
+            other @ CobsError::FrameLost { .. } => other,
+            other @ CobsError::LengthMismatch { .. } => other,
+            #[cfg(any(feature = "tokio-time", feature = "serialport"))]
+            other @ CobsError::Stalled { .. } => other,
+        }
+    }
+}
+
+impl fmt::Display for CobsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CobsError::UnexpectedSentinel { .. } => write!(f, "unexpected sentinel"),
+            CobsError::TruncatedFrame { .. } => write!(f, "truncated frame"),
+            CobsError::InvalidCodeByte { .. } => write!(f, "invalid code byte"),
+            CobsError::InvalidUtf8 { .. } => write!(f, "invalid utf-8 in string field"),
+            CobsError::NonCanonicalEncoding { .. } => write!(f, "non-canonical encoding"),
+            #[cfg(feature = "tokio")]
+            CobsError::FrameTooLong { limit } => write!(f, "frame exceeded {limit}-byte limit"),
+            #[cfg(feature = "tokio")]
+            CobsError::EmptyFrame { .. } => write!(f, "empty frame"),
+            #[cfg(feature = "std")]
+            CobsError::Io(e) => write!(f, "{e}"),
+            #[cfg(feature = "crc")]
+            CobsError::CrcMismatch => write!(f, "crc mismatch"),
+            CobsError::FragmentGap { expected, found } => {
+                write!(f, "expected fragment {expected}, found {found}")
+            }
+            CobsError::FrameLost { expected, got } => {
+                write!(f, "expected frame {expected}, got {got}")
+            }
+            CobsError::LengthMismatch { expected, got } => {
+                write!(f, "length header promised {expected} byte(s), got {got}")
+            }
+            #[cfg(any(feature = "tokio-time", feature = "serialport"))]
+            CobsError::Stalled { buffered } => {
+                write!(f, "stalled with {buffered} bytes of a partial frame buffered")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CobsError {}
+
+#[cfg(feature = "std")]
+impl From<io::Error> for CobsError {
+    fn from(e: io::Error) -> Self {
+        CobsError::Io(e)
+    }
+}
+
+/// Logs as the same messages produced by [`fmt::Display`], so the two stay
+/// in sync. [`CobsError::Io`] goes through [`defmt::Display2Format`] since
+/// `std::io::Error` has no `defmt::Format` impl of its own.
+#[cfg(feature = "defmt")]
+impl defmt::Format for CobsError {
+    fn format(&self, fmt: defmt::Formatter) {
+        match self {
+            CobsError::UnexpectedSentinel { offset } => {
+                defmt::write!(fmt, "unexpected sentinel at offset {}", offset)
+            }
+            CobsError::TruncatedFrame { offset } => {
+                defmt::write!(fmt, "truncated frame at offset {}", offset)
+            }
+            CobsError::InvalidCodeByte { offset } => {
+                defmt::write!(fmt, "invalid code byte at offset {}", offset)
+            }
+            CobsError::InvalidUtf8 { offset } => {
+                defmt::write!(fmt, "invalid utf-8 in string field at offset {}", offset)
+            }
+            CobsError::NonCanonicalEncoding { offset } => {
+                defmt::write!(fmt, "non-canonical encoding at offset {}", offset)
+            }
+            #[cfg(feature = "tokio")]
+            CobsError::FrameTooLong { limit } => {
+                defmt::write!(fmt, "frame exceeded {}-byte limit", limit)
+            }
+            #[cfg(feature = "tokio")]
+            CobsError::EmptyFrame { offset } => {
+                defmt::write!(fmt, "empty frame at offset {}", offset)
+            }
+            #[cfg(feature = "std")]
+            CobsError::Io(e) => defmt::write!(fmt, "{}", defmt::Display2Format(e)),
+            #[cfg(feature = "crc")]
+            CobsError::CrcMismatch => defmt::write!(fmt, "crc mismatch"),
+            CobsError::FragmentGap { expected, found } => {
+                defmt::write!(fmt, "expected fragment {}, found {}", expected, found)
+            }
+            CobsError::FrameLost { expected, got } => {
+                defmt::write!(fmt, "expected frame {}, got {}", expected, got)
+            }
+            CobsError::LengthMismatch { expected, got } => {
+                defmt::write!(fmt, "length header promised {} byte(s), got {}", expected, got)
+            }
+            #[cfg(any(feature = "tokio-time", feature = "serialport"))]
+            CobsError::Stalled { buffered } => {
+                defmt::write!(fmt, "stalled with {} bytes of a partial frame buffered", buffered)
+            }
+        }
+    }
+}
+
+/// Error returned by the `embedded-io`/`embedded-io-async` adapters: either a
+/// framing error or one propagated from the inner transport. Kept generic
+/// over the transport's own error type instead of reusing [`CobsError::Io`],
+/// which needs the `std` feature these adapters are meant to work without.
+#[derive(Debug)]
+#[cfg(any(feature = "embedded-io", feature = "embedded-io-async"))]
+pub enum EmbeddedIoError<E> {
+    /// A framing error while destuffing a frame.
+    Cobs(CobsError),
+    /// An error from the inner reader or writer.
+    Io(E),
+}
+
+#[cfg(any(feature = "embedded-io", feature = "embedded-io-async"))]
+impl<E: fmt::Display> fmt::Display for EmbeddedIoError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EmbeddedIoError::Cobs(e) => write!(f, "{e}"),
+            EmbeddedIoError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// Error returned by the fixed-capacity `embedded-hal-async` adapters: a
+/// framing error, a frame too large for the fixed-capacity buffer, or one
+/// propagated from the inner transport. Kept separate from
+/// [`EmbeddedIoError`] since these adapters have a failure mode (an
+/// oversized frame) the allocating ones don't.
+#[derive(Debug)]
+#[cfg(feature = "embedded-hal-async")]
+pub enum FixedFrameError<E> {
+    /// A framing error while destuffing a frame.
+    Cobs(CobsError),
+    /// A frame didn't fit in the fixed-capacity buffer.
+    BufferTooSmall,
+    /// An error from the inner reader or writer.
+    Io(E),
+}
+
+#[cfg(feature = "embedded-hal-async")]
+impl<E: fmt::Display> fmt::Display for FixedFrameError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FixedFrameError::Cobs(e) => write!(f, "{e}"),
+            FixedFrameError::BufferTooSmall => write!(f, "frame too large for the fixed-capacity buffer"),
+            FixedFrameError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+#[cfg(feature = "embedded-hal-async")]
+impl<E> From<DecodeToSliceError> for FixedFrameError<E> {
+    fn from(err: DecodeToSliceError) -> Self {
+        match err {
+            DecodeToSliceError::Cobs(e) => FixedFrameError::Cobs(e),
+            DecodeToSliceError::BufferTooSmall => FixedFrameError::BufferTooSmall,
+        }
+    }
+}
+
+/// Returned by [`encode_to_slice`] when the destination buffer isn't big
+/// enough to hold the stuffed frame. Size `dst` with [`max_encoded_len`]
+/// (plus one for the trailing delimiter) to rule this out ahead of time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferTooSmall;
+
+impl fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "destination buffer too small")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BufferTooSmall {}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for BufferTooSmall {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(fmt, "destination buffer too small")
+    }
+}
+
+/// Error from [`decode_to_slice`]: either a framing error, or `dst` not
+/// being big enough to hold the decoded payload.
+#[derive(Debug)]
+pub enum DecodeToSliceError {
+    /// A framing error while destuffing the frame.
+    Cobs(CobsError),
+    /// `dst` wasn't big enough to hold the decoded payload.
+    BufferTooSmall,
+}
+
+impl fmt::Display for DecodeToSliceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeToSliceError::Cobs(e) => write!(f, "{e}"),
+            DecodeToSliceError::BufferTooSmall => write!(f, "destination buffer too small"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeToSliceError {}
+
+impl From<CobsError> for DecodeToSliceError {
+    fn from(e: CobsError) -> Self {
+        DecodeToSliceError::Cobs(e)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for DecodeToSliceError {
+    fn format(&self, fmt: defmt::Formatter) {
+        match self {
+            DecodeToSliceError::Cobs(e) => defmt::write!(fmt, "{}", e),
+            DecodeToSliceError::BufferTooSmall => {
+                defmt::write!(fmt, "destination buffer too small")
+            }
+        }
+    }
+}
+
+/// Locate the first occurrence of `sentinel` in `haystack`, the way a
+/// [`Decoder`] scans for a frame's terminating delimiter. With the `memchr`
+/// feature (on by default) this runs at memory bandwidth instead of a
+/// byte-at-a-time loop.
+#[cfg(all(
+    any(feature = "tokio", feature = "asynchronous-codec"),
+    feature = "memchr"
+))]
+pub(crate) fn find_sentinel(haystack: &[u8], sentinel: u8) -> Option<usize> {
+    memchr::memchr(sentinel, haystack)
+}
+
+#[cfg(all(
+    any(feature = "tokio", feature = "asynchronous-codec"),
+    not(feature = "memchr")
+))]
+pub(crate) fn find_sentinel(haystack: &[u8], sentinel: u8) -> Option<usize> {
+    haystack.iter().position(|&b| b == sentinel)
+}
+
+/// Standard COBS caps a group at 254 data bytes, signaled by a `0xFF`
+/// continuation code; [`Encoder::with_max_block`]/[`Decoder::with_max_block`]
+/// let both sides agree on a smaller cap instead, for peers that bound
+/// latency by forcing more frequent groups.
+pub(crate) const DEFAULT_MAX_BLOCK: u8 = 0xFF;
+
+/// Stuff `data` into a COBS block stream over the `0x00` delimiter, rolling
+/// over to a new group every `max_block - 1` data bytes. The result never
+/// contains a `0x00` byte.
+#[cfg(not(feature = "simd"))]
+pub(crate) fn stuff(data: &[u8], max_block: u8) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len() + data.len() / (max_block as usize - 1) + 2);
+    let mut code_idx = 0;
+    out.push(0);
+    let mut code: u8 = 1;
+    for &b in data {
+        if b == 0 {
+            out[code_idx] = code;
+            code_idx = out.len();
+            out.push(0);
+            code = 1;
+        } else {
+            out.push(b);
+            code += 1;
+            if code == max_block {
+                out[code_idx] = code;
+                code_idx = out.len();
+                out.push(0);
+                code = 1;
+            }
+        }
+    }
+    out[code_idx] = code;
+    out
+}
+
+/// Stuff `data` into a COBS block stream over the `0x00` delimiter, rolling
+/// over to a new group every `max_block - 1` data bytes. The result never
+/// contains a `0x00` byte.
+///
+/// With the `simd` feature, zero bytes are located with `memchr` rather than
+/// a per-byte loop, so whole runs between them are copied in one slice
+/// extend. Each run is then regrouped by the `max_block` cap: every full
+/// group is written with a `max_block` continuation code, and the (possibly
+/// empty) remainder always gets its own terminating group, exactly matching
+/// the byte-at-a-time algorithm's output.
+#[cfg(feature = "simd")]
+pub(crate) fn stuff(data: &[u8], max_block: u8) -> Vec<u8> {
+    let max_run = max_block as usize - 1;
+    let mut out = Vec::with_capacity(data.len() + data.len() / max_run + 2);
+    let mut pos = 0;
+    loop {
+        let zero_at = memchr::memchr(0, &data[pos..]).map(|i| pos + i);
+        let run = &data[pos..zero_at.unwrap_or(data.len())];
+
+        let mut i = 0;
+        while i + max_run <= run.len() {
+            out.push(max_block);
+            out.extend_from_slice(&run[i..i + max_run]);
+            i += max_run;
+        }
+        out.push((run.len() - i) as u8 + 1);
+        out.extend_from_slice(&run[i..]);
+
+        match zero_at {
+            Some(z) => pos = z + 1,
+            None => break,
+        }
+    }
+    out
+}
+
+/// A growable byte buffer that [`stuff_into`] can append to and backpatch,
+/// abstracting over [`Vec<u8>`] and [`bytes::BytesMut`] so the allocation-free
+/// encode path works for both without duplicating the stuffing algorithm.
+/// `len`/`set` go unused under the `simd` feature, whose run-length-first
+/// algorithm never needs to backpatch.
+#[cfg_attr(feature = "simd", allow(dead_code))]
+trait ByteSink {
+    fn len(&self) -> usize;
+    fn push(&mut self, b: u8);
+    fn set(&mut self, idx: usize, b: u8);
+
+    /// Append `bytes` verbatim. Defaults to a per-byte [`ByteSink::push`]
+    /// loop; [`Vec<u8>`] and [`bytes::BytesMut`] override it with their own
+    /// bulk copy so a run known not to need per-byte transformation (no
+    /// sentinel to XOR in) can be appended in one call.
+    fn extend_from_slice(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.push(b);
+        }
+    }
+}
+
+impl ByteSink for Vec<u8> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn push(&mut self, b: u8) {
+        Vec::push(self, b)
+    }
+
+    fn set(&mut self, idx: usize, b: u8) {
+        self[idx] = b;
+    }
+
+    fn extend_from_slice(&mut self, bytes: &[u8]) {
+        Vec::extend_from_slice(self, bytes)
+    }
+}
+
+#[cfg(any(feature = "tokio", feature = "asynchronous-codec"))]
+impl ByteSink for bytes::BytesMut {
+    fn len(&self) -> usize {
+        bytes::BytesMut::len(self)
+    }
+
+    fn push(&mut self, b: u8) {
+        self.extend_from_slice(&[b]);
+    }
+
+    fn set(&mut self, idx: usize, b: u8) {
+        self[idx] = b;
+    }
+
+    fn extend_from_slice(&mut self, bytes: &[u8]) {
+        bytes::BytesMut::extend_from_slice(self, bytes)
+    }
+}
+
+/// Stuff `data` straight into `dst`, XORing with `sentinel` as each byte is
+/// written, rolling over to a new group every `max_block - 1` data bytes,
+/// and backpatching a group's code byte once its length is known. Used by
+/// [`Encoder::encode_frame_into`]/[`Encoder::encode_frame`] so steady state
+/// encoding needs no allocation beyond whatever `dst` itself grows by.
+#[cfg(not(feature = "simd"))]
+fn stuff_into<S: ByteSink>(data: &[u8], sentinel: u8, max_block: u8, dst: &mut S) {
+    if data.len() < max_block as usize - 1 && !data.contains(&0) {
+        stuff_single_group(data, sentinel, dst);
+        return;
+    }
+    let mut code_idx = dst.len();
+    dst.push(0);
+    let mut code: u8 = 1;
+    for &b in data {
+        if b == 0 {
+            dst.set(code_idx, code ^ sentinel);
+            code_idx = dst.len();
+            dst.push(0);
+            code = 1;
+        } else {
+            dst.push(b ^ sentinel);
+            code += 1;
+            if code == max_block {
+                dst.set(code_idx, code ^ sentinel);
+                code_idx = dst.len();
+                dst.push(0);
+                code = 1;
+            }
+        }
+    }
+    dst.set(code_idx, code ^ sentinel);
+}
+
+/// Write `data` as a single group: a length-prefixed code byte followed by
+/// the payload verbatim, XORed with `sentinel`. Callers must already have
+/// checked `data` has no zero byte and fits in one group with room to spare
+/// (`data.len() < max_block - 1`) — a data length of exactly `max_block - 1`
+/// still needs a trailing empty group to signal it isn't a `max_block`-sized
+/// group with more data to follow, which only [`stuff_into`]'s general
+/// algorithm produces. For anything shorter, this is exactly what that
+/// algorithm produces too, just via per-byte group bookkeeping it doesn't
+/// need. With
+/// the default sentinel of `0` the payload needs no transformation at all
+/// and copies straight into `dst` in one call instead of a per-byte loop.
+fn stuff_single_group<S: ByteSink>(data: &[u8], sentinel: u8, dst: &mut S) {
+    dst.push((data.len() as u8 + 1) ^ sentinel);
+    if sentinel == 0 {
+        dst.extend_from_slice(data);
+    } else {
+        for &b in data {
+            dst.push(b ^ sentinel);
+        }
+    }
+}
+
+/// See the non-`simd` overload: same backpatching approach, but zero bytes
+/// are located with `memchr` so whole runs between them are pushed in one go
+/// instead of a per-byte loop. With the default sentinel of `0`, a run that
+/// fits in one group copies straight into `dst` instead of a per-byte push.
+#[cfg(feature = "simd")]
+fn stuff_into<S: ByteSink>(data: &[u8], sentinel: u8, max_block: u8, dst: &mut S) {
+    if data.len() < max_block as usize - 1 && memchr::memchr(0, data).is_none() {
+        stuff_single_group(data, sentinel, dst);
+        return;
+    }
+    let max_run = max_block as usize - 1;
+    let mut pos = 0;
+    loop {
+        let zero_at = memchr::memchr(0, &data[pos..]).map(|i| pos + i);
+        let run = &data[pos..zero_at.unwrap_or(data.len())];
+
+        let mut i = 0;
+        while i + max_run <= run.len() {
+            dst.push(max_block ^ sentinel);
+            push_run(&run[i..i + max_run], sentinel, dst);
+            i += max_run;
+        }
+        dst.push(((run.len() - i) as u8 + 1) ^ sentinel);
+        push_run(&run[i..], sentinel, dst);
+
+        match zero_at {
+            Some(z) => pos = z + 1,
+            None => break,
+        }
+    }
+}
+
+/// Append one zero-free run of data bytes, XORed with `sentinel`. With the
+/// default sentinel of `0` this is a straight bulk copy; any other sentinel
+/// still needs each byte transformed.
+#[cfg(feature = "simd")]
+fn push_run<S: ByteSink>(run: &[u8], sentinel: u8, dst: &mut S) {
+    if sentinel == 0 {
+        dst.extend_from_slice(run);
+    } else {
+        for &b in run {
+            dst.push(b ^ sentinel);
+        }
+    }
+}
+
+/// Same backpatching approach as [`stuff_into`], but walks the payload as a
+/// sequence of `chunks` treated as one logical run of bytes, so a frame
+/// assembled from several buffers (a header slice plus a body slice) never
+/// needs concatenating into one contiguous one first. Used by
+/// [`Encoder::encode_vectored_into`]/[`Encoder::encode_vectored`]. Not
+/// `simd`-accelerated: vectored writes are for gather-style framing, not the
+/// bulk throughput path `simd` targets.
+fn stuff_vectored_into<'d, S: ByteSink>(
+    chunks: impl IntoIterator<Item = &'d [u8]>,
+    sentinel: u8,
+    max_block: u8,
+    dst: &mut S,
+) {
+    let mut code_idx = dst.len();
+    dst.push(0);
+    let mut code: u8 = 1;
+    for data in chunks {
+        for &b in data {
+            if b == 0 {
+                dst.set(code_idx, code ^ sentinel);
+                code_idx = dst.len();
+                dst.push(0);
+                code = 1;
+            } else {
+                dst.push(b ^ sentinel);
+                code += 1;
+                if code == max_block {
+                    dst.set(code_idx, code ^ sentinel);
+                    code_idx = dst.len();
+                    dst.push(0);
+                    code = 1;
+                }
+            }
+        }
+    }
+    dst.set(code_idx, code ^ sentinel);
+}
+
+/// Same backpatching approach as [`stuff_into`], but pulls input one byte at
+/// a time from `src` instead of from a contiguous slice, so a payload
+/// produced lazily (a compressed stream, a serializer yielding bytes as it
+/// goes) never needs collecting into a buffer first. Used by
+/// [`Encoder::encode_from_iter_into`]/[`Encoder::encode_from_iter`].
+fn stuff_from_iter<S: ByteSink>(src: impl Iterator<Item = u8>, sentinel: u8, max_block: u8, dst: &mut S) {
+    let mut code_idx = dst.len();
+    dst.push(0);
+    let mut code: u8 = 1;
+    for b in src {
+        if b == 0 {
+            dst.set(code_idx, code ^ sentinel);
+            code_idx = dst.len();
+            dst.push(0);
+            code = 1;
+        } else {
+            dst.push(b ^ sentinel);
+            code += 1;
+            if code == max_block {
+                dst.set(code_idx, code ^ sentinel);
+                code_idx = dst.len();
+                dst.push(0);
+                code = 1;
+            }
+        }
+    }
+    dst.set(code_idx, code ^ sentinel);
+}
+
+/// Destuff a single frame's content (the bytes preceding the delimiter, in the
+/// sentinel transmission domain) back into the original payload. `max_block`
+/// must match the value the frame was stuffed with, since it's what
+/// distinguishes a group that rolled over (no implicit zero follows) from one
+/// that ended on an embedded zero byte.
+///
+/// A group's bounds are known up front from its code byte, so each one is
+/// copied into `out` with a single `extend_from_slice` rather than a
+/// per-byte push; the sentinel XOR, when needed, is then applied to that
+/// whole run in a second pass the optimizer can auto-vectorize, instead of
+/// interleaving it with the copy.
+#[cfg(not(feature = "unsafe-fast"))]
+fn unstuff(frame: &[u8], sentinel: u8, max_block: u8) -> Result<Vec<u8>, CobsError> {
+    let mut out = Vec::with_capacity(frame.len());
+    let mut i = 0;
+    let n = frame.len();
+    while i < n {
+        let code = frame[i] ^ sentinel;
+        if code == 0 {
+            return Err(CobsError::InvalidCodeByte { offset: i });
+        }
+        let block = code as usize;
+        let start = i + 1;
+        let end = start + block - 1;
+        if end > n {
+            return Err(CobsError::UnexpectedSentinel { offset: n });
+        }
+        let run_start = out.len();
+        out.extend_from_slice(&frame[start..end]);
+        if sentinel != 0 {
+            for b in &mut out[run_start..] {
+                *b ^= sentinel;
+            }
+        }
+        i = end;
+        if block != max_block as usize && i < n {
+            out.push(0);
+        }
+    }
+    Ok(out)
+}
+
+/// See the safe overload: identical run-copying algorithm, but every index
+/// already bounds-checked against `n` above is read with `get_unchecked`
+/// instead of going through the bounds check again.
+#[cfg(feature = "unsafe-fast")]
+fn unstuff(frame: &[u8], sentinel: u8, max_block: u8) -> Result<Vec<u8>, CobsError> {
+    let mut out = Vec::with_capacity(frame.len());
+    let mut i = 0;
+    let n = frame.len();
+    while i < n {
+        // SAFETY: `i < n` is the loop condition.
+        let code = unsafe { *frame.get_unchecked(i) } ^ sentinel;
+        if code == 0 {
+            return Err(CobsError::InvalidCodeByte { offset: i });
+        }
+        let block = code as usize;
+        let start = i + 1;
+        let end = start + block - 1;
+        if end > n {
+            return Err(CobsError::UnexpectedSentinel { offset: n });
+        }
+        // SAFETY: `end <= n` was just checked, so `start..end` is in bounds
+        // (`start <= end` since `block >= 1`).
+        let run = unsafe { frame.get_unchecked(start..end) };
+        let run_start = out.len();
+        out.extend_from_slice(run);
+        if sentinel != 0 {
+            for b in &mut out[run_start..] {
+                *b ^= sentinel;
+            }
+        }
+        i = end;
+        if block != max_block as usize && i < n {
+            out.push(0);
+        }
+    }
+    Ok(out)
+}
+
+/// Upper bound on the stuffed length (without the trailing delimiter byte) of
+/// a `payload_len`-byte payload, for sizing buffers ahead of encoding. Tight
+/// for a sentinel-free payload; any occurrence of the sentinel only shrinks
+/// the real output below this bound.
+pub const fn max_encoded_len(payload_len: usize) -> usize {
+    payload_len + payload_len / 254 + 1
+}
+
+/// Exact stuffed length (without the trailing delimiter byte) that encoding
+/// `data` will produce.
+pub fn encoded_len(data: &[u8]) -> usize {
+    let mut groups = 1usize;
+    let mut nonzero = 0usize;
+    let mut run = 0usize;
+    for &b in data {
+        if b == 0 {
+            groups += 1;
+            run = 0;
+        } else {
+            nonzero += 1;
+            run += 1;
+            if run == 254 {
+                groups += 1;
+                run = 0;
+            }
+        }
+    }
+    nonzero + groups
+}
+
+/// Stuff `src` and append a single terminated frame to `dst`, without
+/// constructing an [`Encoder`]. Equivalent to
+/// `Encoder::with_sentinel(sentinel).encode_frame_into(src, dst)`.
+pub fn encode(sentinel: u8, src: &[u8], dst: &mut Vec<u8>) {
+    Encoder::with_sentinel(sentinel).encode_frame_into(src, dst)
+}
+
+/// Destuff a single frame's content (without the trailing delimiter), without
+/// constructing a [`Decoder`]. Equivalent to
+/// `Decoder::with_sentinel(sentinel).decode_frame(src)`.
+pub fn decode(sentinel: u8, src: &[u8]) -> Result<Vec<u8>, CobsError> {
+    Decoder::with_sentinel(sentinel).decode_frame(src)
+}
+
+/// Stuff `src` into a freshly allocated, terminated frame. Convenience
+/// wrapper around [`encode`] for callers who'd rather get a `Vec` back than
+/// thread a destination buffer through themselves; named to match
+/// [`decode_vec`] and the `encode_vec`/`decode_vec` convention other COBS
+/// crates use.
+pub fn encode_vec(sentinel: u8, src: &[u8]) -> Vec<u8> {
+    let mut dst = Vec::with_capacity(max_encoded_len(src.len()) + 1);
+    encode(sentinel, src, &mut dst);
+    dst
+}
+
+/// Alias for [`decode`], named to match [`encode_vec`].
+pub fn decode_vec(sentinel: u8, src: &[u8]) -> Result<Vec<u8>, CobsError> {
+    decode(sentinel, src)
+}
+
+/// Stuff `src` into `dst` with no trailing delimiter, for transports (UDP,
+/// CAN-FD) that already deliver one frame per packet and so have no need for
+/// a sentinel to mark the boundary. Equivalent to
+/// `Encoder::with_sentinel(sentinel).with_delimiter(false).encode_frame_into(src, dst)`.
+pub fn encode_datagram(sentinel: u8, src: &[u8], dst: &mut Vec<u8>) {
+    Encoder::with_sentinel(sentinel).with_delimiter(false).encode_frame_into(src, dst)
+}
+
+/// Destuff a whole packet produced by [`encode_datagram`]. Since
+/// [`decode_frame`][Decoder::decode_frame] never expects a trailing
+/// delimiter in the first place, this is just [`decode`] under a name that
+/// matches [`encode_datagram`] at call sites.
+pub fn decode_datagram(sentinel: u8, packet: &[u8]) -> Result<Vec<u8>, CobsError> {
+    decode(sentinel, packet)
+}
+
+/// Decode exactly one frame from the start of `src` and return its payload
+/// alongside whatever follows the frame's terminating delimiter.
+///
+/// For callers who already know a frame boundary another way (a datagram's
+/// length, a length-prefixed envelope) and so have no use for a stateful
+/// [`Decoder`] scanning a byte stream for it.
+pub fn decode_exact(sentinel: u8, src: &[u8]) -> Result<(Vec<u8>, &[u8]), CobsError> {
+    let pos = src
+        .iter()
+        .position(|&b| b == sentinel)
+        .ok_or(CobsError::TruncatedFrame { offset: src.len() })?;
+    let payload = decode(sentinel, &src[..pos])?;
+    Ok((payload, &src[pos + 1..]))
+}
+
+/// Iterate over back-to-back encoded frames already sitting in `src`,
+/// repeatedly applying [`decode_exact`]. For post-processing a fully
+/// captured dump in memory, where simulating a streaming [`Decoder`] would
+/// be more machinery than the job needs.
+///
+/// Stops after the first decode error, same as [`decode_exact`] would on
+/// that frame; callers who want to skip past malformed frames instead
+/// should use a [`Decoder::with_resync`].
+pub fn frames(sentinel: u8, src: &[u8]) -> Frames<'_> {
+    Frames { sentinel, remaining: Some(src) }
+}
+
+/// Iterator returned by [`frames`].
+pub struct Frames<'a> {
+    sentinel: u8,
+    remaining: Option<&'a [u8]>,
+}
+
+impl Iterator for Frames<'_> {
+    type Item = Result<Vec<u8>, CobsError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.remaining.take()?;
+        if remaining.is_empty() {
+            return None;
+        }
+        match decode_exact(self.sentinel, remaining) {
+            Ok((payload, rest)) => {
+                self.remaining = Some(rest);
+                Some(Ok(payload))
+            }
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+/// Where a frame sat in the stream it was decoded from, for logging or
+/// correlating against a packet capture. Returned alongside each payload by
+/// [`frames_with_meta`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameMeta {
+    /// Byte offset of the frame's first stuffed byte within the original
+    /// stream passed to [`frames_with_meta`].
+    pub stream_offset: usize,
+    /// Length of the decoded payload.
+    pub encoded_len: usize,
+    /// Length of the stuffed frame body, delimiter excluded.
+    pub stuffed_bytes: usize,
+}
+
+/// Iterate over back-to-back encoded frames already sitting in `src`, same
+/// as [`frames`], but alongside each payload yield a [`FrameMeta`] recording
+/// where in `src` the frame came from. For debugging corrupted captures,
+/// where a bare payload doesn't say which bytes of the dump it was found at.
+///
+/// Stops after the first decode error, same as [`frames`].
+pub fn frames_with_meta(sentinel: u8, src: &[u8]) -> FramesWithMeta<'_> {
+    FramesWithMeta { sentinel, remaining: Some(src), offset: 0 }
+}
+
+/// Iterator returned by [`frames_with_meta`].
+pub struct FramesWithMeta<'a> {
+    sentinel: u8,
+    remaining: Option<&'a [u8]>,
+    offset: usize,
+}
+
+impl Iterator for FramesWithMeta<'_> {
+    type Item = Result<(Vec<u8>, FrameMeta), CobsError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.remaining.take()?;
+        if remaining.is_empty() {
+            return None;
+        }
+        let stream_offset = self.offset;
+        match decode_exact(self.sentinel, remaining) {
+            Ok((payload, rest)) => {
+                let stuffed_bytes = remaining.len() - rest.len() - 1;
+                self.offset += stuffed_bytes + 1;
+                self.remaining = Some(rest);
+                let meta = FrameMeta { stream_offset, encoded_len: payload.len(), stuffed_bytes };
+                Some(Ok((payload, meta)))
+            }
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+/// Stuff `src` and write a single terminated frame into the fixed-size `dst`,
+/// without allocating — for DMA TX buffers and other interrupt contexts where
+/// growing a `Vec` is unacceptable. Returns the number of bytes written
+/// (including the trailing delimiter), or [`BufferTooSmall`] if `dst` isn't
+/// big enough.
+pub fn encode_to_slice(sentinel: u8, src: &[u8], dst: &mut [u8]) -> Result<usize, BufferTooSmall> {
+    if dst.is_empty() {
+        return Err(BufferTooSmall);
+    }
+    let mut code_idx = 0;
+    dst[0] = 0; // placeholder, overwritten once the group's length is known
+    let mut written = 1;
+    let mut code: u8 = 1;
+
+    for &b in src {
+        if b == 0 {
+            dst[code_idx] = code ^ sentinel;
+            if written >= dst.len() {
+                return Err(BufferTooSmall);
+            }
+            code_idx = written;
+            dst[written] = 0;
+            written += 1;
+            code = 1;
+        } else {
+            if written >= dst.len() {
+                return Err(BufferTooSmall);
+            }
+            dst[written] = b ^ sentinel;
+            written += 1;
+            code += 1;
+            if code == 0xFF {
+                dst[code_idx] = code ^ sentinel;
+                if written >= dst.len() {
+                    return Err(BufferTooSmall);
+                }
+                code_idx = written;
+                dst[written] = 0;
+                written += 1;
+                code = 1;
+            }
+        }
+    }
+    dst[code_idx] = code ^ sentinel;
+
+    if written >= dst.len() {
+        return Err(BufferTooSmall);
+    }
+    dst[written] = sentinel;
+    written += 1;
+
+    Ok(written)
+}
+
+/// Destuff `frame`'s content (without the trailing delimiter) into the
+/// fixed-size `dst`, without allocating. The read-only counterpart to
+/// [`Decoder::decode_in_place`] for sources that can't be mutated in place
+/// (flash, `const` data) and for stack buffers in tests and fuzz targets.
+pub fn decode_to_slice(sentinel: u8, frame: &[u8], dst: &mut [u8]) -> Result<usize, DecodeToSliceError> {
+    let mut i = 0;
+    let mut written = 0;
+    let n = frame.len();
+    while i < n {
+        let code = frame[i] ^ sentinel;
+        if code == 0 {
+            return Err(CobsError::InvalidCodeByte { offset: i }.into());
+        }
+        let block = code as usize;
+        let start = i + 1;
+        let end = start + block - 1;
+        if end > n {
+            return Err(CobsError::UnexpectedSentinel { offset: n }.into());
+        }
+        for &b in &frame[start..end] {
+            if written >= dst.len() {
+                return Err(DecodeToSliceError::BufferTooSmall);
+            }
+            dst[written] = b ^ sentinel;
+            written += 1;
+        }
+        i = end;
+        if block != 0xFF && i < n {
+            if written >= dst.len() {
+                return Err(DecodeToSliceError::BufferTooSmall);
+            }
+            dst[written] = 0;
+            written += 1;
+        }
+    }
+    Ok(written)
+}
+
+/// Running counters for link-quality monitoring, accumulated by an
+/// [`Encoder`] or [`Decoder`] that opted in via `with_stats(true)`. Counting
+/// is skipped entirely when stats aren't enabled, so the common case pays no
+/// bookkeeping cost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CodecStats {
+    /// Frames successfully encoded or decoded.
+    pub frames: usize,
+    /// Sum of payload lengths across those frames (pre-stuffing on encode,
+    /// post-destuffing on decode).
+    pub payload_bytes: usize,
+    /// Sum of on-the-wire lengths across those frames: the stuffed frame
+    /// body on decode (delimiter excluded, since it's consumed separately),
+    /// the full bytes written including delimiters on encode.
+    pub stuffed_bytes: usize,
+    /// Frames dropped by [`Decoder::with_resync`] instead of being reported.
+    pub resync_events: usize,
+    /// Frames that failed to destuff or were rejected outright, whether or
+    /// not resync then skipped past them.
+    pub malformed_frames: usize,
+}
+
+impl CodecStats {
+    #[cfg(feature = "tokio")]
+    const fn new() -> Self {
+        Self {
+            frames: 0,
+            payload_bytes: 0,
+            stuffed_bytes: 0,
+            resync_events: 0,
+            malformed_frames: 0,
+        }
+    }
+}
+
+/// A small free-list of [`bytes::BytesMut`] buffers, so a [`Decoder`] handing
+/// back one freshly allocated buffer per frame doesn't allocate (and then
+/// immediately free) backing storage on every call. Opt in with
+/// [`Decoder::with_pool`]; once the application is done with a decoded
+/// frame, return its buffer with [`BufferPool::release`] so the next decode
+/// reuses it instead of allocating again. Buffers nobody releases just fall
+/// back to the allocator, same as without a pool.
+#[cfg(feature = "tokio")]
+#[derive(Debug, Default)]
+pub struct BufferPool {
+    free: Vec<bytes::BytesMut>,
+}
+
+#[cfg(feature = "tokio")]
+impl BufferPool {
+    /// Construct an empty pool.
+    pub const fn new() -> Self {
+        Self { free: Vec::new() }
+    }
+
+    /// Take a buffer from the pool, or allocate a fresh one if it's empty.
+    pub fn acquire(&mut self) -> bytes::BytesMut {
+        self.free.pop().unwrap_or_default()
+    }
+
+    /// Clear `buf` and return it to the pool for a future [`BufferPool::acquire`]
+    /// call. Its capacity is kept, so the next caller starts from whatever
+    /// high-water mark this buffer already reached instead of from zero.
+    pub fn release(&mut self, mut buf: bytes::BytesMut) {
+        buf.clear();
+        self.free.push(buf);
+    }
+
+    /// Buffers currently sitting in the pool.
+    pub fn len(&self) -> usize {
+        self.free.len()
+    }
+
+    /// Whether the pool has no buffers to hand out right now.
+    pub fn is_empty(&self) -> bool {
+        self.free.is_empty()
+    }
+}
+
+/// Shared starting point for a matched [`Encoder`]/[`Decoder`] pair (and, with
+/// the `tokio` feature, a [`Codec`]), covering the options the two must agree
+/// on to understand each other's frames: the sentinel and `max_block`. Each
+/// type's own options (strictness, resync, stats, ...) stay exactly where
+/// they are, as `with_*` builder methods on [`Encoder`]/[`Decoder`]
+/// themselves — `CobsConfig` only saves re-typing the options both sides
+/// share before diverging into type-specific configuration.
+///
+/// ```
+/// use cobs_codec::CobsConfig;
+///
+/// let config = CobsConfig::new(0).with_max_block(16);
+/// let encoder = config.encoder();
+/// let decoder = config.decoder().with_resync(true);
+/// # let _ = (encoder, decoder);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct CobsConfig {
+    sentinel: u8,
+    max_block: u8,
+}
+
+impl CobsConfig {
+    /// Start from the given runtime `sentinel`, with the default `max_block`.
+    pub const fn new(sentinel: u8) -> Self {
+        Self {
+            sentinel,
+            max_block: DEFAULT_MAX_BLOCK,
+        }
+    }
+
+    /// See [`Encoder::with_max_block`]/[`Decoder::with_max_block`]. Deferred:
+    /// the panic for a `max_block` below `2` happens when [`CobsConfig::encoder`]
+    /// or [`CobsConfig::decoder`] applies it, not here.
+    pub const fn with_max_block(mut self, max_block: u8) -> Self {
+        self.max_block = max_block;
+        self
+    }
+
+    /// Build an [`Encoder`] from this configuration.
+    pub const fn encoder(self) -> Encoder {
+        Encoder::with_sentinel(self.sentinel).with_max_block(self.max_block)
+    }
+
+    /// Build a [`Decoder`] from this configuration.
+    pub const fn decoder(self) -> Decoder {
+        Decoder::with_sentinel(self.sentinel).with_max_block(self.max_block)
+    }
+
+    /// Build a [`Codec`] from this configuration.
+    #[cfg(feature = "tokio")]
+    pub const fn codec(self) -> Codec {
+        Codec {
+            encoder: self.encoder(),
+            decoder: self.decoder(),
+        }
+    }
+}
+
+/// Frames a byte stream by COBS-stuffing out the sentinel and appending it as a
+/// delimiter.
+#[derive(Debug, Clone)]
+pub struct Encoder {
+    sentinel: u8,
+    delimiter: bool,
+    leading_delimiter: bool,
+    max_block: u8,
+    #[cfg(feature = "tokio")]
+    stats: Option<CodecStats>,
+}
+
+impl Encoder {
+    /// Construct an encoder that frames on the given runtime `sentinel`.
+    pub const fn with_sentinel(sentinel: u8) -> Self {
+        Self {
+            sentinel,
+            delimiter: true,
+            leading_delimiter: false,
+            max_block: DEFAULT_MAX_BLOCK,
+            #[cfg(feature = "tokio")]
+            stats: None,
+        }
+    }
+
+    /// Const-generic constructor retained for the compile-time form; delegates
+    /// to [`Encoder::with_sentinel`].
+    pub const fn new<const SENTINEL: u8>() -> Self {
+        Self::with_sentinel(SENTINEL)
+    }
+
+    /// Opt in to accumulating a [`CodecStats`] as frames are encoded through
+    /// [`codec::Encoder`] (frame count, payload bytes, stuffed bytes). Off by
+    /// default. See [`Encoder::stats`].
+    #[cfg(feature = "tokio")]
+    pub const fn with_stats(mut self, enabled: bool) -> Self {
+        self.stats = if enabled { Some(CodecStats::new()) } else { None };
+        self
+    }
+
+    /// The counters accumulated so far, or `None` if [`Encoder::with_stats`]
+    /// was never enabled.
+    #[cfg(feature = "tokio")]
+    pub const fn stats(&self) -> Option<&CodecStats> {
+        self.stats.as_ref()
+    }
+
+    /// Opt out of appending the trailing sentinel: for peers that only place
+    /// the delimiter between frames (never after the last one) or that add
+    /// it themselves. On by default.
+    pub const fn with_delimiter(mut self, delimiter: bool) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Opt in to also prepending the sentinel before each encoded frame, for
+    /// delimiting frames on both sides so a receiver on a lossy serial link
+    /// can resynchronize from either end. [`Decoder`] already tolerates the
+    /// resulting empty frame between two adjacent delimiters (a trailing one
+    /// immediately followed by the next frame's leading one): destuffing an
+    /// empty slice yields an empty payload rather than an error. Off by
+    /// default.
+    pub const fn with_leading_delimiter(mut self, leading_delimiter: bool) -> Self {
+        self.leading_delimiter = leading_delimiter;
+        self
+    }
+
+    /// Cap a group at `max_block - 1` data bytes instead of the standard 254,
+    /// matching peers (some legacy hardware COBS implementations) that roll
+    /// over sooner to bound per-group latency. [`Decoder::with_max_block`]
+    /// must be set to the same value to destuff the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_block` is less than `2`, since a group needs room for
+    /// at least one data byte.
+    pub const fn with_max_block(mut self, max_block: u8) -> Self {
+        assert!(max_block >= 2, "max_block must allow at least one data byte per group");
+        self.max_block = max_block;
+        self
+    }
+
+    /// Stuff `data` and append a single terminated frame to `dst`, writing
+    /// straight into `dst` instead of allocating a temporary stuffed buffer.
+    /// Core primitive that only needs `alloc`; [`codec::Encoder`] builds on
+    /// it.
+    pub fn encode_frame_into(&self, data: &[u8], dst: &mut Vec<u8>) {
+        let s = self.sentinel;
+        #[cfg(any(feature = "tracing", feature = "metrics"))]
+        let before = dst.len();
+        dst.reserve(
+            max_encoded_len(data.len()) + self.leading_delimiter as usize + self.delimiter as usize,
+        );
+        if self.leading_delimiter {
+            dst.push(s);
+        }
+        stuff_into(data, s, self.max_block, dst);
+        if self.delimiter {
+            dst.push(s);
+        }
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            payload_len = data.len(),
+            stuffed_len = dst.len() - before,
+            "encoded cobs frame"
+        );
+        #[cfg(feature = "metrics")]
+        {
+            metrics::counter!("frames_encoded").increment(1);
+            metrics::histogram!("frame_size").record((dst.len() - before) as f64);
+        }
+    }
+
+    /// Stuff `data` and write a single terminated frame into `dst`, writing
+    /// straight into `dst` instead of allocating a temporary stuffed buffer.
+    /// Requires the `tokio` or `asynchronous-codec` feature; see
+    /// [`Encoder::encode_frame_into`] for the `no_std`-friendly primitive.
+    #[cfg(any(feature = "tokio", feature = "asynchronous-codec"))]
+    pub fn encode_frame(&self, data: &[u8], dst: &mut bytes::BytesMut) {
+        let s = self.sentinel;
+        dst.reserve(
+            max_encoded_len(data.len()) + self.leading_delimiter as usize + self.delimiter as usize,
+        );
+        if self.leading_delimiter {
+            dst.extend_from_slice(&[s]);
+        }
+        stuff_into(data, s, self.max_block, dst);
+        if self.delimiter {
+            dst.extend_from_slice(&[s]);
+        }
+    }
+
+    /// Stuff `chunks` as a single logical payload and append one terminated
+    /// frame to `dst`, without first concatenating them into a contiguous
+    /// buffer. For a frame assembled from a header slice plus a body slice
+    /// (or any other gather-style write). `no_std`-friendly counterpart of
+    /// [`Encoder::encode_vectored`].
+    pub fn encode_vectored_into<'d>(&self, chunks: impl IntoIterator<Item = &'d [u8]>, dst: &mut Vec<u8>) {
+        let s = self.sentinel;
+        if self.leading_delimiter {
+            dst.push(s);
+        }
+        stuff_vectored_into(chunks, s, self.max_block, dst);
+        if self.delimiter {
+            dst.push(s);
+        }
+    }
+
+    /// Stuff `chunks` as a single logical payload and write one terminated
+    /// frame into `dst`, without first concatenating them into a contiguous
+    /// buffer. Requires the `tokio` or `asynchronous-codec` feature; see
+    /// [`Encoder::encode_vectored_into`] for the `no_std`-friendly primitive.
+    #[cfg(any(feature = "tokio", feature = "asynchronous-codec"))]
+    pub fn encode_vectored<'d>(&self, chunks: impl IntoIterator<Item = &'d [u8]>, dst: &mut bytes::BytesMut) {
+        let s = self.sentinel;
+        if self.leading_delimiter {
+            dst.extend_from_slice(&[s]);
+        }
+        stuff_vectored_into(chunks, s, self.max_block, dst);
+        if self.delimiter {
+            dst.extend_from_slice(&[s]);
+        }
+    }
+
+    /// Stuff a payload produced lazily by `src` (a compressed stream, a
+    /// serializer yielding bytes as it goes) into a single terminated frame
+    /// appended to `dst`, without collecting `src` into a buffer first.
+    /// `no_std`-friendly counterpart of [`Encoder::encode_from_iter`].
+    pub fn encode_from_iter_into(&self, src: impl Iterator<Item = u8>, dst: &mut Vec<u8>) {
+        let s = self.sentinel;
+        if self.leading_delimiter {
+            dst.push(s);
+        }
+        stuff_from_iter(src, s, self.max_block, dst);
+        if self.delimiter {
+            dst.push(s);
+        }
+    }
+
+    /// Stuff a payload produced lazily by `src` into a single terminated
+    /// frame appended to `dst`. Requires the `tokio` or `asynchronous-codec`
+    /// feature; see [`Encoder::encode_from_iter_into`] for the
+    /// `no_std`-friendly primitive.
+    #[cfg(any(feature = "tokio", feature = "asynchronous-codec"))]
+    pub fn encode_from_iter(&self, src: impl Iterator<Item = u8>, dst: &mut bytes::BytesMut) {
+        let s = self.sentinel;
+        if self.leading_delimiter {
+            dst.extend_from_slice(&[s]);
+        }
+        stuff_from_iter(src, s, self.max_block, dst);
+        if self.delimiter {
+            dst.extend_from_slice(&[s]);
+        }
+    }
+
+    /// Stuff and append `frames` to `dst` back-to-back, one call instead of
+    /// looping [`Encoder::encode_frame`] per frame — for batching many small
+    /// frames into a single buffer before a syscall.
+    #[cfg(any(feature = "tokio", feature = "asynchronous-codec"))]
+    pub fn encode_all<F: AsRef<[u8]>>(&self, frames: impl IntoIterator<Item = F>, dst: &mut bytes::BytesMut) {
+        for frame in frames {
+            self.encode_frame(frame.as_ref(), dst);
+        }
+    }
+
+    /// Update [`Encoder::stats`], if enabled, after encoding one frame.
+    #[cfg(feature = "tokio")]
+    fn record_encode(&mut self, payload_len: usize, stuffed_len: usize) {
+        if let Some(stats) = &mut self.stats {
+            stats.frames += 1;
+            stats.payload_bytes += payload_len;
+            stats.stuffed_bytes += stuffed_len;
+        }
+    }
+}
+
+impl Default for Encoder {
+    fn default() -> Self {
+        Self::with_sentinel(0)
+    }
+}
+
+/// Alias for [`Encoder`], which already carries its sentinel as a runtime
+/// field rather than a const generic. Named for discoverability by anyone
+/// migrating from a compile-time-only COBS implementation.
+pub type DynEncoder = Encoder;
+
+#[cfg(feature = "tokio")]
+impl codec::Encoder<Vec<u8>> for Encoder {
+    type Error = CobsError;
+
+    fn encode(&mut self, item: Vec<u8>, dst: &mut bytes::BytesMut) -> Result<(), Self::Error> {
+        let before = dst.len();
+        self.encode_frame(&item, dst);
+        self.record_encode(item.len(), dst.len() - before);
+        Ok(())
+    }
+}
+
+/// So zero-copy senders don't need to clone a borrowed payload into a
+/// `Vec<u8>` just to satisfy [`codec::Encoder`].
+///
+/// A single `impl<B: bytes::Buf> codec::Encoder<B> for Encoder` would be
+/// nicer than enumerating types, but it conflicts with the `Vec<u8>` impl
+/// above: the compiler won't rule out `bytes` adding a `Buf` impl for
+/// `Vec<u8>` in a future version, so the two are treated as overlapping.
+#[cfg(feature = "tokio")]
+impl codec::Encoder<&[u8]> for Encoder {
+    type Error = CobsError;
+
+    fn encode(&mut self, item: &[u8], dst: &mut bytes::BytesMut) -> Result<(), Self::Error> {
+        let before = dst.len();
+        self.encode_frame(item, dst);
+        self.record_encode(item.len(), dst.len() - before);
+        Ok(())
+    }
+}
+
+/// See the `&[u8]` impl above for why this isn't a single blanket
+/// `impl<B: Buf>` instead.
+#[cfg(feature = "tokio")]
+impl codec::Encoder<bytes::Bytes> for Encoder {
+    type Error = CobsError;
+
+    fn encode(&mut self, item: bytes::Bytes, dst: &mut bytes::BytesMut) -> Result<(), Self::Error> {
+        let before = dst.len();
+        self.encode_frame(&item, dst);
+        self.record_encode(item.len(), dst.len() - before);
+        Ok(())
+    }
+}
+
+/// How [`Decoder`]'s `codec::Decoder` impl handles two delimiters arriving
+/// back to back (an empty frame), whether from a peer that sends keep-alive
+/// delimiters or one using [`Encoder::with_leading_delimiter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EmptyFrames {
+    /// Yield the empty frame like any other. The default, and the behavior
+    /// before this option existed.
+    #[default]
+    Yield,
+    /// Silently consume the delimiter and keep scanning for the next frame.
+    Skip,
+    /// Report [`CobsError::EmptyFrame`] instead of yielding or skipping it.
+    Error,
+}
+
+/// Result of [`Decoder::decode_frame_lossy`]: the payload decoded so far,
+/// plus what stopped it from decoding cleanly to the end, if anything.
+#[derive(Debug)]
+pub struct Salvage {
+    /// The successfully decoded prefix of the frame's payload. Equal to the
+    /// full payload when `error` is `None`.
+    pub payload: Vec<u8>,
+    /// Why decoding stopped early, or `None` if the frame was well-formed.
+    pub error: Option<CobsError>,
+}
+
+/// Reassembles frames produced by [`Encoder`], yielding each payload once its
+/// terminating sentinel is seen. Serializable with the `serde` feature, so a
+/// long-running process can checkpoint its decoder (sentinel, options, and
+/// counters) and restore it across a planned restart. Note that this only
+/// covers the `Decoder` itself: a partially-received frame's bytes live in
+/// the caller's own buffer (or a [`codec::Framed`]'s internal one), and must
+/// be checkpointed separately.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Decoder {
+    sentinel: u8,
+    strict: bool,
+    max_block: u8,
+    #[cfg(feature = "tokio")]
+    max_frame_len: Option<usize>,
+    #[cfg(feature = "tokio")]
+    resync: bool,
+    #[cfg(feature = "tokio")]
+    discarded: usize,
+    #[cfg(feature = "tokio")]
+    empty_frames: EmptyFrames,
+    #[cfg(feature = "tokio")]
+    stats: Option<CodecStats>,
+    #[cfg(feature = "tokio")]
+    shrink_after: Option<usize>,
+}
+
+/// A frame destuffed in place by [`Decoder::decode_next`], borrowing its
+/// payload straight out of the `bytes::BytesMut` it was decoded from instead
+/// of copying it into an owned buffer. Dropping it advances that buffer past
+/// the frame and its delimiter, so the next `decode_next` call sees
+/// whatever follows.
+#[cfg(feature = "tokio")]
+pub struct Frame<'a> {
+    buf: &'a mut bytes::BytesMut,
+    len: usize,
+    consumed: usize,
+}
+
+#[cfg(feature = "tokio")]
+impl core::ops::Deref for Frame<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl Drop for Frame<'_> {
+    fn drop(&mut self) {
+        use bytes::Buf;
+        self.buf.advance(self.consumed);
+    }
+}
+
+impl Decoder {
+    /// Construct a decoder that splits on the given runtime `sentinel`.
+    pub const fn with_sentinel(sentinel: u8) -> Self {
+        Self {
+            sentinel,
+            strict: false,
+            max_block: DEFAULT_MAX_BLOCK,
+            #[cfg(feature = "tokio")]
+            max_frame_len: None,
+            #[cfg(feature = "tokio")]
+            resync: false,
+            #[cfg(feature = "tokio")]
+            discarded: 0,
+            #[cfg(feature = "tokio")]
+            empty_frames: EmptyFrames::Yield,
+            #[cfg(feature = "tokio")]
+            stats: None,
+            #[cfg(feature = "tokio")]
+            shrink_after: None,
+        }
+    }
+
+    /// Const-generic constructor retained for the compile-time form; delegates
+    /// to [`Decoder::with_sentinel`].
+    pub const fn new<const SENTINEL: u8>() -> Self {
+        Self::with_sentinel(SENTINEL)
+    }
+
+    /// Cap the length of an in-progress frame: once more than `max_frame_len`
+    /// bytes have arrived without a delimiter, [`codec::Decoder::decode`]
+    /// discards them and reports [`CobsError::FrameTooLong`] instead of
+    /// buffering an unbounded amount of data from a corrupted stream.
+    #[cfg(feature = "tokio")]
+    pub const fn with_max_frame_len(mut self, max_frame_len: usize) -> Self {
+        self.max_frame_len = Some(max_frame_len);
+        self
+    }
+
+    /// Release `src`'s allocation once it's fully drained and its capacity
+    /// exceeds `threshold` bytes, instead of holding onto whatever the
+    /// largest frame seen so far grew it to forever. Off by default, since
+    /// reallocating on the next frame has a cost of its own that isn't worth
+    /// paying for links whose frame sizes don't vary wildly. Checked by
+    /// [`codec::Decoder::decode`] each time it drains `src` completely.
+    #[cfg(feature = "tokio")]
+    pub const fn with_shrink_after(mut self, threshold: usize) -> Self {
+        self.shrink_after = Some(threshold);
+        self
+    }
+
+    /// Opt in to skipping past a malformed frame instead of surfacing one
+    /// `Err` per bad frame: when a frame fails to destuff,
+    /// [`codec::Decoder::decode`] discards it and keeps scanning for the next
+    /// sentinel within the same call, so a run of corrupt frames drains
+    /// silently instead of needing one `decode()` call per frame. Off by
+    /// default, since most callers want to see and handle each error
+    /// themselves — either way the malformed frame's bytes are dropped from
+    /// `src` and the stream self-heals on the next well-formed frame; `resync`
+    /// only changes whether that costs an `Err` or not. Use
+    /// [`Decoder::discarded_bytes`] to track how much was dropped.
+    #[cfg(feature = "tokio")]
+    pub const fn with_resync(mut self, resync: bool) -> Self {
+        self.resync = resync;
+        self
+    }
+
+    /// Total bytes discarded from malformed or oversized frames so far (frame
+    /// content plus delimiter), whether or not [`Decoder::with_resync`] is on.
+    #[cfg(feature = "tokio")]
+    pub const fn discarded_bytes(&self) -> usize {
+        self.discarded
+    }
+
+    /// Discard bytes from `src` up to and including the next sentinel,
+    /// resynchronizing to the start of the next frame, and return how many
+    /// bytes were dropped. If no sentinel has arrived yet, discards all of
+    /// `src` and returns its length, leaving the caller to call this again
+    /// once more bytes have come in. Unlike [`Decoder::with_resync`], which
+    /// only kicks in once a frame has already failed to destuff, this lets a
+    /// caller resync on its own signal (an out-of-band break condition, a
+    /// protocol version mismatch) without waiting for that to happen.
+    #[cfg(feature = "tokio")]
+    pub fn skip_to_next_frame(&mut self, src: &mut bytes::BytesMut) -> usize {
+        use bytes::Buf;
+
+        let dropped = match find_sentinel(src, self.sentinel) {
+            Some(pos) => pos + 1,
+            None => src.len(),
+        };
+        src.advance(dropped);
+        self.discarded += dropped;
+        dropped
+    }
+
+    /// Choose how [`codec::Decoder::decode`] handles two delimiters arriving
+    /// back to back. See [`EmptyFrames`]. Yields the empty frame by default.
+    #[cfg(feature = "tokio")]
+    pub const fn with_empty_frames(mut self, empty_frames: EmptyFrames) -> Self {
+        self.empty_frames = empty_frames;
+        self
+    }
+
+    /// Opt in to accumulating a [`CodecStats`] as frames pass through
+    /// [`codec::Decoder::decode`] (frame count, payload/stuffed bytes, resync
+    /// events, malformed frames). Off by default. See [`Decoder::stats`].
+    #[cfg(feature = "tokio")]
+    pub const fn with_stats(mut self, enabled: bool) -> Self {
+        self.stats = if enabled { Some(CodecStats::new()) } else { None };
+        self
+    }
+
+    /// The counters accumulated so far, or `None` if [`Decoder::with_stats`]
+    /// was never enabled.
+    #[cfg(feature = "tokio")]
+    pub const fn stats(&self) -> Option<&CodecStats> {
+        self.stats.as_ref()
+    }
+
+    /// Reject frames whose code bytes aren't the minimal (canonical) COBS
+    /// encoding of their payload — for example a trailing empty group after
+    /// an exact multiple of 254 sentinel-free bytes, which destuffs cleanly
+    /// either way but only one form is what [`Encoder`] itself would produce.
+    /// Off by default, since accepting any well-formed frame is cheaper and
+    /// is what most peers expect. Useful as a lightweight integrity check on
+    /// untrusted input instead of re-encoding the payload to compare.
+    pub const fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Expect groups capped at `max_block - 1` data bytes instead of the
+    /// standard 254, matching a peer that rolls over sooner via
+    /// [`Encoder::with_max_block`]. Must agree with the value the frame was
+    /// stuffed with, or destuffing misreads where groups end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_block` is less than `2`, since a group needs room for
+    /// at least one data byte.
+    pub const fn with_max_block(mut self, max_block: u8) -> Self {
+        assert!(max_block >= 2, "max_block must allow at least one data byte per group");
+        self.max_block = max_block;
+        self
+    }
+
+    /// Destuff a frame's content (without the trailing delimiter). Core
+    /// primitive that only needs `alloc`.
+    pub fn decode_frame(&self, frame: &[u8]) -> Result<Vec<u8>, CobsError> {
+        let payload = match unstuff(frame, self.sentinel, self.max_block) {
+            Ok(payload) => payload,
+            Err(e) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(frame_len = frame.len(), error = %e, "cobs frame decode failed");
+                #[cfg(feature = "metrics")]
+                metrics::counter!("decode_errors").increment(1);
+                return Err(e);
+            }
+        };
+        if self.strict {
+            let canonical = stuff(&payload, self.max_block);
+            let s = self.sentinel;
+            let mismatch = canonical.len() != frame.len()
+                || canonical
+                    .iter()
+                    .zip(frame)
+                    .any(|(&c, &f)| (c ^ s) != f);
+            if mismatch {
+                let offset = canonical
+                    .iter()
+                    .zip(frame)
+                    .position(|(&c, &f)| (c ^ s) != f)
+                    .unwrap_or_else(|| canonical.len().min(frame.len()));
+                let e = CobsError::NonCanonicalEncoding { offset };
+                #[cfg(feature = "tracing")]
+                tracing::warn!(frame_len = frame.len(), error = %e, "cobs frame decode failed");
+                #[cfg(feature = "metrics")]
+                metrics::counter!("decode_errors").increment(1);
+                return Err(e);
+            }
+        }
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            frame_len = frame.len(),
+            payload_len = payload.len(),
+            "decoded cobs frame"
+        );
+        #[cfg(feature = "metrics")]
+        {
+            metrics::counter!("frames_decoded").increment(1);
+            metrics::histogram!("frame_size").record(frame.len() as f64);
+        }
+        Ok(payload)
+    }
+
+    /// Destuff as much of `frame` as possible, salvaging the decoded prefix
+    /// instead of discarding it when the frame turns out to be malformed or
+    /// truncated. For forensic analysis of corrupted captures, where a
+    /// partial payload is far more useful than nothing; callers that want
+    /// strict all-or-nothing decoding should use [`Decoder::decode_frame`].
+    pub fn decode_frame_lossy(&self, frame: &[u8]) -> Salvage {
+        let sentinel = self.sentinel;
+        let mut out = Vec::with_capacity(frame.len());
+        let mut i = 0;
+        let n = frame.len();
+        while i < n {
+            let code = frame[i] ^ sentinel;
+            if code == 0 {
+                return Salvage { payload: out, error: Some(CobsError::InvalidCodeByte { offset: i }) };
+            }
+            let block = code as usize;
+            let start = i + 1;
+            let end = start + block - 1;
+            if end > n {
+                // The group's length prefix promises more bytes than
+                // arrived; salvage whatever of it did.
+                out.extend(frame[start..n].iter().map(|&b| b ^ sentinel));
+                return Salvage { payload: out, error: Some(CobsError::UnexpectedSentinel { offset: n }) };
+            }
+            out.extend(frame[start..end].iter().map(|&b| b ^ sentinel));
+            i = end;
+            if block != self.max_block as usize && i < n {
+                out.push(0);
+            }
+        }
+        Salvage { payload: out, error: None }
+    }
+
+    /// Destuff `buf` in place and return the decoded length. The decoded
+    /// payload is always at most as long as its frame, so this never
+    /// allocates: `buf[..len]` holds the payload and the tail is left
+    /// untouched garbage.
+    pub fn decode_in_place(&self, buf: &mut [u8]) -> Result<usize, CobsError> {
+        let sentinel = self.sentinel;
+        let n = buf.len();
+        let mut read = 0;
+        let mut write = 0;
+        while read < n {
+            let code = buf[read] ^ sentinel;
+            if code == 0 {
+                return Err(CobsError::InvalidCodeByte { offset: read });
+            }
+            let block = code as usize;
+            let start = read + 1;
+            let end = start + block - 1;
+            if end > n {
+                return Err(CobsError::UnexpectedSentinel { offset: n });
+            }
+            for i in start..end {
+                buf[write] = buf[i] ^ sentinel;
+                write += 1;
+            }
+            read = end;
+            if block != self.max_block as usize && read < n {
+                buf[write] = 0;
+                write += 1;
+            }
+        }
+        Ok(write)
+    }
+
+    /// Check that `frame` destuffs cleanly and return the length its decoded
+    /// payload would have, without writing that payload anywhere. For
+    /// callers that only need to verify and forward a frame (a router
+    /// checking a frame is well-formed before relaying the original bytes
+    /// unchanged) and so have no use for [`Decoder::decode_frame`]'s
+    /// allocation or [`Decoder::decode_in_place`]'s destination buffer.
+    /// Like `decode_in_place`, this ignores [`Decoder::with_strict`]: there's
+    /// no payload to re-stuff and compare against.
+    pub fn validate(&self, frame: &[u8]) -> Result<usize, CobsError> {
+        let sentinel = self.sentinel;
+        let n = frame.len();
+        let mut read = 0;
+        let mut len = 0;
+        while read < n {
+            let code = frame[read] ^ sentinel;
+            if code == 0 {
+                return Err(CobsError::InvalidCodeByte { offset: read });
+            }
+            let block = code as usize;
+            let start = read + 1;
+            let end = start + block - 1;
+            if end > n {
+                return Err(CobsError::UnexpectedSentinel { offset: n });
+            }
+            len += end - start;
+            read = end;
+            if block != self.max_block as usize && read < n {
+                len += 1;
+            }
+        }
+        Ok(len)
+    }
+
+    /// Split one frame off `src` and decode it, reusing `src`'s allocation
+    /// instead of copying into a new buffer whenever the frame didn't need
+    /// any destuffing. That's the case exactly when the frame is a single
+    /// group spanning its whole length (no sentinel occurred in the payload)
+    /// and the sentinel is `0` (so no byte needs XORing either). High-
+    /// throughput links where most frames are sentinel-free skip the
+    /// allocation entirely; any other frame falls back to [`Decoder::decode_frame`].
+    /// The fast path only ever returns a frame's bytes unchanged, so a
+    /// [`Decoder::with_strict`] decoder always falls back to `decode_frame`
+    /// instead, to still catch a non-canonically stuffed single-group frame.
+    #[cfg(feature = "tokio")]
+    pub fn decode_zero_copy(
+        &self,
+        src: &mut bytes::BytesMut,
+    ) -> Result<Option<bytes::Bytes>, CobsError> {
+        use bytes::Buf;
+
+        match find_sentinel(src, self.sentinel) {
+            Some(pos) => {
+                let mut frame = src.split_to(pos);
+                let _delimiter = src.split_to(1);
+                if !self.strict
+                    && self.sentinel == 0
+                    && !frame.is_empty()
+                    && frame[0] as usize == frame.len()
+                {
+                    frame.advance(1);
+                    Ok(Some(frame.freeze()))
+                } else {
+                    let payload = self.decode_frame(&frame)?;
+                    Ok(Some(bytes::Bytes::from(payload)))
+                }
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Destuff the next frame in `src` in place and hand back a [`Frame`]
+    /// borrowing its payload, instead of [`Decoder::decode_frame`]'s owned
+    /// `Vec` or [`Decoder::decode_zero_copy`]'s `bytes::Bytes`. For a caller
+    /// that only needs to inspect a frame before moving on (checking a
+    /// header, validating a checksum) this skips both the allocation and the
+    /// refcount bump those incur, at the cost of `src` being unusable again
+    /// until the returned `Frame` is dropped, which advances `src` past the
+    /// frame and its delimiter. Returns `None` once `src` holds no complete
+    /// frame.
+    #[cfg(feature = "tokio")]
+    pub fn decode_next<'b>(
+        &self,
+        src: &'b mut bytes::BytesMut,
+    ) -> Option<Result<Frame<'b>, CobsError>> {
+        use bytes::Buf;
+
+        let pos = find_sentinel(src, self.sentinel)?;
+        match self.decode_in_place(&mut src[..pos]) {
+            Ok(len) => Some(Ok(Frame {
+                buf: src,
+                len,
+                consumed: pos + 1,
+            })),
+            Err(e) => {
+                src.advance(pos + 1);
+                Some(Err(e))
+            }
+        }
+    }
+
+    /// Destuff the next sentinel-terminated frame out of any [`bytes::Buf`]
+    /// source — a `Chain`, `VecDeque<u8>`, or other buffer backed by
+    /// non-contiguous storage — without flattening it into `BytesMut` first.
+    /// Consumes `src` through the delimiter on success; returns
+    /// [`CobsError::TruncatedFrame`] if `src` runs out before the delimiter
+    /// appears, consuming whatever was scanned in the process.
+    #[cfg(any(feature = "tokio", feature = "asynchronous-codec"))]
+    pub fn decode_frame_buf<B: bytes::Buf>(&self, src: &mut B) -> Result<Vec<u8>, CobsError> {
+        let sentinel = self.sentinel;
+        let mut frame = Vec::new();
+
+        while src.has_remaining() {
+            let chunk = src.chunk();
+            if let Some(pos) = chunk.iter().position(|&b| b == sentinel) {
+                frame.extend_from_slice(&chunk[..pos]);
+                src.advance(pos + 1);
+                return self.decode_frame(&frame);
+            }
+            frame.extend_from_slice(chunk);
+            let len = chunk.len();
+            src.advance(len);
+        }
+
+        Err(CobsError::TruncatedFrame { offset: frame.len() })
+    }
+
+    /// Drain every complete frame currently sitting in `src` in one call,
+    /// instead of one [`codec::Decoder::decode`] call (and the task wakeup
+    /// that comes with it through a [`codec::Framed`] stream) per frame. At
+    /// high frame rates, several frames often arrive in the same read; this
+    /// lets a proxy or other hot loop drain all of them per wakeup instead
+    /// of paying that overhead per frame.
+    ///
+    /// Stops at the first decode error, same as a single
+    /// [`codec::Decoder::decode`] call would; frames already drained into the
+    /// returned `Vec` are lost along with it. [`Decoder::with_resync`]
+    /// absorbs errors internally instead and keeps draining through them.
+    #[cfg(feature = "tokio")]
+    pub fn decode_many(&mut self, src: &mut bytes::BytesMut) -> Result<Vec<bytes::BytesMut>, CobsError> {
+        let mut frames = Vec::new();
+        while let Some(frame) = codec::Decoder::decode(self, src)? {
+            frames.push(frame);
+        }
+        Ok(frames)
+    }
+
+    /// Whether `buf` holds the start of a frame that hasn't seen its
+    /// terminating sentinel yet. [`Decoder`] itself keeps no buffer between
+    /// calls — the in-progress bytes live in whatever buffer the caller
+    /// passes to [`codec::Decoder::decode`], e.g. a `Framed`'s read buffer —
+    /// so this inspects that buffer directly.
+    #[cfg(any(feature = "tokio", feature = "asynchronous-codec"))]
+    pub fn is_mid_frame(&self, buf: &[u8]) -> bool {
+        !buf.is_empty() && find_sentinel(buf, self.sentinel).is_none()
+    }
+
+    /// How many of `buf`'s bytes belong to a not-yet-terminated frame; `0` if
+    /// `buf` is empty or already ends on a delimiter. See [`Decoder::is_mid_frame`].
+    #[cfg(any(feature = "tokio", feature = "asynchronous-codec"))]
+    pub fn buffered_len(&self, buf: &[u8]) -> usize {
+        if self.is_mid_frame(buf) {
+            buf.len()
+        } else {
+            0
+        }
+    }
+
+    /// Discard `buf`'s contents, e.g. to drop a partially received frame
+    /// after the underlying transport reconnects. Only clears the caller's
+    /// buffer — the decoder's own configuration (sentinel, resync, stats,
+    /// ...) is untouched, unlike constructing a fresh [`Decoder`].
+    #[cfg(any(feature = "tokio", feature = "asynchronous-codec"))]
+    pub fn reset(&self, buf: &mut bytes::BytesMut) {
+        buf.clear();
+    }
+}
+
+impl Default for Decoder {
+    fn default() -> Self {
+        Self::with_sentinel(0)
+    }
+}
+
+/// Logs the decoder's configuration and in-progress resync state, for
+/// tracing why a particular frame was accepted, discarded, or rejected.
+#[cfg(feature = "defmt")]
+impl defmt::Format for Decoder {
+    fn format(&self, fmt: defmt::Formatter) {
+        #[cfg(feature = "tokio")]
+        defmt::write!(
+            fmt,
+            "Decoder {{ sentinel: {}, max_frame_len: {}, resync: {}, discarded: {} }}",
+            self.sentinel,
+            self.max_frame_len,
+            self.resync,
+            self.discarded
+        );
+        #[cfg(not(feature = "tokio"))]
+        defmt::write!(fmt, "Decoder {{ sentinel: {} }}", self.sentinel);
+    }
+}
+
+/// Alias for [`Decoder`]; see [`DynEncoder`].
+pub type DynDecoder = Decoder;
+
+#[cfg(feature = "tokio")]
+impl codec::Decoder for Decoder {
+    type Item = bytes::BytesMut;
+    type Error = CobsError;
+
+    fn decode(&mut self, src: &mut bytes::BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        use bytes::Buf;
+
+        loop {
+            match find_sentinel(src, self.sentinel) {
+                Some(pos) => {
+                    if let Some(limit) = self.max_frame_len {
+                        if pos > limit {
+                            // Discard the oversized frame and its delimiter so
+                            // the next call resyncs on the following one.
+                            src.advance(pos + 1);
+                            self.discarded += pos + 1;
+                            if let Some(stats) = &mut self.stats {
+                                stats.malformed_frames += 1;
+                            }
+                            return Err(CobsError::FrameTooLong { limit });
+                        }
+                    }
+                    if pos == 0 {
+                        match self.empty_frames {
+                            EmptyFrames::Skip => {
+                                src.advance(1);
+                                continue;
+                            }
+                            EmptyFrames::Error => {
+                                src.advance(1);
+                                return Err(CobsError::EmptyFrame { offset: 0 });
+                            }
+                            EmptyFrames::Yield => {}
+                        }
+                    }
+                    let frame = src.split_to(pos);
+                    let _delimiter = src.split_to(1);
+                    match self.decode_frame(&frame) {
+                        Ok(payload) => {
+                            if let Some(stats) = &mut self.stats {
+                                stats.frames += 1;
+                                stats.payload_bytes += payload.len();
+                                stats.stuffed_bytes += frame.len();
+                            }
+                            return Ok(Some(bytes::BytesMut::from(&payload[..])));
+                        }
+                        Err(_) if self.resync => {
+                            self.discarded += frame.len() + 1;
+                            if let Some(stats) = &mut self.stats {
+                                stats.malformed_frames += 1;
+                                stats.resync_events += 1;
+                            }
+                            #[cfg(feature = "tracing")]
+                            tracing::warn!(
+                                discarded_len = frame.len() + 1,
+                                "resynced past malformed cobs frame"
+                            );
+                            continue;
+                        }
+                        Err(e) => {
+                            self.discarded += frame.len() + 1;
+                            if let Some(stats) = &mut self.stats {
+                                stats.malformed_frames += 1;
+                            }
+                            return Err(e);
+                        }
+                    }
+                }
+                None => {
+                    if let Some(limit) = self.max_frame_len {
+                        if src.len() > limit {
+                            self.discarded += src.len();
+                            src.clear();
+                            if let Some(stats) = &mut self.stats {
+                                stats.malformed_frames += 1;
+                            }
+                            return Err(CobsError::FrameTooLong { limit });
+                        }
+                    }
+                    if let Some(threshold) = self.shrink_after {
+                        if src.is_empty() && src.capacity() > threshold {
+                            *src = bytes::BytesMut::new();
+                        }
+                    }
+                    return Ok(None);
+                }
+            }
+        }
+    }
+
+    fn decode_eof(&mut self, src: &mut bytes::BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match self.decode(src)? {
+            Some(frame) => Ok(Some(frame)),
+            None if src.is_empty() => Ok(None),
+            // Bytes remain but no terminating sentinel arrived: the stream was
+            // cut mid-frame.
+            None => Err(CobsError::TruncatedFrame { offset: src.len() }),
+        }
+    }
+}
+
+#[cfg(feature = "asynchronous-codec")]
+impl asynchronous_codec::Encoder for Encoder {
+    type Item<'a> = &'a [u8];
+    type Error = CobsError;
+
+    fn encode(&mut self, item: Self::Item<'_>, dst: &mut bytes::BytesMut) -> Result<(), Self::Error> {
+        self.encode_frame(item, dst);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "asynchronous-codec")]
+impl asynchronous_codec::Decoder for Decoder {
+    type Item = bytes::BytesMut;
+    type Error = CobsError;
+
+    fn decode(&mut self, src: &mut bytes::BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match find_sentinel(src, self.sentinel) {
+            Some(pos) => {
+                let frame = src.split_to(pos);
+                let _delimiter = src.split_to(1);
+                let payload = self.decode_frame(&frame)?;
+                Ok(Some(bytes::BytesMut::from(&payload[..])))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+/// Combines an [`Encoder`] and a [`Decoder`] into a single type implementing
+/// both `codec::Encoder<Vec<u8>>` and `codec::Decoder`, the way
+/// `codec::Framed::new` wants one codec satisfying both traits instead of
+/// two separate ones.
+#[cfg(feature = "tokio")]
+#[derive(Debug, Clone, Default)]
+pub struct Codec {
+    encoder: Encoder,
+    decoder: Decoder,
+}
+
+#[cfg(feature = "tokio")]
+impl Codec {
+    /// Construct a codec that frames on the given runtime `sentinel`.
+    pub const fn with_sentinel(sentinel: u8) -> Self {
+        Self {
+            encoder: Encoder::with_sentinel(sentinel),
+            decoder: Decoder::with_sentinel(sentinel),
+        }
+    }
+
+    /// Const-generic constructor retained for the compile-time form; delegates
+    /// to [`Codec::with_sentinel`].
+    pub const fn new<const SENTINEL: u8>() -> Self {
+        Self::with_sentinel(SENTINEL)
+    }
+
+    /// See [`Decoder::with_max_frame_len`].
+    pub const fn with_max_frame_len(mut self, max_frame_len: usize) -> Self {
+        self.decoder = self.decoder.with_max_frame_len(max_frame_len);
+        self
+    }
+
+    /// See [`Decoder::with_shrink_after`].
+    pub const fn with_shrink_after(mut self, threshold: usize) -> Self {
+        self.decoder = self.decoder.with_shrink_after(threshold);
+        self
+    }
+
+    /// See [`Decoder::with_resync`].
+    pub const fn with_resync(mut self, resync: bool) -> Self {
+        self.decoder = self.decoder.with_resync(resync);
+        self
+    }
+
+    /// See [`Decoder::discarded_bytes`].
+    pub const fn discarded_bytes(&self) -> usize {
+        self.decoder.discarded_bytes()
+    }
+
+    /// See [`Decoder::skip_to_next_frame`].
+    pub fn skip_to_next_frame(&mut self, src: &mut bytes::BytesMut) -> usize {
+        self.decoder.skip_to_next_frame(src)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl codec::Encoder<Vec<u8>> for Codec {
+    type Error = CobsError;
+
+    fn encode(&mut self, item: Vec<u8>, dst: &mut bytes::BytesMut) -> Result<(), Self::Error> {
+        codec::Encoder::encode(&mut self.encoder, item, dst)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl codec::Decoder for Codec {
+    type Item = bytes::BytesMut;
+    type Error = CobsError;
+
+    fn decode(&mut self, src: &mut bytes::BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        codec::Decoder::decode(&mut self.decoder, src)
+    }
+
+    fn decode_eof(&mut self, src: &mut bytes::BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        codec::Decoder::decode_eof(&mut self.decoder, src)
+    }
+}
+
+/// Wrap `io` in a [`codec::Framed`] framing on sentinel `0`. Everyone writes
+/// `Framed::new(io, Codec::default())`; this just saves typing it out.
+#[cfg(feature = "tokio")]
+pub fn framed<IO>(io: IO) -> codec::Framed<IO, Codec> {
+    codec::Framed::new(io, Codec::default())
+}
+
+/// Wrap `io` in a [`codec::Framed`] framing on the given runtime `sentinel`.
+#[cfg(feature = "tokio")]
+pub fn framed_with_sentinel<IO>(io: IO, sentinel: u8) -> codec::Framed<IO, Codec> {
+    codec::Framed::new(io, Codec::with_sentinel(sentinel))
+}
+
+/// Like [`framed_with_sentinel`], but pre-sizing the internal read buffer to
+/// `capacity` bytes instead of `Framed`'s built-in default, for links whose
+/// typical frame size is known ahead of time.
+#[cfg(feature = "tokio")]
+pub fn framed_with_capacity<IO>(io: IO, sentinel: u8, capacity: usize) -> codec::Framed<IO, Codec> {
+    codec::Framed::with_capacity(io, Codec::with_sentinel(sentinel), capacity)
+}
+
+/// The "report" malformed-frame policy: a frame that fails to destuff comes
+/// back as `Err` wrapped in [`codec::Decoder::Item`] rather than
+/// [`codec::Decoder::Error`], so a [`codec::Framed`] stream built on it keeps
+/// running past a single corrupt frame instead of the error ending the
+/// stream. This is one of three ways callers can cope with malformed frames
+/// on a noisy link: a plain [`Decoder`] errors out (the default), and
+/// [`Decoder::with_resync`] silently skips past them; wrap in
+/// `ReportingDecoder` instead when the caller wants to see and count the bad
+/// frames without losing the rest of the stream.
+#[cfg(feature = "tokio")]
+#[derive(Debug, Clone, Default)]
+pub struct ReportingDecoder {
+    decoder: Decoder,
+}
+
+#[cfg(feature = "tokio")]
+impl ReportingDecoder {
+    /// Wrap a decoder that splits on the given runtime `sentinel`.
+    pub const fn with_sentinel(sentinel: u8) -> Self {
+        Self {
+            decoder: Decoder::with_sentinel(sentinel),
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl codec::Decoder for ReportingDecoder {
+    type Item = Result<bytes::BytesMut, CobsError>;
+    type Error = CobsError;
+
+    fn decode(&mut self, src: &mut bytes::BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match find_sentinel(src, self.decoder.sentinel) {
+            Some(pos) => {
+                let frame = src.split_to(pos);
+                let _delimiter = src.split_to(1);
+                Ok(Some(
+                    self.decoder
+                        .decode_frame(&frame)
+                        .map(|payload| bytes::BytesMut::from(&payload[..])),
+                ))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+/// Codec behavior observable via [`EventDecoder::with_on_event`]: a frame
+/// decoded, bytes discarded resyncing past a malformed or oversized frame, or
+/// a resync starting. Mirrors what [`CodecStats`] counts, as events instead
+/// of counters.
+#[cfg(feature = "tokio")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecoderEvent {
+    /// A frame was destuffed successfully.
+    FrameDecoded {
+        /// Length of the decoded payload.
+        payload_len: usize,
+        /// Length of the stuffed frame body, delimiter excluded.
+        stuffed_bytes: usize,
+    },
+    /// [`Decoder::with_resync`] skipped past a malformed or oversized frame
+    /// and is scanning for the next delimiter.
+    ResyncStarted,
+    /// Bytes were discarded resyncing past a malformed frame or one rejected
+    /// by [`Decoder::with_max_frame_len`].
+    BytesDiscarded {
+        /// Number of bytes discarded, delimiter included.
+        len: usize,
+    },
+}
+
+/// Wraps a [`Decoder`], invoking a callback with a [`DecoderEvent`] for every
+/// frame decoded or byte range discarded as frames pass through
+/// `codec::Decoder::decode`. For monitoring codec behavior (logging,
+/// forwarding to a metrics sink not already covered by the `metrics`
+/// feature) without changing the `Framed` item type the way
+/// [`ReportingDecoder`] does, or polling [`CodecStats`] after the fact.
+#[cfg(feature = "tokio")]
+pub struct EventDecoder<F> {
+    decoder: Decoder,
+    on_event: F,
+}
+
+#[cfg(feature = "tokio")]
+impl<F: FnMut(DecoderEvent)> EventDecoder<F> {
+    /// Wrap a decoder that splits on the given runtime `sentinel`, calling
+    /// `on_event` for every event observed.
+    pub fn with_sentinel(sentinel: u8, on_event: F) -> Self {
+        Self {
+            decoder: Decoder::with_sentinel(sentinel).with_stats(true),
+            on_event,
+        }
+    }
+
+    /// See [`Decoder::with_resync`].
+    pub fn with_resync(mut self, resync: bool) -> Self {
+        self.decoder = self.decoder.with_resync(resync);
+        self
+    }
+
+    /// See [`Decoder::with_max_frame_len`].
+    pub fn with_max_frame_len(mut self, max_frame_len: usize) -> Self {
+        self.decoder = self.decoder.with_max_frame_len(max_frame_len);
+        self
+    }
+
+    /// See [`Decoder::with_shrink_after`].
+    pub fn with_shrink_after(mut self, threshold: usize) -> Self {
+        self.decoder = self.decoder.with_shrink_after(threshold);
+        self
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<F: FnMut(DecoderEvent)> codec::Decoder for EventDecoder<F> {
+    type Item = bytes::BytesMut;
+    type Error = CobsError;
+
+    fn decode(&mut self, src: &mut bytes::BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let discarded_before = self.decoder.discarded_bytes();
+        let stats_before = self.decoder.stats().copied().unwrap_or_default();
+        let result = codec::Decoder::decode(&mut self.decoder, src);
+        let stats_after = self.decoder.stats().copied().unwrap_or_default();
+
+        // `decoder.decode` returns after at most one successful frame, so any
+        // stats/discard movement in this call belongs to it (and whatever it
+        // resynced or skipped past to get there).
+        for _ in 0..(stats_after.resync_events - stats_before.resync_events) {
+            (self.on_event)(DecoderEvent::ResyncStarted);
+        }
+        let discarded = self.decoder.discarded_bytes() - discarded_before;
+        if discarded > 0 {
+            (self.on_event)(DecoderEvent::BytesDiscarded { len: discarded });
+        }
+        if let Ok(Some(frame)) = &result {
+            (self.on_event)(DecoderEvent::FrameDecoded {
+                payload_len: frame.len(),
+                stuffed_bytes: stats_after.stuffed_bytes - stats_before.stuffed_bytes,
+            });
+        }
+        result
+    }
+}
+
+/// Wraps a [`Decoder`], drawing each decoded frame's backing buffer from a
+/// [`BufferPool`] instead of allocating fresh [`bytes::BytesMut`] storage
+/// every call. Decodes with [`Decoder::decode_in_place`] straight into the
+/// pooled buffer rather than [`Decoder::decode_frame`], so the pool actually
+/// eliminates the per-frame allocation instead of just moving it one copy
+/// later. Once the application is done with a yielded frame, return its
+/// buffer with [`PooledDecoder::release`] so the next `decode` call reuses
+/// it; frames that are never released just fall back to the allocator like
+/// a plain [`Decoder`] always does, so this is safe to adopt incrementally.
+#[cfg(feature = "tokio")]
+pub struct PooledDecoder {
+    decoder: Decoder,
+    pool: BufferPool,
+}
+
+#[cfg(feature = "tokio")]
+impl PooledDecoder {
+    /// Wrap a decoder that splits on the given runtime `sentinel`, with a
+    /// fresh, empty pool.
+    pub const fn with_sentinel(sentinel: u8) -> Self {
+        Self {
+            decoder: Decoder::with_sentinel(sentinel),
+            pool: BufferPool::new(),
+        }
+    }
+
+    /// Return `buf` (typically a frame this decoder previously yielded) to
+    /// the pool for reuse by the next `decode` call.
+    pub fn release(&mut self, buf: bytes::BytesMut) {
+        self.pool.release(buf);
+    }
+
+    /// Buffers currently sitting in the pool, ready for reuse.
+    pub fn pooled_buffers(&self) -> usize {
+        self.pool.len()
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl codec::Decoder for PooledDecoder {
+    type Item = bytes::BytesMut;
+    type Error = CobsError;
+
+    fn decode(&mut self, src: &mut bytes::BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match find_sentinel(src, self.decoder.sentinel) {
+            Some(pos) => {
+                let frame = src.split_to(pos);
+                let _delimiter = src.split_to(1);
+                let mut buf = self.pool.acquire();
+                buf.clear();
+                buf.extend_from_slice(&frame);
+                match self.decoder.decode_in_place(&mut buf) {
+                    Ok(len) => {
+                        buf.truncate(len);
+                        Ok(Some(buf))
+                    }
+                    Err(e) => {
+                        self.pool.release(buf);
+                        Err(e)
+                    }
+                }
+            }
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+//! A [`std::io::Read`] adapter that pulls bytes from an inner reader and
+//! decodes complete COBS frames, for blocking tools that read captured
+//! streams from files.
+
+use std::io::{self, Read};
+
+use crate::sans_io::PushDecoder;
+
+/// Wraps an inner [`Read`], destuffing complete frames out of the bytes it
+/// produces.
+pub struct CobsReader<R: Read> {
+    inner: R,
+    decoder: PushDecoder,
+}
+
+impl<R: Read> CobsReader<R> {
+    /// Wrap `inner`, framing on the given runtime `sentinel`.
+    pub fn new(sentinel: u8, inner: R) -> Self {
+        Self {
+            inner,
+            decoder: PushDecoder::with_sentinel(sentinel),
+        }
+    }
+
+    /// Pull bytes from the inner reader, appending the next decoded frame's
+    /// payload to `buf`. Returns `Ok(true)` once a frame was appended, or
+    /// `Ok(false)` if the inner reader hit EOF before completing one.
+    pub fn read_frame(&mut self, buf: &mut Vec<u8>) -> io::Result<bool> {
+        let mut byte = [0u8; 1];
+        loop {
+            if self.inner.read(&mut byte)? == 0 {
+                return Ok(false);
+            }
+            if let Some(frame) = self.decoder.feed(byte[0]) {
+                let frame = frame.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                buf.extend_from_slice(&frame);
+                return Ok(true);
+            }
+        }
+    }
+
+    /// Consume the reader, returning the inner one. Any partially-received
+    /// frame is discarded.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
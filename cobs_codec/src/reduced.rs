@@ -0,0 +1,176 @@
+//! COBS/R (reduced), which folds the final code byte into the last data byte
+//! when that saves a byte on the wire.
+//!
+//! A plain COBS frame always ends on a code byte. COBS/R instead checks,
+//! after stuffing, whether the final code byte's value is no greater than
+//! the frame's actual last data byte; if so the code byte is replaced by that
+//! data byte and the duplicate is dropped. [`EncoderR`]/[`DecoderR`] mirror
+//! [`crate::Encoder`]/[`crate::Decoder`] but speak this variant.
+
+use crate::{stuff, CobsError, DEFAULT_MAX_BLOCK};
+use alloc::vec::Vec;
+#[cfg(feature = "tokio")]
+use tokio_util::codec;
+
+/// Stuff `data`, then fold the final code byte into the last data byte when
+/// that's a win (COBS/R). The result never contains a `0x00` byte.
+fn stuff_r(data: &[u8]) -> Vec<u8> {
+    let mut out = stuff(data, DEFAULT_MAX_BLOCK);
+    if data.is_empty() {
+        return out;
+    }
+
+    // Walk the groups to find the final code byte's index.
+    let n = out.len();
+    let mut i = 0;
+    let mut last_code_idx = 0;
+    while i < n {
+        last_code_idx = i;
+        i += out[i] as usize;
+    }
+
+    // Only worth folding if the final group has a data byte to fold in, and
+    // that byte's value isn't itself ambiguous with a larger code.
+    if last_code_idx < n - 1 {
+        let code_val = out[last_code_idx];
+        let last_byte = out[n - 1];
+        if last_byte >= code_val {
+            out[last_code_idx] = last_byte;
+            out.pop();
+        }
+    }
+    out
+}
+
+/// Destuff a COBS/R frame's content (without the trailing delimiter).
+fn unstuff_r(frame: &[u8], sentinel: u8) -> Result<Vec<u8>, CobsError> {
+    let mut out = Vec::new();
+    let mut i = 0;
+    let n = frame.len();
+    while i < n {
+        let code = frame[i] ^ sentinel;
+        if code == 0 {
+            return Err(CobsError::InvalidCodeByte { offset: i });
+        }
+        let block = code as usize;
+        let start = i + 1;
+        let end = start + block - 1;
+        if end > n {
+            // The declared block runs past the end of the frame: the code
+            // byte was folded data, not a real code. Everything since is a
+            // plain data byte and the frame ends here.
+            for &b in &frame[start..n] {
+                out.push(b ^ sentinel);
+            }
+            out.push(code);
+            break;
+        }
+        for &b in &frame[start..end] {
+            out.push(b ^ sentinel);
+        }
+        i = end;
+        if block != 0xFF && i < n {
+            out.push(0);
+        }
+    }
+    Ok(out)
+}
+
+/// COBS/R encoder: same framing as [`crate::Encoder`], but the final code
+/// byte is folded into the last data byte when possible.
+#[derive(Debug, Clone)]
+pub struct EncoderR {
+    sentinel: u8,
+}
+
+impl EncoderR {
+    /// Construct a COBS/R encoder that frames on the given runtime `sentinel`.
+    pub const fn with_sentinel(sentinel: u8) -> Self {
+        Self { sentinel }
+    }
+
+    /// Stuff `data` and append a single terminated frame to `dst`.
+    pub fn encode_frame_into(&self, data: &[u8], dst: &mut Vec<u8>) {
+        let s = self.sentinel;
+        let stuffed = stuff_r(data);
+        dst.reserve(stuffed.len() + 1);
+        dst.extend(stuffed.into_iter().map(|b| b ^ s));
+        dst.push(s);
+    }
+
+    /// Stuff `data` and write a single terminated frame into `dst`. Requires
+    /// the `tokio` feature.
+    #[cfg(feature = "tokio")]
+    pub fn encode_frame(&self, data: &[u8], dst: &mut bytes::BytesMut) {
+        let mut buf = Vec::new();
+        self.encode_frame_into(data, &mut buf);
+        dst.extend_from_slice(&buf);
+    }
+}
+
+impl Default for EncoderR {
+    fn default() -> Self {
+        Self::with_sentinel(0)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl codec::Encoder<Vec<u8>> for EncoderR {
+    type Error = CobsError;
+
+    fn encode(&mut self, item: Vec<u8>, dst: &mut bytes::BytesMut) -> Result<(), Self::Error> {
+        self.encode_frame(&item, dst);
+        Ok(())
+    }
+}
+
+/// COBS/R decoder: same reassembly as [`crate::Decoder`], but understands a
+/// final code byte folded into the last data byte.
+#[derive(Debug, Clone)]
+pub struct DecoderR {
+    sentinel: u8,
+}
+
+impl DecoderR {
+    /// Construct a COBS/R decoder that splits on the given runtime `sentinel`.
+    pub const fn with_sentinel(sentinel: u8) -> Self {
+        Self { sentinel }
+    }
+
+    /// Destuff a frame's content (without the trailing delimiter).
+    pub fn decode_frame(&self, frame: &[u8]) -> Result<Vec<u8>, CobsError> {
+        unstuff_r(frame, self.sentinel)
+    }
+}
+
+impl Default for DecoderR {
+    fn default() -> Self {
+        Self::with_sentinel(0)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl codec::Decoder for DecoderR {
+    type Item = bytes::BytesMut;
+    type Error = CobsError;
+
+    fn decode(&mut self, src: &mut bytes::BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match crate::find_sentinel(src, self.sentinel) {
+            Some(pos) => {
+                let frame = src.split_to(pos);
+                let _delimiter = src.split_to(1);
+                let payload = self.decode_frame(&frame)?;
+                Ok(Some(bytes::BytesMut::from(&payload[..])))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn decode_eof(&mut self, src: &mut bytes::BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match self.decode(src)? {
+            Some(frame) => Ok(Some(frame)),
+            None if src.is_empty() => Ok(None),
+            None => Err(CobsError::TruncatedFrame { offset: src.len() }),
+        }
+    }
+}
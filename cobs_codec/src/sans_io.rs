@@ -0,0 +1,48 @@
+//! A sans-io, push-based decoder for drivers that can only hand over one byte
+//! (or a small chunk) at a time, such as an interrupt-driven UART RX handler.
+//! Unlike [`crate::Decoder`] it needs neither `BytesMut` nor `tokio-util`.
+
+use crate::{CobsError, Decoder};
+use alloc::vec::Vec;
+
+/// Byte-at-a-time COBS decoder. Feed it bytes as they arrive; it reports a
+/// completed frame (or the error from decoding it) each time the sentinel is
+/// seen.
+#[derive(Debug, Clone)]
+pub struct PushDecoder {
+    sentinel: u8,
+    buf: Vec<u8>,
+}
+
+impl PushDecoder {
+    /// Construct a push decoder that splits on the given runtime `sentinel`.
+    pub const fn with_sentinel(sentinel: u8) -> Self {
+        Self {
+            sentinel,
+            buf: Vec::new(),
+        }
+    }
+
+    /// Feed a single byte. Returns `Some` with the decoded frame (or the
+    /// error from decoding it) once `byte` completes a frame.
+    pub fn feed(&mut self, byte: u8) -> Option<Result<Vec<u8>, CobsError>> {
+        if byte == self.sentinel {
+            let frame = core::mem::take(&mut self.buf);
+            Some(Decoder::with_sentinel(self.sentinel).decode_frame(&frame))
+        } else {
+            self.buf.push(byte);
+            None
+        }
+    }
+
+    /// Feed a chunk of bytes, collecting every frame (or decode error)
+    /// completed along the way, in order.
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<Result<Vec<u8>, CobsError>> {
+        chunk.iter().filter_map(|&b| self.feed(b)).collect()
+    }
+
+    /// Number of bytes buffered for the frame currently in progress.
+    pub fn pending_len(&self) -> usize {
+        self.buf.len()
+    }
+}
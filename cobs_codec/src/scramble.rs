@@ -0,0 +1,84 @@
+//! A COBS framing layer that runs each frame's stuffed bytes through a
+//! caller-supplied, self-inverse [`Transform`] after encoding and before
+//! decoding, for links (an RF radio wanting a whitened spectrum) that need
+//! more than COBS's own zero-byte removal.
+//!
+//! The transform only ever sees the stuffed frame body, never the trailing
+//! delimiter, so a [`Decoder`](crate::Decoder) scanning a stream for frame
+//! boundaries still finds them without needing to know about the transform.
+
+use alloc::vec::Vec;
+
+use crate::{CobsError, Decoder, Encoder};
+
+/// A reversible transform applied to a frame's stuffed bytes. Must be its
+/// own inverse: the same `apply` call undoes what an earlier call to it did,
+/// since [`ScrambledCobsCodec`] uses one instance for both directions.
+pub trait Transform {
+    /// Transform `buf` in place.
+    fn apply(&mut self, buf: &mut [u8]);
+}
+
+impl<F: FnMut(&mut [u8])> Transform for F {
+    fn apply(&mut self, buf: &mut [u8]) {
+        self(buf)
+    }
+}
+
+/// XOR-whitens bytes against a fixed keystream, repeating it from the start
+/// for every frame. A minimal, dependency-free stand-in for a PRBS whitening
+/// sequence; self-inverse like any XOR keystream. An empty keystream leaves
+/// bytes untouched.
+#[derive(Debug, Clone, Copy)]
+pub struct XorWhitening {
+    keystream: &'static [u8],
+}
+
+impl XorWhitening {
+    /// Construct a whitener that repeats `keystream` across each frame.
+    pub const fn new(keystream: &'static [u8]) -> Self {
+        Self { keystream }
+    }
+}
+
+impl Transform for XorWhitening {
+    fn apply(&mut self, buf: &mut [u8]) {
+        for (b, k) in buf.iter_mut().zip(self.keystream.iter().cycle()) {
+            *b ^= k;
+        }
+    }
+}
+
+/// Wraps [`Encoder`]/[`Decoder`] to run a [`Transform`] over each frame's
+/// stuffed bytes, after stuffing on encode and before destuffing on decode.
+pub struct ScrambledCobsCodec<T> {
+    sentinel: u8,
+    transform: T,
+}
+
+impl<T: Transform> ScrambledCobsCodec<T> {
+    /// Construct a codec that frames on `sentinel` and scrambles each
+    /// frame's stuffed bytes with `transform`.
+    pub const fn new(sentinel: u8, transform: T) -> Self {
+        Self { sentinel, transform }
+    }
+
+    /// Stuff `data`, scramble the result, and push it (plus an untouched
+    /// delimiter) onto `dst`.
+    pub fn encode_frame(&mut self, data: &[u8], dst: &mut Vec<u8>) {
+        let start = dst.len();
+        Encoder::with_sentinel(self.sentinel)
+            .with_delimiter(false)
+            .encode_frame_into(data, dst);
+        self.transform.apply(&mut dst[start..]);
+        dst.push(self.sentinel);
+    }
+
+    /// Descramble `frame` (its stuffed content, without the trailing
+    /// delimiter) and destuff the result.
+    pub fn decode_frame(&mut self, frame: &[u8]) -> Result<Vec<u8>, CobsError> {
+        let mut descrambled = frame.to_vec();
+        self.transform.apply(&mut descrambled);
+        Decoder::with_sentinel(self.sentinel).decode_frame(&descrambled)
+    }
+}
@@ -0,0 +1,123 @@
+//! A sequence-numbered framing layer for lossy links: [`SequencedEncoder`]
+//! prepends an incrementing sequence number inside each frame, and
+//! [`SequencedDecoder`] reports a [`CobsError::FrameLost`] gap whenever a
+//! decoded frame's sequence number isn't the one immediately following the
+//! last, so a dropped frame is noticed instead of silently skipped.
+//!
+//! Unlike [`crate::fragment::ReassemblingDecoder`], which only numbers the
+//! fragments of one message, this layer numbers every frame on the link, so
+//! it also catches whole frames lost between messages.
+
+use crate::{CobsError, Decoder, Encoder};
+use alloc::vec::Vec;
+
+/// How wide a sequence number to prepend: a single wrapping byte, or a
+/// wrapping little-endian word for links expected to carry many frames
+/// between restarts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeqWidth {
+    /// A single byte, wrapping every 256 frames.
+    Byte,
+    /// A little-endian `u16`, wrapping every 65536 frames.
+    Word,
+}
+
+impl SeqWidth {
+    const fn len(self) -> usize {
+        match self {
+            SeqWidth::Byte => 1,
+            SeqWidth::Word => 2,
+        }
+    }
+
+    const fn modulus(self) -> u32 {
+        match self {
+            SeqWidth::Byte => 1 << 8,
+            SeqWidth::Word => 1 << 16,
+        }
+    }
+}
+
+/// Prepends an incrementing sequence number inside each frame before COBS
+/// stuffing it.
+#[derive(Debug, Clone)]
+pub struct SequencedEncoder {
+    sentinel: u8,
+    width: SeqWidth,
+    next_seq: u32,
+}
+
+impl SequencedEncoder {
+    /// Construct an encoder that frames on `sentinel` and numbers frames
+    /// with the given `width`, starting from `0`.
+    pub const fn new(sentinel: u8, width: SeqWidth) -> Self {
+        Self {
+            sentinel,
+            width,
+            next_seq: 0,
+        }
+    }
+
+    /// Prepend the next sequence number to `data` and append it as a single
+    /// terminated COBS frame to `dst`.
+    pub fn encode_frame_into(&mut self, data: &[u8], dst: &mut Vec<u8>) {
+        let mut framed = Vec::with_capacity(self.width.len() + data.len());
+        match self.width {
+            SeqWidth::Byte => framed.push(self.next_seq as u8),
+            SeqWidth::Word => framed.extend_from_slice(&(self.next_seq as u16).to_le_bytes()),
+        }
+        framed.extend_from_slice(data);
+        Encoder::with_sentinel(self.sentinel).encode_frame_into(&framed, dst);
+        self.next_seq = (self.next_seq + 1) % self.width.modulus();
+    }
+}
+
+/// Destuffs frames produced by [`SequencedEncoder`] and checks that each
+/// one's sequence number immediately follows the last.
+#[derive(Debug, Clone)]
+pub struct SequencedDecoder {
+    decoder: Decoder,
+    width: SeqWidth,
+    next_seq: u32,
+}
+
+impl SequencedDecoder {
+    /// Construct a decoder that splits on `sentinel` and expects sequence
+    /// numbers of the given `width`, starting from `0`.
+    pub const fn with_sentinel(sentinel: u8, width: SeqWidth) -> Self {
+        Self {
+            decoder: Decoder::with_sentinel(sentinel),
+            width,
+            next_seq: 0,
+        }
+    }
+
+    /// Destuff `frame`, split off its leading sequence number, and check it
+    /// against the expected one, returning the remaining payload either way.
+    ///
+    /// On a gap, the expected sequence number resyncs to just past the one
+    /// actually received, so a single dropped frame is reported once rather
+    /// than on every frame that follows it.
+    pub fn decode_frame(&mut self, frame: &[u8]) -> Result<Vec<u8>, CobsError> {
+        let framed = self.decoder.decode_frame(frame)?;
+        let header_len = self.width.len();
+        if framed.len() < header_len {
+            return Err(CobsError::TruncatedFrame {
+                offset: framed.len(),
+            });
+        }
+        let seq = match self.width {
+            SeqWidth::Byte => framed[0] as u32,
+            SeqWidth::Word => u16::from_le_bytes([framed[0], framed[1]]) as u32,
+        };
+        let expected = self.next_seq;
+        self.next_seq = (seq + 1) % self.width.modulus();
+        if seq != expected {
+            return Err(CobsError::FrameLost {
+                expected,
+                got: seq,
+            });
+        }
+        Ok(framed[header_len..].to_vec())
+    }
+}
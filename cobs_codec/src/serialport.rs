@@ -0,0 +1,59 @@
+//! Helpers for talking COBS frames over a blocking
+//! [`serialport`](https://docs.rs/serialport) port, for synchronous,
+//! non-async provisioning tools that hand-roll a send-command/await-reply
+//! loop around this crate's decoder today.
+
+use alloc::vec::Vec;
+use std::io::{Read, Write};
+use std::time::Duration;
+
+use crate::sans_io::PushDecoder;
+use crate::stream::StreamEncoder;
+use crate::CobsError;
+
+/// Open the serial port at `path` running at `baud_rate`, with reads timing
+/// out after `timeout` instead of blocking forever for a byte that never
+/// arrives.
+pub fn open_serial_port(
+    path: &str,
+    baud_rate: u32,
+    timeout: Duration,
+) -> serialport::Result<Box<dyn serialport::SerialPort>> {
+    serialport::new(path, baud_rate).timeout(timeout).open()
+}
+
+/// Stuff `data` into a single terminated frame and write it to `port`.
+pub fn send_frame(sentinel: u8, port: &mut dyn Write, data: &[u8]) -> Result<(), CobsError> {
+    let mut encoder = StreamEncoder::with_sentinel(sentinel);
+    let mut scratch = Vec::new();
+    encoder.start_frame();
+    encoder.write(data, &mut scratch);
+    encoder.finish(&mut scratch);
+    port.write_all(&scratch)?;
+    Ok(())
+}
+
+/// Read from `port` one byte at a time until a full COBS frame has been
+/// seen, and return its decoded payload. If `port`'s configured read
+/// timeout elapses while waiting on the next byte, returns
+/// [`CobsError::Stalled`] instead of the underlying I/O error, discarding
+/// whatever of the frame had been buffered so far.
+pub fn recv_frame(sentinel: u8, port: &mut dyn Read) -> Result<Vec<u8>, CobsError> {
+    let mut decoder = PushDecoder::with_sentinel(sentinel);
+    let mut byte = [0u8; 1];
+    loop {
+        match port.read_exact(&mut byte) {
+            Ok(()) => {
+                if let Some(frame) = decoder.feed(byte[0]) {
+                    return frame;
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::TimedOut => {
+                return Err(CobsError::Stalled {
+                    buffered: decoder.pending_len(),
+                })
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! A push-style encoder for frames assembled from several chunks, so the
+//! payload never needs to be concatenated up front before stuffing.
+
+use alloc::vec::Vec;
+
+/// Incrementally stuffs a frame from data arriving in pieces:
+/// [`StreamEncoder::start_frame`], any number of [`StreamEncoder::write`]
+/// calls, then [`StreamEncoder::finish`]. At most one code block (254 bytes)
+/// is held in memory regardless of how the writes are chunked.
+#[derive(Debug, Clone)]
+pub struct StreamEncoder {
+    sentinel: u8,
+    block: Vec<u8>,
+}
+
+impl StreamEncoder {
+    /// Construct a streaming encoder that frames on the given runtime `sentinel`.
+    pub const fn with_sentinel(sentinel: u8) -> Self {
+        Self {
+            sentinel,
+            block: Vec::new(),
+        }
+    }
+
+    /// Begin a new frame, discarding any block left over from an unfinished one.
+    pub fn start_frame(&mut self) {
+        self.block.clear();
+    }
+
+    /// Stuff and append another piece of the frame's payload to `dst`.
+    pub fn write(&mut self, data: &[u8], dst: &mut Vec<u8>) {
+        for &byte in data {
+            if byte == 0 {
+                self.emit_block(dst);
+            } else {
+                self.block.push(byte);
+                if self.block.len() == 254 {
+                    self.emit_block(dst);
+                }
+            }
+        }
+    }
+
+    /// Flush the trailing block and terminate the frame with the sentinel.
+    pub fn finish(&mut self, dst: &mut Vec<u8>) {
+        self.emit_block(dst);
+        dst.push(self.sentinel);
+    }
+
+    /// Stuff the completed block (the non-zero run since the last delimiter)
+    /// into `dst` in the sentinel transmission domain, and reset it.
+    fn emit_block(&mut self, dst: &mut Vec<u8>) {
+        let s = self.sentinel;
+        dst.reserve(self.block.len() + 1);
+        dst.push((self.block.len() as u8 + 1) ^ s);
+        dst.extend(self.block.iter().map(|&b| b ^ s));
+        self.block.clear();
+    }
+}
@@ -0,0 +1,83 @@
+//! Extension traits for composing the codec into tower/stream pipelines
+//! with a single method call instead of manually building a [`Framed`] or a
+//! decode loop.
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use bytes::BytesMut;
+use futures_util::Stream;
+use tokio_util::codec::{self, Framed};
+
+use crate::{CobsError, Codec, Decoder};
+
+/// Adds [`CobsTransportExt::cobs_framed`] to any I/O type usable with
+/// [`Framed`].
+pub trait CobsTransportExt: Sized {
+    /// Wrap `self` in a [`Framed`] transport framing on the compile-time
+    /// `SENTINEL`. See [`crate::framed_with_sentinel`] for a runtime-sentinel
+    /// equivalent.
+    fn cobs_framed<const SENTINEL: u8>(self) -> Framed<Self, Codec> {
+        Framed::new(self, Codec::new::<SENTINEL>())
+    }
+}
+
+impl<T> CobsTransportExt for T {}
+
+/// Adds [`CobsStreamExt::cobs_decode`] to any stream of raw byte chunks.
+pub trait CobsStreamExt: Stream<Item = BytesMut> + Sized {
+    /// Destuff COBS frames out of a stream of raw byte chunks (e.g. reads off
+    /// a socket, unrelated to frame boundaries), yielding one decoded
+    /// payload per frame.
+    fn cobs_decode(self, sentinel: u8) -> CobsDecodedStream<Self> {
+        CobsDecodedStream {
+            inner: self,
+            decoder: Decoder::with_sentinel(sentinel),
+            buf: BytesMut::new(),
+            done: false,
+        }
+    }
+}
+
+impl<S: Stream<Item = BytesMut>> CobsStreamExt for S {}
+
+/// Stream returned by [`CobsStreamExt::cobs_decode`].
+pub struct CobsDecodedStream<S> {
+    inner: S,
+    decoder: Decoder,
+    buf: BytesMut,
+    done: bool,
+}
+
+impl<S: Stream<Item = BytesMut> + Unpin> Stream for CobsDecodedStream<S> {
+    type Item = Result<BytesMut, CobsError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.done {
+            return Poll::Ready(None);
+        }
+        loop {
+            match codec::Decoder::decode(&mut this.decoder, &mut this.buf) {
+                Ok(Some(frame)) => return Poll::Ready(Some(Ok(frame))),
+                Ok(None) => {}
+                Err(e) => {
+                    this.done = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
+            match Pin::new(&mut this.inner).poll_next(cx) {
+                Poll::Ready(Some(chunk)) => this.buf.extend_from_slice(&chunk),
+                Poll::Ready(None) => {
+                    this.done = true;
+                    return match codec::Decoder::decode_eof(&mut this.decoder, &mut this.buf) {
+                        Ok(Some(frame)) => Poll::Ready(Some(Ok(frame))),
+                        Ok(None) => Poll::Ready(None),
+                        Err(e) => Poll::Ready(Some(Err(e))),
+                    };
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
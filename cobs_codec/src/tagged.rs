@@ -0,0 +1,61 @@
+//! A COBS framing layer that prepends a one-byte tag to each frame's
+//! payload, for multiplexing several logical message types (or channels)
+//! over a single link without building a whole separate transport per type.
+//!
+//! Unlike [`sequence`](crate::sequence), which numbers every frame on the
+//! link, the tag here is caller-defined: it might select an enum variant, a
+//! channel number, or anything else a demultiplexing match arm can switch
+//! on.
+
+use alloc::vec::Vec;
+
+use crate::{CobsError, Decoder, Encoder};
+
+/// Prepends a tag byte to each frame before COBS stuffing it.
+#[derive(Debug, Clone)]
+pub struct TaggedEncoder {
+    sentinel: u8,
+}
+
+impl TaggedEncoder {
+    /// Construct an encoder that frames on `sentinel`.
+    pub const fn new(sentinel: u8) -> Self {
+        Self { sentinel }
+    }
+
+    /// Prepend `tag` to `data` and append it as a single terminated COBS
+    /// frame to `dst`.
+    pub fn encode_frame_into(&self, tag: u8, data: &[u8], dst: &mut Vec<u8>) {
+        let mut tagged = Vec::with_capacity(1 + data.len());
+        tagged.push(tag);
+        tagged.extend_from_slice(data);
+        Encoder::with_sentinel(self.sentinel).encode_frame_into(&tagged, dst);
+    }
+}
+
+/// Destuffs frames produced by [`TaggedEncoder`] and splits off the leading
+/// tag byte, so a caller can demultiplex on it directly.
+#[derive(Debug, Clone)]
+pub struct TaggedDecoder {
+    decoder: Decoder,
+}
+
+impl TaggedDecoder {
+    /// Construct a decoder that frames on `sentinel`.
+    pub const fn new(sentinel: u8) -> Self {
+        Self {
+            decoder: Decoder::with_sentinel(sentinel),
+        }
+    }
+
+    /// Destuff `frame` (its content, without the trailing delimiter) and
+    /// split off its leading tag byte, returning `(tag, payload)`.
+    pub fn decode_frame(&self, frame: &[u8]) -> Result<(u8, Vec<u8>), CobsError> {
+        let mut payload = self.decoder.decode_frame(frame)?;
+        if payload.is_empty() {
+            return Err(CobsError::TruncatedFrame { offset: 0 });
+        }
+        let tag = payload.remove(0);
+        Ok((tag, payload))
+    }
+}
@@ -0,0 +1,141 @@
+//! Free functions over `tokio::io::{AsyncRead, AsyncWrite}`, for a one-shot
+//! request/response exchange (a command sent down a serial port, a single
+//! reply read back) where setting up a `Framed`/`FramedRead` transport is
+//! more machinery than the job needs.
+
+use alloc::vec::Vec;
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+#[cfg(feature = "tokio-time")]
+use tokio::time::Duration;
+
+use crate::sans_io::PushDecoder;
+use crate::stream::StreamEncoder;
+use crate::CobsError;
+
+/// Stuff `data` into a single terminated frame and write it to `writer`.
+pub async fn write_frame(
+    sentinel: u8,
+    writer: &mut (impl AsyncWrite + Unpin),
+    data: &[u8],
+) -> Result<(), CobsError> {
+    let mut encoder = StreamEncoder::with_sentinel(sentinel);
+    let mut scratch = Vec::new();
+    encoder.start_frame();
+    encoder.write(data, &mut scratch);
+    encoder.finish(&mut scratch);
+    writer.write_all(&scratch).await?;
+    Ok(())
+}
+
+/// Read from `reader` one byte at a time until a full COBS frame has been
+/// seen, and return its decoded payload.
+pub async fn read_frame(
+    sentinel: u8,
+    reader: &mut (impl AsyncRead + Unpin),
+) -> Result<Vec<u8>, CobsError> {
+    let mut decoder = PushDecoder::with_sentinel(sentinel);
+    let mut byte = [0u8; 1];
+    loop {
+        reader.read_exact(&mut byte).await?;
+        if let Some(frame) = decoder.feed(byte[0]) {
+            return frame;
+        }
+    }
+}
+
+/// Writes frames to an inner [`AsyncWrite`], with a choice of whether the
+/// transport is flushed after every frame or only when [`FramedWriter::flush`]
+/// is called explicitly. [`write_frame`] always leaves flushing to the
+/// caller; for latency-sensitive request/response traffic (a command sent
+/// down a serial port expecting an immediate reply) that's an easy thing to
+/// forget, while bulk telemetry wants to batch several frames per flush on
+/// purpose. Defaults to flushing after every frame, matching
+/// `SinkExt::send`'s behavior on a `Framed` built from this crate's
+/// [`Codec`](crate::Codec).
+pub struct FramedWriter<W> {
+    sentinel: u8,
+    auto_flush: bool,
+    writer: W,
+}
+
+impl<W: AsyncWrite + Unpin> FramedWriter<W> {
+    /// Wrap `writer`, framing on the given runtime `sentinel`.
+    pub const fn new(sentinel: u8, writer: W) -> Self {
+        Self {
+            sentinel,
+            auto_flush: true,
+            writer,
+        }
+    }
+
+    /// Set whether [`FramedWriter::write_frame`] flushes the transport after
+    /// writing the frame. Disable this for batched writes and call
+    /// [`FramedWriter::flush`] at the batch boundary instead.
+    pub const fn with_auto_flush(mut self, auto_flush: bool) -> Self {
+        self.auto_flush = auto_flush;
+        self
+    }
+
+    /// Stuff `data` into a single terminated frame, write it, and flush the
+    /// transport if auto-flush is enabled.
+    pub async fn write_frame(&mut self, data: &[u8]) -> Result<(), CobsError> {
+        write_frame(self.sentinel, &mut self.writer, data).await?;
+        if self.auto_flush {
+            self.writer.flush().await?;
+        }
+        Ok(())
+    }
+
+    /// Flush the transport, regardless of auto-flush. For draining a batch
+    /// of frames written with auto-flush disabled.
+    pub async fn flush(&mut self) -> Result<(), CobsError> {
+        self.writer.flush().await?;
+        Ok(())
+    }
+
+    /// Borrow the wrapped writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Mutably borrow the wrapped writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Consume `self` and return the wrapped writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Like [`read_frame`], but gives up and returns [`CobsError::Stalled`] if
+/// `timeout` elapses while waiting on the next byte, discarding whatever of
+/// the frame had been buffered so far. Restarts the clock on every byte
+/// received, so it bounds the gap between bytes, not the frame's total
+/// transit time.
+#[cfg(feature = "tokio-time")]
+pub async fn read_frame_with_timeout(
+    sentinel: u8,
+    reader: &mut (impl AsyncRead + Unpin),
+    timeout: Duration,
+) -> Result<Vec<u8>, CobsError> {
+    let mut decoder = PushDecoder::with_sentinel(sentinel);
+    let mut byte = [0u8; 1];
+    loop {
+        match tokio::time::timeout(timeout, reader.read_exact(&mut byte)).await {
+            Ok(result) => {
+                result?;
+                if let Some(frame) = decoder.feed(byte[0]) {
+                    return frame;
+                }
+            }
+            Err(_elapsed) => {
+                return Err(CobsError::Stalled {
+                    buffered: decoder.pending_len(),
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! Helper for opening a [`tokio-serial`](https://docs.rs/tokio-serial) port
+//! already wrapped in a COBS [`Framed`] transport, for the common case of
+//! talking COBS frames over an actual UART instead of an in-process one.
+
+use tokio_serial::{SerialPortBuilderExt, SerialStream};
+use tokio_util::codec::Framed;
+
+use crate::{framed_with_sentinel, Codec};
+
+/// Open the serial port at `path` running at `baud_rate`, framing on the
+/// given runtime `sentinel`.
+pub fn open_serial(
+    path: &str,
+    baud_rate: u32,
+    sentinel: u8,
+) -> tokio_serial::Result<Framed<SerialStream, Codec>> {
+    let port = tokio_serial::new(path, baud_rate).open_native_async()?;
+    Ok(framed_with_sentinel(port, sentinel))
+}
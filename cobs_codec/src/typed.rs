@@ -0,0 +1,203 @@
+//! A typed `tokio_util` codec: postcard-serializes `T` and wraps the result
+//! in a COBS frame, so [`codec::Framed`] yields items of `T` directly instead
+//! of raw bytes. The pattern everyone builds by hand on top of [`Encoder`]
+//! and [`Decoder`].
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tokio_util::codec;
+
+use crate::{CobsError, Decoder, Encoder};
+
+/// Error returned by [`TypedCobsCodec`]: either a framing error or a
+/// postcard (de)serialization failure.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TypedCobsError {
+    /// A framing error while stuffing or destuffing a frame.
+    Cobs(CobsError),
+    /// `T` failed to serialize or deserialize with postcard.
+    Postcard(postcard::Error),
+}
+
+impl fmt::Display for TypedCobsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypedCobsError::Cobs(e) => write!(f, "{e}"),
+            TypedCobsError::Postcard(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for TypedCobsError {}
+
+impl From<CobsError> for TypedCobsError {
+    fn from(e: CobsError) -> Self {
+        TypedCobsError::Cobs(e)
+    }
+}
+
+impl From<postcard::Error> for TypedCobsError {
+    fn from(e: postcard::Error) -> Self {
+        TypedCobsError::Postcard(e)
+    }
+}
+
+impl From<std::io::Error> for TypedCobsError {
+    fn from(e: std::io::Error) -> Self {
+        TypedCobsError::Cobs(CobsError::from(e))
+    }
+}
+
+/// A `tokio_util` codec that frames `T` over COBS, serializing with
+/// postcard. `Framed<_, TypedCobsCodec<T>>` yields `T` directly rather than
+/// the raw bytes [`Encoder`]/[`Decoder`] deal in.
+pub struct TypedCobsCodec<T> {
+    encoder: Encoder,
+    decoder: Decoder,
+    _item: PhantomData<fn() -> T>,
+}
+
+impl<T> TypedCobsCodec<T> {
+    /// Construct a codec that frames on the given runtime `sentinel`.
+    pub const fn with_sentinel(sentinel: u8) -> Self {
+        Self {
+            encoder: Encoder::with_sentinel(sentinel),
+            decoder: Decoder::with_sentinel(sentinel),
+            _item: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for TypedCobsCodec<T> {
+    fn default() -> Self {
+        Self::with_sentinel(0)
+    }
+}
+
+impl<T: Serialize> codec::Encoder<T> for TypedCobsCodec<T> {
+    type Error = TypedCobsError;
+
+    fn encode(&mut self, item: T, dst: &mut bytes::BytesMut) -> Result<(), Self::Error> {
+        let serialized: Vec<u8> = postcard::to_allocvec(&item)?;
+        self.encoder.encode_frame(&serialized, dst);
+        Ok(())
+    }
+}
+
+impl<T: DeserializeOwned> codec::Decoder for TypedCobsCodec<T> {
+    type Item = T;
+    type Error = TypedCobsError;
+
+    fn decode(&mut self, src: &mut bytes::BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match self.decoder.decode_zero_copy(src)? {
+            Some(frame) => Ok(Some(postcard::from_bytes(&frame)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// The result of feeding [`FeedDecoder`], mirroring postcard's
+/// `accumulator::FeedResult` so a receive loop written against
+/// `CobsAccumulator` ports over unchanged.
+#[derive(Debug)]
+pub enum FeedResult<'a, T> {
+    /// Consumed all of `input`, still waiting on the rest of the frame.
+    Consumed,
+    /// The internal buffer filled up before a delimiter appeared. Contains
+    /// the remaining, unconsumed section of `input`.
+    OverFull(&'a [u8]),
+    /// A full frame was destuffed, but postcard failed to deserialize it.
+    /// Contains the remaining, unconsumed section of `input`.
+    DeserError(&'a [u8]),
+    /// A full frame was destuffed and deserialized.
+    Success {
+        /// The deserialized value.
+        data: T,
+        /// The remaining, unconsumed section of `input`.
+        remaining: &'a [u8],
+    },
+}
+
+/// A fixed-capacity, allocation-free counterpart to [`TypedCobsCodec`] for
+/// receive loops that hand over arbitrarily-sized chunks instead of driving a
+/// `BytesMut`-based [`codec::Decoder`] — built to the same `feed`/`feed_ref`
+/// shape as postcard's `accumulator::CobsAccumulator`, so code migrating from
+/// postcard's built-in COBS support doesn't need to restructure its loop,
+/// only swap the type.
+pub struct FeedDecoder<const N: usize> {
+    sentinel: u8,
+    buf: [u8; N],
+    idx: usize,
+}
+
+impl<const N: usize> FeedDecoder<N> {
+    /// Construct a feed decoder that splits on the given runtime `sentinel`.
+    pub const fn with_sentinel(sentinel: u8) -> Self {
+        Self {
+            sentinel,
+            buf: [0; N],
+            idx: 0,
+        }
+    }
+
+    /// Append `input` to the internal buffer and attempt to destuff and
+    /// deserialize a `T` out of the accumulated data.
+    #[inline]
+    pub fn feed<'a, T>(&mut self, input: &'a [u8]) -> FeedResult<'a, T>
+    where
+        T: DeserializeOwned,
+    {
+        self.feed_ref(input)
+    }
+
+    /// Append `input` to the internal buffer and attempt to destuff and
+    /// deserialize a `T` out of the accumulated data.
+    ///
+    /// This differs from [`FeedDecoder::feed`] in that it allows `T` to
+    /// borrow from the internal buffer, at the cost of mutably borrowing
+    /// `self` for the lifetime of the deserialized value.
+    pub fn feed_ref<'de, 'a, T>(&'de mut self, input: &'a [u8]) -> FeedResult<'a, T>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        let Some(n) = input.iter().position(|&b| b == self.sentinel) else {
+            return if self.idx + input.len() > N {
+                let new_start = N - self.idx;
+                self.idx = 0;
+                FeedResult::OverFull(&input[new_start..])
+            } else {
+                self.buf[self.idx..self.idx + input.len()].copy_from_slice(input);
+                self.idx += input.len();
+                FeedResult::Consumed
+            };
+        };
+
+        // Include the delimiter in the taken portion, not the released one.
+        let (take, remaining) = input.split_at(n + 1);
+
+        if self.idx + take.len() > N {
+            self.idx = 0;
+            return FeedResult::OverFull(remaining);
+        }
+        self.buf[self.idx..self.idx + take.len()].copy_from_slice(take);
+        self.idx += take.len();
+
+        // Destuff in place so a borrowing `T` can reference `self.buf`
+        // directly, the same way postcard's `from_bytes_cobs` does.
+        let decoder = Decoder::with_sentinel(self.sentinel);
+        let frame_len = self.idx - 1;
+        self.idx = 0;
+        match decoder.decode_in_place(&mut self.buf[..frame_len]) {
+            Ok(len) => match postcard::from_bytes(&self.buf[..len]) {
+                Ok(data) => FeedResult::Success { data, remaining },
+                Err(_) => FeedResult::DeserError(remaining),
+            },
+            Err(_) => FeedResult::DeserError(remaining),
+        }
+    }
+}
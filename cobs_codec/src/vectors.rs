@@ -0,0 +1,132 @@
+//! A JSON corpus of `{sentinel, payload_hex, encoded_hex}` test vectors, so
+//! the other language implementations under `interop/` have one shared,
+//! checked-in source of truth to validate against instead of each
+//! maintaining their own test data. See `interop/vectors.json` and
+//! `tests/vectors.rs`.
+
+use crate::{Decoder, Encoder};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::fmt::Write as _;
+use serde::{Deserialize, Serialize};
+
+/// One `sentinel`/`payload`/`encoded` case. Payload and encoded frame are
+/// hex strings rather than raw bytes so the corpus stays human-diffable in a
+/// JSON file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Vector {
+    pub sentinel: u8,
+    pub payload_hex: String,
+    pub encoded_hex: String,
+}
+
+impl Vector {
+    /// Encode `payload` under `sentinel` and record both as hex.
+    pub fn new(sentinel: u8, payload: &[u8]) -> Self {
+        let mut encoded = Vec::new();
+        Encoder::with_sentinel(sentinel).encode_frame_into(payload, &mut encoded);
+        Self {
+            sentinel,
+            payload_hex: to_hex(payload),
+            encoded_hex: to_hex(&encoded),
+        }
+    }
+
+    /// Check that this crate's [`Decoder`] reproduces `payload_hex` when fed
+    /// `encoded_hex` (with its trailing delimiter stripped), and that
+    /// [`Encoder`] reproduces `encoded_hex` when fed `payload_hex`.
+    pub fn verify(&self) -> Result<(), VerifyError> {
+        let payload = from_hex(&self.payload_hex)?;
+        let encoded = from_hex(&self.encoded_hex)?;
+
+        let mut reencoded = Vec::new();
+        Encoder::with_sentinel(self.sentinel).encode_frame_into(&payload, &mut reencoded);
+        if reencoded != encoded {
+            return Err(VerifyError::EncodeMismatch);
+        }
+
+        let frame = encoded.strip_suffix(&[self.sentinel]).unwrap_or(&encoded);
+        let decoded = Decoder::with_sentinel(self.sentinel).decode_frame(frame)?;
+        if decoded != payload {
+            return Err(VerifyError::DecodeMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A representative corpus covering the edge cases this codec is tested
+/// against elsewhere (empty payload, embedded zeros, a 254-byte block
+/// boundary, a non-zero sentinel), bundled here as the one source other
+/// implementations are meant to reproduce against.
+pub fn default_corpus() -> Vec<Vector> {
+    alloc::vec![
+        Vector::new(0, b""),
+        Vector::new(0, b"\0"),
+        Vector::new(0, b"hello, world"),
+        Vector::new(0, &[0, 1, 2, 0, 0, 255, 254]),
+        Vector::new(0, &[0u8; 300]),
+        Vector::new(0xAA, &[0xAA, 1, 0xAA, 0xAA, 2]),
+    ]
+}
+
+/// Serialize `vectors` as pretty-printed JSON.
+pub fn to_json(vectors: &[Vector]) -> Result<String, serde_json::Error> {
+    serde_json::to_string_pretty(vectors)
+}
+
+/// Parse a JSON corpus previously produced by [`to_json`].
+pub fn from_json(json: &str) -> Result<Vec<Vector>, serde_json::Error> {
+    serde_json::from_str(json)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    let mut s = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        write!(s, "{b:02x}").unwrap();
+    }
+    s
+}
+
+fn from_hex(s: &str) -> Result<Vec<u8>, VerifyError> {
+    if !s.len().is_multiple_of(2) {
+        return Err(VerifyError::InvalidHex);
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).map_err(|_| VerifyError::InvalidHex))
+        .collect()
+}
+
+/// Why [`Vector::verify`] failed.
+#[derive(Debug)]
+pub enum VerifyError {
+    /// `payload_hex` or `encoded_hex` wasn't valid hex.
+    InvalidHex,
+    /// Encoding `payload_hex` under `sentinel` didn't reproduce `encoded_hex`.
+    EncodeMismatch,
+    /// Decoding `encoded_hex` didn't reproduce `payload_hex`.
+    DecodeMismatch,
+    /// Decoding `encoded_hex` failed outright.
+    Decode(crate::CobsError),
+}
+
+impl From<crate::CobsError> for VerifyError {
+    fn from(err: crate::CobsError) -> Self {
+        VerifyError::Decode(err)
+    }
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifyError::InvalidHex => write!(f, "invalid hex string"),
+            VerifyError::EncodeMismatch => write!(f, "encode(payload_hex) != encoded_hex"),
+            VerifyError::DecodeMismatch => write!(f, "decode(encoded_hex) != payload_hex"),
+            VerifyError::Decode(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VerifyError {}
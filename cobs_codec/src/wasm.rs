@@ -0,0 +1,59 @@
+//! `wasm-bindgen` exports for browser tooling. Build with
+//! `wasm-pack build --target web -- --features wasm` (or
+//! `cargo build --target wasm32-unknown-unknown --features wasm` plus a
+//! manual `wasm-bindgen-cli` pass) so device dashboards can frame and
+//! deframe WebSerial data with the exact same implementation as the
+//! backend.
+//!
+//! [`encode_frame`]/[`decode_frame`] mirror the allocating
+//! [`crate::encode`]/[`crate::decode`] free functions for one-shot framing.
+//! [`StreamDecoder`] wraps [`crate::sans_io::PushDecoder`] for WebSerial's
+//! byte-at-a-time `ReadableStream` reads.
+
+use crate::sans_io::PushDecoder;
+use crate::{decode, encode, CobsError};
+use alloc::format;
+use alloc::vec::Vec;
+use wasm_bindgen::prelude::*;
+
+fn to_js_err(err: CobsError) -> JsValue {
+    JsValue::from_str(&format!("{err} at byte offset {}", err.offset()))
+}
+
+/// Stuff `data` and return a single terminated frame.
+#[wasm_bindgen]
+pub fn encode_frame(sentinel: u8, data: &[u8]) -> Vec<u8> {
+    let mut dst = Vec::new();
+    encode(sentinel, data, &mut dst);
+    dst
+}
+
+/// Destuff a single frame's content (without the trailing delimiter).
+#[wasm_bindgen]
+pub fn decode_frame(sentinel: u8, frame: &[u8]) -> Result<Vec<u8>, JsValue> {
+    decode(sentinel, frame).map_err(to_js_err)
+}
+
+/// Incremental decoder for bytes arriving one at a time off a
+/// `ReadableStreamDefaultReader`. Mirrors [`crate::sans_io::PushDecoder`].
+#[wasm_bindgen]
+pub struct StreamDecoder(PushDecoder);
+
+#[wasm_bindgen]
+impl StreamDecoder {
+    /// Construct a decoder that splits on the given runtime `sentinel`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(sentinel: u8) -> Self {
+        Self(PushDecoder::with_sentinel(sentinel))
+    }
+
+    /// Feed one byte. Returns the decoded frame once `byte` completes one,
+    /// or `undefined` if the frame isn't finished yet.
+    pub fn feed(&mut self, byte: u8) -> Result<Option<Vec<u8>>, JsValue> {
+        match self.0.feed(byte) {
+            None => Ok(None),
+            Some(Ok(frame)) => Ok(Some(frame)),
+            Some(Err(e)) => Err(to_js_err(e)),
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! COBS generalized to 16-bit symbol streams, for transports where the
+//! reserved delimiter is a whole word rather than a single byte (a 16-bit
+//! parallel bus, a word-addressed link). [`Encoder16`]/[`Decoder16`] mirror
+//! [`crate::Encoder`]/[`crate::Decoder`] one symbol width up: code words
+//! count `u16` symbols instead of bytes, so a group holds up to `0xFFFE`
+//! symbols instead of 254.
+
+use crate::CobsError;
+use alloc::vec::Vec;
+
+/// Stuff a `u16` symbol stream: zero words are eliminated into a leading
+/// code word giving the distance to the next one, then every symbol (code
+/// and data alike) is XORed with `sentinel` so the sentinel word, not `0`,
+/// is the one value guaranteed never to appear except as the delimiter.
+fn stuff16(data: &[u16], sentinel: u16) -> Vec<u16> {
+    let mut out = Vec::with_capacity(data.len() + data.len() / 0xFFFE + 1);
+    let mut code_idx = 0;
+    out.push(0);
+    let mut code: u32 = 1;
+    for &w in data {
+        if w == 0 {
+            out[code_idx] = code as u16 ^ sentinel;
+            code_idx = out.len();
+            out.push(0);
+            code = 1;
+        } else {
+            out.push(w ^ sentinel);
+            code += 1;
+            if code == 0xFFFF {
+                out[code_idx] = code as u16 ^ sentinel;
+                code_idx = out.len();
+                out.push(0);
+                code = 1;
+            }
+        }
+    }
+    out[code_idx] = code as u16 ^ sentinel;
+    out
+}
+
+/// Destuff a single frame's content (the symbols preceding the delimiter)
+/// back into the original symbol stream.
+fn unstuff16(frame: &[u16], sentinel: u16) -> Result<Vec<u16>, CobsError> {
+    let mut out = Vec::new();
+    let mut i = 0;
+    let n = frame.len();
+    while i < n {
+        let code = frame[i] ^ sentinel;
+        if code == 0 {
+            return Err(CobsError::InvalidCodeByte { offset: i });
+        }
+        let block = code as usize;
+        let start = i + 1;
+        let end = start + block - 1;
+        if end > n {
+            return Err(CobsError::UnexpectedSentinel { offset: n });
+        }
+        for &w in &frame[start..end] {
+            out.push(w ^ sentinel);
+        }
+        i = end;
+        if block != 0xFFFF && i < n {
+            out.push(0);
+        }
+    }
+    Ok(out)
+}
+
+/// COBS encoder over `u16` symbols: splits on a runtime-selectable sentinel
+/// word instead of a sentinel byte.
+#[derive(Debug, Clone, Copy)]
+pub struct Encoder16 {
+    sentinel: u16,
+}
+
+impl Encoder16 {
+    /// Construct an encoder that frames on the given runtime `sentinel` word.
+    pub const fn with_sentinel(sentinel: u16) -> Self {
+        Self { sentinel }
+    }
+
+    /// Stuff `data` and append a single terminated frame to `dst`.
+    pub fn encode_frame_into(&self, data: &[u16], dst: &mut Vec<u16>) {
+        dst.extend_from_slice(&stuff16(data, self.sentinel));
+        dst.push(self.sentinel);
+    }
+}
+
+impl Default for Encoder16 {
+    fn default() -> Self {
+        Self::with_sentinel(0)
+    }
+}
+
+/// COBS decoder over `u16` symbols: splits on a runtime-selectable sentinel
+/// word instead of a sentinel byte.
+#[derive(Debug, Clone, Copy)]
+pub struct Decoder16 {
+    sentinel: u16,
+}
+
+impl Decoder16 {
+    /// Construct a decoder that splits on the given runtime `sentinel` word.
+    pub const fn with_sentinel(sentinel: u16) -> Self {
+        Self { sentinel }
+    }
+
+    /// Destuff a frame's content (without the trailing delimiter word).
+    pub fn decode_frame(&self, frame: &[u16]) -> Result<Vec<u16>, CobsError> {
+        unstuff16(frame, self.sentinel)
+    }
+}
+
+impl Default for Decoder16 {
+    fn default() -> Self {
+        Self::with_sentinel(0)
+    }
+}
+
+/// Stuff `src` and append a single terminated frame to `dst`, without
+/// constructing an [`Encoder16`]. Equivalent to
+/// `Encoder16::with_sentinel(sentinel).encode_frame_into(src, dst)`.
+pub fn encode16(sentinel: u16, src: &[u16], dst: &mut Vec<u16>) {
+    Encoder16::with_sentinel(sentinel).encode_frame_into(src, dst)
+}
+
+/// Destuff a single frame's content (without the trailing delimiter word),
+/// without constructing a [`Decoder16`]. Equivalent to
+/// `Decoder16::with_sentinel(sentinel).decode_frame(src)`.
+pub fn decode16(sentinel: u16, src: &[u16]) -> Result<Vec<u16>, CobsError> {
+    Decoder16::with_sentinel(sentinel).decode_frame(src)
+}
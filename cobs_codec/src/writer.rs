@@ -0,0 +1,71 @@
+//! A [`std::io::Write`] adapter that COBS-encodes written bytes and forwards
+//! the stuffed output to an inner writer, for plugging COBS into blocking
+//! code paths (files, serial ports) without pulling in tokio.
+
+use std::io::{self, Read, Write};
+
+use crate::stream::StreamEncoder;
+
+/// Wraps an inner [`Write`], COBS-encoding everything written to it. Each
+/// [`Write::write`] call stuffs and forwards its bytes immediately; call
+/// [`CobsWriter::end_frame`] to terminate the current frame with the
+/// sentinel and start the next one.
+pub struct CobsWriter<W: Write> {
+    inner: W,
+    encoder: StreamEncoder,
+    scratch: Vec<u8>,
+}
+
+impl<W: Write> CobsWriter<W> {
+    /// Wrap `inner`, framing on the given runtime `sentinel`.
+    pub fn new(sentinel: u8, inner: W) -> Self {
+        let mut encoder = StreamEncoder::with_sentinel(sentinel);
+        encoder.start_frame();
+        Self {
+            inner,
+            encoder,
+            scratch: Vec::new(),
+        }
+    }
+
+    /// Terminate the current frame with the sentinel and start the next one.
+    pub fn end_frame(&mut self) -> io::Result<()> {
+        self.scratch.clear();
+        self.encoder.finish(&mut self.scratch);
+        self.inner.write_all(&self.scratch)?;
+        self.encoder.start_frame();
+        Ok(())
+    }
+
+    /// Consume the writer, returning the inner one. Any unterminated frame
+    /// is discarded rather than silently flushed without its delimiter.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for CobsWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.scratch.clear();
+        self.encoder.write(buf, &mut self.scratch);
+        self.inner.write_all(&self.scratch)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Stream `src` into a single COBS-terminated frame written to `dst`, in
+/// bounded memory: [`io::copy`] reads and stuffs `src` in fixed-size chunks
+/// through a [`CobsWriter`] rather than requiring the whole payload and its
+/// encoded frame resident at once, for payloads too large to duplicate in
+/// RAM (a multi-megabyte firmware image). Returns the number of payload
+/// bytes copied.
+pub fn encode_frame<R: Read, W: Write>(sentinel: u8, src: &mut R, dst: W) -> io::Result<u64> {
+    let mut writer = CobsWriter::new(sentinel, dst);
+    let copied = io::copy(src, &mut writer)?;
+    writer.end_frame()?;
+    Ok(copied)
+}
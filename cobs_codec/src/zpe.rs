@@ -0,0 +1,196 @@
+//! COBS/ZPE (zero-pair elimination), which collapses runs of two or more
+//! consecutive zero bytes into a single two-byte marker instead of one code
+//! byte per zero. Telemetry payloads with long zero runs shrink noticeably;
+//! sparse or isolated zeros cost the same as plain COBS.
+//!
+//! The plain-domain token stream (before the sentinel XOR) is:
+//! - code `0x01`: a single eliminated zero byte, no data follows.
+//! - code `0x02..=0xFD`: a normal group of `code - 1` non-zero data bytes.
+//! - code `0xFE`: a zero run; the following byte is the run length
+//!   (`1..=255`), itself eliminated back into that many zero bytes.
+//! - code `0xFF`: reserved, never produced.
+//!
+//! Reserving `0xFE` for the run marker caps a single normal group at 252
+//! data bytes, two fewer than plain COBS's 254.
+
+use crate::CobsError;
+use alloc::vec::Vec;
+#[cfg(feature = "tokio")]
+use tokio_util::codec;
+
+const MAX_RUN_GROUP: usize = 252;
+
+/// Stuff `data`, collapsing runs of two or more zero bytes into a single
+/// `[0xFE, count]` marker. The result never contains a `0x00` byte.
+fn stuff_zpe(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len() + data.len() / MAX_RUN_GROUP + 2);
+    let mut i = 0;
+    let n = data.len();
+    while i < n {
+        if data[i] == 0 {
+            let mut run = 1;
+            while i + run < n && data[i + run] == 0 {
+                run += 1;
+            }
+            if run == 1 {
+                out.push(1);
+                i += 1;
+            } else {
+                let mut remaining = run;
+                while remaining > 0 {
+                    let chunk = remaining.min(255);
+                    out.push(0xFE);
+                    out.push(chunk as u8);
+                    remaining -= chunk;
+                }
+                i += run;
+            }
+        } else {
+            let mut run = 1;
+            while i + run < n && data[i + run] != 0 && run < MAX_RUN_GROUP {
+                run += 1;
+            }
+            out.push((run + 1) as u8);
+            out.extend_from_slice(&data[i..i + run]);
+            i += run;
+        }
+    }
+    out
+}
+
+/// Destuff a COBS/ZPE frame's content (without the trailing delimiter).
+fn unstuff_zpe(frame: &[u8], sentinel: u8) -> Result<Vec<u8>, CobsError> {
+    let mut out = Vec::new();
+    let mut i = 0;
+    let n = frame.len();
+    while i < n {
+        let code = frame[i] ^ sentinel;
+        match code {
+            0 | 0xFF => return Err(CobsError::InvalidCodeByte { offset: i }),
+            1 => {
+                out.push(0);
+                i += 1;
+            }
+            0xFE => {
+                if i + 1 >= n {
+                    return Err(CobsError::TruncatedFrame { offset: n });
+                }
+                let count = frame[i + 1] ^ sentinel;
+                out.resize(out.len() + count as usize, 0);
+                i += 2;
+            }
+            code => {
+                let len = code as usize - 1;
+                let start = i + 1;
+                let end = start + len;
+                if end > n {
+                    return Err(CobsError::UnexpectedSentinel { offset: n });
+                }
+                for &b in &frame[start..end] {
+                    out.push(b ^ sentinel);
+                }
+                i = end;
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// COBS/ZPE encoder: same framing as [`crate::Encoder`], but collapses zero
+/// runs of two or more bytes into a single marker.
+#[derive(Debug, Clone)]
+pub struct EncoderZpe {
+    sentinel: u8,
+}
+
+impl EncoderZpe {
+    /// Construct a COBS/ZPE encoder that frames on the given runtime `sentinel`.
+    pub const fn with_sentinel(sentinel: u8) -> Self {
+        Self { sentinel }
+    }
+
+    /// Stuff `data` and append a single terminated frame to `dst`.
+    pub fn encode_frame_into(&self, data: &[u8], dst: &mut Vec<u8>) {
+        let s = self.sentinel;
+        let stuffed = stuff_zpe(data);
+        dst.reserve(stuffed.len() + 1);
+        dst.extend(stuffed.into_iter().map(|b| b ^ s));
+        dst.push(s);
+    }
+
+    /// Stuff `data` and write a single terminated frame into `dst`. Requires
+    /// the `tokio` feature.
+    #[cfg(feature = "tokio")]
+    pub fn encode_frame(&self, data: &[u8], dst: &mut bytes::BytesMut) {
+        let mut buf = Vec::new();
+        self.encode_frame_into(data, &mut buf);
+        dst.extend_from_slice(&buf);
+    }
+}
+
+impl Default for EncoderZpe {
+    fn default() -> Self {
+        Self::with_sentinel(0)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl codec::Encoder<Vec<u8>> for EncoderZpe {
+    type Error = CobsError;
+
+    fn encode(&mut self, item: Vec<u8>, dst: &mut bytes::BytesMut) -> Result<(), Self::Error> {
+        self.encode_frame(&item, dst);
+        Ok(())
+    }
+}
+
+/// COBS/ZPE decoder: same reassembly as [`crate::Decoder`], but expands
+/// zero-run markers back into their eliminated zero bytes.
+#[derive(Debug, Clone)]
+pub struct DecoderZpe {
+    sentinel: u8,
+}
+
+impl DecoderZpe {
+    /// Construct a COBS/ZPE decoder that splits on the given runtime `sentinel`.
+    pub const fn with_sentinel(sentinel: u8) -> Self {
+        Self { sentinel }
+    }
+
+    /// Destuff a frame's content (without the trailing delimiter).
+    pub fn decode_frame(&self, frame: &[u8]) -> Result<Vec<u8>, CobsError> {
+        unstuff_zpe(frame, self.sentinel)
+    }
+}
+
+impl Default for DecoderZpe {
+    fn default() -> Self {
+        Self::with_sentinel(0)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl codec::Decoder for DecoderZpe {
+    type Item = bytes::BytesMut;
+    type Error = CobsError;
+
+    fn decode(&mut self, src: &mut bytes::BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match crate::find_sentinel(src, self.sentinel) {
+            Some(pos) => {
+                let frame = src.split_to(pos);
+                let _delimiter = src.split_to(1);
+                let payload = self.decode_frame(&frame)?;
+                Ok(Some(bytes::BytesMut::from(&payload[..])))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn decode_eof(&mut self, src: &mut bytes::BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match self.decode(src)? {
+            Some(frame) => Ok(Some(frame)),
+            None if src.is_empty() => Ok(None),
+            None => Err(CobsError::TruncatedFrame { offset: src.len() }),
+        }
+    }
+}
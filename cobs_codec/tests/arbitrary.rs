@@ -0,0 +1,31 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use cobs_codec::arbitrary::ChunkPattern;
+use cobs_codec::{Decoder, Encoder};
+
+#[test]
+fn generates_encoders_and_decoders_from_raw_bytes() {
+    let seed = [0x5Au8; 64];
+    let mut u = Unstructured::new(&seed);
+    let encoder = Encoder::arbitrary(&mut u).unwrap();
+    let decoder = Decoder::arbitrary(&mut u).unwrap();
+
+    let mut frame = Vec::new();
+    encoder.encode_frame_into(b"hello", &mut frame);
+    // Arbitrary sentinels/configs don't have to agree between the two, so
+    // only check that generation itself doesn't panic or error.
+    let _ = decoder.decode_frame(&frame);
+}
+
+#[test]
+fn chunk_pattern_splits_cover_the_whole_frame() {
+    let seed = [0x17u8; 32];
+    let mut u = Unstructured::new(&seed);
+    let pattern = ChunkPattern::arbitrary(&mut u).unwrap();
+
+    let frame = b"hello, world".to_vec();
+    let chunks = pattern.split(&frame);
+    let rejoined: Vec<u8> = chunks.into_iter().flatten().copied().collect();
+    assert_eq!(rejoined, frame);
+}
@@ -0,0 +1,24 @@
+#![cfg(all(feature = "asynchronous-codec", feature = "futures"))]
+
+use asynchronous_codec::{FramedRead, FramedWrite};
+use cobs_codec::{Decoder, Encoder};
+use futures_executor::block_on;
+use futures_util::io::Cursor;
+use futures_util::sink::SinkExt;
+use futures_util::stream::TryStreamExt;
+
+#[test]
+fn round_trips_frames_through_asynchronous_codec_framed() {
+    block_on(async {
+        let mut writer = FramedWrite::new(Cursor::new(Vec::new()), Encoder::with_sentinel(0));
+        writer.send(&b"one"[..]).await.unwrap();
+        writer.send(&b"two"[..]).await.unwrap();
+
+        let encoded = writer.into_inner().into_inner();
+
+        let mut reader = FramedRead::new(Cursor::new(encoded), Decoder::with_sentinel(0));
+        assert_eq!(reader.try_next().await.unwrap().unwrap(), &b"one"[..]);
+        assert_eq!(reader.try_next().await.unwrap().unwrap(), &b"two"[..]);
+        assert!(reader.try_next().await.unwrap().is_none());
+    });
+}
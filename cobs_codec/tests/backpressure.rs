@@ -0,0 +1,83 @@
+#![cfg(feature = "futures")]
+
+use std::cell::Cell;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use cobs_codec::backpressure::BoundedSink;
+use futures_util::task::noop_waker_ref;
+use futures_util::Sink;
+
+/// A sink that accepts every item immediately but only completes a flush
+/// once `stuck` is cleared, standing in for a writer that's fallen behind.
+struct MockSink {
+    items: Vec<Vec<u8>>,
+    stuck: Cell<bool>,
+}
+
+impl MockSink {
+    fn new() -> Self {
+        Self { items: Vec::new(), stuck: Cell::new(true) }
+    }
+}
+
+impl Sink<Vec<u8>> for MockSink {
+    type Error = std::convert::Infallible;
+
+    fn poll_ready(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Vec<u8>) -> Result<(), Self::Error> {
+        self.get_mut().items.push(item);
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        if self.stuck.get() {
+            Poll::Pending
+        } else {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.poll_flush(cx)
+    }
+}
+
+fn poll_ready(sink: &mut BoundedSink<MockSink>) -> Poll<Result<(), std::convert::Infallible>> {
+    let waker = noop_waker_ref();
+    let mut cx = Context::from_waker(waker);
+    Pin::new(sink).poll_ready(&mut cx)
+}
+
+#[test]
+fn stays_ready_under_the_high_water_mark() {
+    let mut sink = BoundedSink::new(MockSink::new(), 16);
+    assert!(poll_ready(&mut sink).is_ready());
+    Pin::new(&mut sink).start_send(vec![0u8; 10]).unwrap();
+    assert_eq!(sink.pending_bytes(), 10);
+    assert!(poll_ready(&mut sink).is_ready());
+}
+
+#[test]
+fn goes_pending_once_the_high_water_mark_is_hit_and_the_writer_is_stuck() {
+    let mut sink = BoundedSink::new(MockSink::new(), 16);
+    Pin::new(&mut sink).start_send(vec![0u8; 10]).unwrap();
+    Pin::new(&mut sink).start_send(vec![0u8; 10]).unwrap();
+    assert_eq!(sink.pending_bytes(), 20);
+
+    assert!(poll_ready(&mut sink).is_pending());
+}
+
+#[test]
+fn recovers_once_the_writer_catches_up() {
+    let mut sink = BoundedSink::new(MockSink::new(), 16);
+    Pin::new(&mut sink).start_send(vec![0u8; 20]).unwrap();
+    assert!(poll_ready(&mut sink).is_pending());
+
+    sink.get_mut().stuck.set(false);
+    assert!(poll_ready(&mut sink).is_ready());
+    assert_eq!(sink.pending_bytes(), 0);
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "bbqueue")]
+
+use bbqueue::nicknames::Churrasco;
+use cobs_codec::bbqueue::GrantDecoder;
+
+#[test]
+fn decodes_a_frame_split_across_the_ring_buffers_wrap_point() {
+    let bb: Churrasco<12> = Churrasco::new();
+    let prod = bb.stream_producer();
+    let cons = bb.stream_consumer();
+
+    // Fill and drain 10 of the 12 bytes so the write pointer sits right
+    // before the end of the buffer, leaving only 2 bytes of tail space.
+    let mut filler = prod.grant_exact(10).unwrap();
+    filler.copy_from_slice(&[0xAA; 10]);
+    filler.commit(10);
+    cons.read().unwrap().release(10);
+
+    let mut framed = Vec::new();
+    cobs_codec::encode(0, b"hi", &mut framed);
+    assert_eq!(framed.len(), 4); // code byte, 'h', 'i', delimiter
+
+    // Write the frame's first two bytes into the last two bytes of the
+    // buffer's tail...
+    let mut head = prod.grant_exact(2).unwrap();
+    head.copy_from_slice(&framed[..2]);
+    head.commit(2);
+
+    // ...and its last two (including the delimiter) wrap around to the
+    // front, since no tail space remains.
+    let mut tail = prod.grant_exact(2).unwrap();
+    tail.copy_from_slice(&framed[2..]);
+    tail.commit(2);
+
+    let mut decoder = GrantDecoder::with_sentinel(0);
+
+    let first = decoder.decode_next_grant(&cons).unwrap();
+    assert!(first.is_empty());
+    assert_eq!(decoder.pending_len(), 2);
+
+    let second = decoder.decode_next_grant(&cons).unwrap();
+    assert_eq!(second.len(), 1);
+    assert_eq!(second[0].as_ref().unwrap(), b"hi");
+    assert_eq!(decoder.pending_len(), 0);
+}
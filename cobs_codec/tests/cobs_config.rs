@@ -0,0 +1,32 @@
+use cobs_codec::CobsConfig;
+
+#[test]
+fn builds_a_matched_encoder_and_decoder() {
+    let config = CobsConfig::new(0).with_max_block(16);
+
+    let mut framed = Vec::new();
+    config.encoder().encode_frame_into(&[1u8; 20], &mut framed);
+    framed.truncate(framed.len() - 1); // drop the trailing delimiter
+
+    let decoded = config.decoder().decode_frame(&framed).unwrap();
+    assert_eq!(decoded, vec![1u8; 20]);
+}
+
+#[test]
+fn decoder_can_still_chain_its_own_type_specific_options() {
+    let decoder = CobsConfig::new(0).decoder().with_resync(true);
+    assert!(decoder.decode_frame(&[]).is_ok());
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn builds_a_codec_that_round_trips_through_tokio_util() {
+    use cobs_codec::bytes::BytesMut;
+    use tokio_util::codec::{Decoder, Encoder};
+
+    let mut codec = CobsConfig::new(0).codec();
+    let mut buf = BytesMut::new();
+    codec.encode(b"hi".to_vec(), &mut buf).unwrap();
+
+    assert_eq!(codec.decode(&mut buf).unwrap().unwrap(), b"hi"[..]);
+}
@@ -0,0 +1,45 @@
+#![cfg(all(feature = "tokio", feature = "tokio-io", feature = "futures"))]
+
+use cobs_codec::testing::CobsPipe;
+use futures_executor::block_on;
+use futures_util::future::join;
+use futures_util::{SinkExt, StreamExt};
+
+#[test]
+fn round_trips_a_frame_between_the_two_endpoints() {
+    let (mut a, mut b) = CobsPipe::open(0);
+    block_on(async {
+        a.send(b"hello".to_vec()).await.unwrap();
+        assert_eq!(&b.next().await.unwrap().unwrap()[..], b"hello");
+    });
+}
+
+#[test]
+fn with_chunk_size_still_round_trips_a_frame_larger_than_a_chunk() {
+    let (mut a, mut b) = CobsPipe::with_chunk_size(0, 4);
+    let payload = vec![7u8; 100];
+    block_on(async {
+        // The channel is narrower than the encoded frame, so the send and
+        // the receive have to make progress concurrently: sending alone
+        // would block on a full buffer nobody is draining yet.
+        let (sent, received) = join(a.send(payload.clone()), b.next()).await;
+        sent.unwrap();
+        assert_eq!(&received.unwrap().unwrap()[..], &payload[..]);
+    });
+}
+
+#[test]
+fn with_corruption_lets_a_fault_injector_break_frames_from_one_side() {
+    // Flip only the leading code byte so the trailing delimiter survives and
+    // the decoder still sees a complete (but malformed) frame, rather than
+    // corrupting it into one with no delimiter at all.
+    let (mut a, mut b) = CobsPipe::with_corruption(0, |buf| {
+        if let Some(first) = buf.first_mut() {
+            *first ^= 0xFF;
+        }
+    });
+    block_on(async {
+        a.send(b"hello".to_vec()).await.unwrap();
+        assert!(b.next().await.unwrap().is_err());
+    });
+}
@@ -0,0 +1,18 @@
+#![cfg(feature = "tokio")]
+
+use cobs_codec::bytes::BytesMut;
+use cobs_codec::Codec;
+use tokio_util::codec::{Decoder, Encoder};
+
+#[test]
+fn single_codec_satisfies_both_encoder_and_decoder() {
+    let mut codec = Codec::with_sentinel(0);
+    let mut dst = BytesMut::new();
+
+    codec.encode(b"one".to_vec(), &mut dst).unwrap();
+    codec.encode(b"two".to_vec(), &mut dst).unwrap();
+
+    assert_eq!(&codec.decode(&mut dst).unwrap().unwrap()[..], b"one");
+    assert_eq!(&codec.decode(&mut dst).unwrap().unwrap()[..], b"two");
+    assert!(codec.decode(&mut dst).unwrap().is_none());
+}
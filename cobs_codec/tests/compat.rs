@@ -0,0 +1,49 @@
+#![cfg(feature = "compat")]
+
+use cobs_codec::compat::{cobs, corncobs};
+
+#[test]
+fn cobs_shim_round_trips_through_slices() {
+    let payload = b"hello\x00world";
+
+    let mut encoded = vec![0u8; cobs::max_encoding_length(payload.len())];
+    let n = cobs::encode(payload, &mut encoded);
+
+    let mut decoded = vec![0u8; payload.len()];
+    let decoded_len = cobs::decode(&encoded[..n], &mut decoded).unwrap();
+
+    assert_eq!(&decoded[..decoded_len], payload);
+}
+
+#[test]
+fn cobs_shim_round_trips_through_vecs() {
+    let payload = b"hello\x00world";
+
+    let encoded = cobs::encode_vec(payload);
+    let decoded = cobs::decode_vec(&encoded).unwrap();
+
+    assert_eq!(decoded, payload);
+}
+
+#[test]
+fn corncobs_shim_round_trips_through_slices() {
+    let payload = b"hello\x00world";
+
+    let mut encoded = vec![0u8; corncobs::max_encoded_len(payload.len())];
+    let n = corncobs::encode_buf(payload, &mut encoded);
+    assert_eq!(*encoded.last().unwrap(), corncobs::ZERO);
+
+    let mut decoded = vec![0u8; payload.len()];
+    let decoded_len = corncobs::decode_buf(&encoded[..n], &mut decoded).unwrap();
+
+    assert_eq!(&decoded[..decoded_len], payload);
+}
+
+#[test]
+fn corncobs_shim_reports_truncated_input() {
+    let mut decoded = [0u8; 8];
+    assert_eq!(
+        corncobs::decode_buf(b"\x03ab", &mut decoded),
+        Err(corncobs::CobsError::Truncated)
+    );
+}
@@ -0,0 +1,39 @@
+use cobs_codec::cobs_frame;
+
+#[test]
+fn encodes_a_sentinel_free_short_payload_at_compile_time() {
+    const FRAME: &[u8] = cobs_frame!(0, b"hello");
+    let decoded = cobs_codec::decode(0, &FRAME[..FRAME.len() - 1]).unwrap();
+    assert_eq!(decoded, b"hello");
+}
+
+#[test]
+fn encodes_a_payload_with_embedded_zeros() {
+    const FRAME: &[u8] = cobs_frame!(0, b"a\0b\0\0c");
+    let decoded = cobs_codec::decode(0, &FRAME[..FRAME.len() - 1]).unwrap();
+    assert_eq!(decoded, b"a\0b\0\0c");
+}
+
+#[test]
+fn encodes_a_payload_spanning_the_254_byte_group_boundary() {
+    const FRAME: &[u8] = cobs_frame!(0, &[1u8; 600]);
+    let decoded = cobs_codec::decode(0, &FRAME[..FRAME.len() - 1]).unwrap();
+    assert_eq!(decoded, &[1u8; 600]);
+}
+
+#[test]
+fn encodes_with_a_non_zero_sentinel() {
+    const FRAME: &[u8] = cobs_frame!(0xAA, b"hello\0world");
+    let decoded = cobs_codec::decode(0xAA, &FRAME[..FRAME.len() - 1]).unwrap();
+    assert_eq!(decoded, b"hello\0world");
+}
+
+#[test]
+fn matches_the_runtime_encoder() {
+    let payload = b"the quick brown fox\0jumps over\0\0the lazy dog";
+    const FRAME: &[u8] = cobs_frame!(7, b"the quick brown fox\0jumps over\0\0the lazy dog");
+
+    let mut expected = Vec::new();
+    cobs_codec::encode(7, payload, &mut expected);
+    assert_eq!(FRAME, &expected[..]);
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "crc")]
+
+use cobs_codec::crc_cobs::{CrcCobsCodec, CrcWidth};
+use cobs_codec::CobsError;
+
+#[test]
+fn crc16_round_trips() {
+    let codec = CrcCobsCodec::new(0, CrcWidth::Crc16(&crc::CRC_16_IBM_3740));
+    let mut framed = Vec::new();
+    codec.encode_frame(b"hello", &mut framed);
+
+    let decoded = codec.decode_frame(&framed[..framed.len() - 1]).unwrap();
+    assert_eq!(decoded, b"hello");
+}
+
+#[test]
+fn crc32_round_trips() {
+    let codec = CrcCobsCodec::new(0, CrcWidth::Crc32(&crc::CRC_32_ISO_HDLC));
+    let mut framed = Vec::new();
+    codec.encode_frame(b"hello", &mut framed);
+
+    let decoded = codec.decode_frame(&framed[..framed.len() - 1]).unwrap();
+    assert_eq!(decoded, b"hello");
+}
+
+#[test]
+fn corrupted_payload_is_a_crc_mismatch() {
+    let codec = CrcCobsCodec::new(0, CrcWidth::Crc16(&crc::CRC_16_IBM_3740));
+    let mut framed = Vec::new();
+    codec.encode_frame(b"hello", &mut framed);
+    // Flip a data byte (not the leading code byte) so the frame still
+    // destuffs cleanly and only the CRC check fails.
+    framed[1] = framed[1].wrapping_add(1);
+
+    let err = codec
+        .decode_frame(&framed[..framed.len() - 1])
+        .unwrap_err();
+    assert!(matches!(err, CobsError::CrcMismatch));
+}
@@ -0,0 +1,21 @@
+use cobs_codec::{decode_datagram, encode_datagram};
+
+#[test]
+fn round_trips_a_packet_with_no_trailing_delimiter() {
+    let payload = [0u8, 1, 2, 0, 0, 255, 254, 0];
+
+    let mut packet = Vec::new();
+    encode_datagram(0, &payload, &mut packet);
+
+    assert!(!packet.contains(&0));
+    assert_eq!(decode_datagram(0, &packet).unwrap(), payload);
+}
+
+#[test]
+fn an_empty_payload_becomes_a_single_code_byte() {
+    let mut packet = Vec::new();
+    encode_datagram(0, &[], &mut packet);
+
+    assert_eq!(packet, [1u8]);
+    assert_eq!(decode_datagram(0, &packet).unwrap(), Vec::<u8>::new());
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "tokio")]
+
+use cobs_codec::bytes::BytesMut;
+use cobs_codec::{CobsError, Decoder, Encoder};
+use tokio_util::codec::Decoder as _;
+
+#[test]
+fn truncated_tail_is_reported_not_dropped() {
+    let mut dst = BytesMut::new();
+    Encoder::with_sentinel(0).encode_frame(b"ok", &mut dst);
+    // A second frame that never reaches its terminating sentinel.
+    dst.extend_from_slice(&[5, b'h', b'i']);
+
+    let mut decoder = Decoder::with_sentinel(0);
+    let frame = decoder.decode(&mut dst).unwrap().expect("first frame");
+    assert_eq!(&frame[..], b"ok");
+
+    assert!(decoder.decode(&mut dst).unwrap().is_none());
+    let err = decoder.decode_eof(&mut dst).unwrap_err();
+    assert!(matches!(err, CobsError::TruncatedFrame { .. }));
+}
+
+#[test]
+fn empty_tail_at_eof_yields_none() {
+    let mut dst = BytesMut::new();
+    Encoder::with_sentinel(0).encode_frame(b"ok", &mut dst);
+
+    let mut decoder = Decoder::with_sentinel(0);
+    decoder.decode(&mut dst).unwrap();
+    assert!(decoder.decode_eof(&mut dst).unwrap().is_none());
+}
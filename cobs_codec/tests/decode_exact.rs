@@ -0,0 +1,32 @@
+use cobs_codec::{decode_exact, encode_vec, CobsError};
+
+#[test]
+fn decodes_one_frame_and_returns_the_remainder() {
+    let mut datagram = encode_vec(0, b"hello");
+    datagram.extend_from_slice(b"trailing garbage");
+
+    let (payload, rest) = decode_exact(0, &datagram).unwrap();
+    assert_eq!(payload, b"hello");
+    assert_eq!(rest, b"trailing garbage");
+}
+
+#[test]
+fn consumes_nothing_more_than_the_first_frame() {
+    let mut two_frames = encode_vec(0, b"one");
+    two_frames.extend_from_slice(&encode_vec(0, b"two"));
+
+    let (first, rest) = decode_exact(0, &two_frames).unwrap();
+    assert_eq!(first, b"one");
+    let (second, rest) = decode_exact(0, rest).unwrap();
+    assert_eq!(second, b"two");
+    assert!(rest.is_empty());
+}
+
+#[test]
+fn reports_truncated_frame_when_no_delimiter_is_found() {
+    let data = [1u8, 2, 3];
+    assert!(matches!(
+        decode_exact(0, &data),
+        Err(CobsError::TruncatedFrame { offset: 3 })
+    ));
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "tokio")]
+
+use cobs_codec::bytes::Buf;
+use cobs_codec::Decoder;
+
+#[test]
+fn decodes_a_frame_split_across_a_chained_buffer() {
+    let mut framed = Vec::new();
+    cobs_codec::encode(0, b"hello", &mut framed);
+    framed.extend_from_slice(b"trailing");
+
+    // Split the frame itself across two chunks, as a chained network buffer
+    // might deliver it.
+    let (head, tail) = framed.split_at(2);
+    let mut chained = head.chain(tail);
+
+    let decoder = Decoder::with_sentinel(0);
+    let payload = decoder.decode_frame_buf(&mut chained).unwrap();
+    assert_eq!(payload, b"hello");
+    assert_eq!(chained.remaining(), b"trailing".len());
+}
+
+#[test]
+fn missing_delimiter_reports_truncated_frame() {
+    let mut chained = Buf::chain(b"abc".as_slice(), b"def".as_slice());
+
+    let decoder = Decoder::with_sentinel(0);
+    let err = decoder.decode_frame_buf(&mut chained).unwrap_err();
+    assert!(matches!(err, cobs_codec::CobsError::TruncatedFrame { .. }));
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "tokio")]
+
+use cobs_codec::bytes::BytesMut;
+use cobs_codec::{CobsError, Decoder, Encoder};
+
+#[test]
+fn drains_every_complete_frame_in_one_call() {
+    let mut dst = BytesMut::new();
+    let encoder = Encoder::with_sentinel(0);
+    encoder.encode_frame(b"one", &mut dst);
+    encoder.encode_frame(b"two", &mut dst);
+    encoder.encode_frame(b"three", &mut dst);
+
+    let mut decoder = Decoder::with_sentinel(0);
+    let frames = decoder.decode_many(&mut dst).unwrap();
+
+    assert_eq!(frames, vec![b"one".as_ref(), b"two".as_ref(), b"three".as_ref()]);
+    assert!(dst.is_empty());
+}
+
+#[test]
+fn leaves_a_trailing_partial_frame_buffered() {
+    let mut dst = BytesMut::new();
+    let encoder = Encoder::with_sentinel(0);
+    encoder.encode_frame(b"one", &mut dst);
+    dst.extend_from_slice(b"\x05partial"); // no terminating sentinel yet
+
+    let mut decoder = Decoder::with_sentinel(0);
+    let frames = decoder.decode_many(&mut dst).unwrap();
+
+    assert_eq!(frames, vec![b"one".as_ref()]);
+    assert_eq!(&dst[..], b"\x05partial");
+}
+
+#[test]
+fn stops_at_the_first_malformed_frame() {
+    let mut dst = BytesMut::new();
+    let encoder = Encoder::with_sentinel(0);
+    encoder.encode_frame(b"good", &mut dst);
+    dst.extend_from_slice(&[0xFF, 1, 0]); // invalid code byte mid-frame
+
+    let mut decoder = Decoder::with_sentinel(0);
+    assert!(matches!(
+        decoder.decode_many(&mut dst),
+        Err(CobsError::UnexpectedSentinel { .. })
+    ));
+}
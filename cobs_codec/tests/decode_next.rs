@@ -0,0 +1,44 @@
+#![cfg(feature = "tokio")]
+
+use cobs_codec::bytes::BytesMut;
+use cobs_codec::{Decoder, Encoder};
+
+#[test]
+fn borrows_each_frame_and_advances_past_it() {
+    let mut encoded = Vec::new();
+    Encoder::with_sentinel(0).encode_frame_into(b"hello", &mut encoded);
+    Encoder::with_sentinel(0).encode_frame_into(b"world", &mut encoded);
+
+    let mut src = BytesMut::from(&encoded[..]);
+    let decoder = Decoder::with_sentinel(0);
+
+    {
+        let frame = decoder.decode_next(&mut src).unwrap().unwrap();
+        assert_eq!(&frame[..], b"hello");
+    }
+    {
+        let frame = decoder.decode_next(&mut src).unwrap().unwrap();
+        assert_eq!(&frame[..], b"world");
+    }
+
+    assert!(decoder.decode_next(&mut src).is_none());
+    assert!(src.is_empty());
+}
+
+#[test]
+fn returns_none_on_a_truncated_trailing_frame() {
+    let mut src = BytesMut::from(&b"\x02a"[..]);
+    let decoder = Decoder::with_sentinel(0);
+
+    assert!(decoder.decode_next(&mut src).is_none());
+    assert_eq!(&src[..], b"\x02a");
+}
+
+#[test]
+fn surfaces_decode_errors_and_still_advances_past_the_frame() {
+    let mut src = BytesMut::from(&b"\x05\x00more"[..]);
+    let decoder = Decoder::with_sentinel(0);
+
+    assert!(decoder.decode_next(&mut src).unwrap().is_err());
+    assert_eq!(&src[..], b"more");
+}
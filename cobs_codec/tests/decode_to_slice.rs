@@ -0,0 +1,40 @@
+use cobs_codec::{decode_to_slice, encode, DecodeToSliceError};
+
+#[test]
+fn matches_the_allocating_decoder_for_various_payloads() {
+    for data in [
+        &b""[..],
+        &b"\0"[..],
+        &b"hello"[..],
+        &[0u8; 10][..],
+        &[1u8; 300][..],
+    ] {
+        let mut framed = Vec::new();
+        encode(0, data, &mut framed);
+        let frame = &framed[..framed.len() - 1]; // drop the trailing delimiter
+
+        let mut dst = vec![0u8; data.len()];
+        let n = decode_to_slice(0, frame, &mut dst).unwrap();
+        assert_eq!(&dst[..n], data);
+    }
+}
+
+#[test]
+fn reports_buffer_too_small_instead_of_writing_a_partial_payload() {
+    let mut framed = Vec::new();
+    encode(0, b"hello", &mut framed);
+    let frame = &framed[..framed.len() - 1];
+
+    let mut dst = [0u8; 2];
+    assert!(matches!(
+        decode_to_slice(0, frame, &mut dst),
+        Err(DecodeToSliceError::BufferTooSmall)
+    ));
+}
+
+#[test]
+fn propagates_framing_errors() {
+    let mut dst = [0u8; 16];
+    let err = decode_to_slice(0, &[0xFF, 1, 2], &mut dst).unwrap_err();
+    assert!(matches!(err, DecodeToSliceError::Cobs(_)));
+}
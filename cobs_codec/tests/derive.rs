@@ -0,0 +1,51 @@
+#![cfg(feature = "tokio")]
+
+use cobs_codec::bytes::BytesMut;
+use cobs_codec::{CobsError, CobsFrame, Decoder};
+use tokio_util::codec::Decoder as _;
+
+#[derive(CobsFrame, Debug, PartialEq)]
+struct Msg {
+    id: u32,
+    flag: bool,
+    payload: Vec<u8>,
+    label: String,
+}
+
+#[test]
+fn round_trips_through_encoder_and_decoder() {
+    let msg = Msg {
+        id: 0xDEAD_BEEF,
+        flag: true,
+        payload: vec![0, 1, 2, 0, 0, 255],
+        label: "héllo, world".to_string(),
+    };
+
+    let mut dst = BytesMut::new();
+    msg.to_cobs_frame(&mut dst);
+
+    let mut decoder = Decoder::with_sentinel(0);
+    let frame = decoder.decode(&mut dst).unwrap().expect("one complete frame");
+    let decoded = Msg::from_cobs_frame(&frame).unwrap();
+
+    assert_eq!(msg, decoded);
+}
+
+#[test]
+fn truncated_length_field_is_reported() {
+    let msg = Msg {
+        id: 1,
+        flag: false,
+        payload: vec![9, 8, 7],
+        label: "abc".to_string(),
+    };
+
+    let mut dst = BytesMut::new();
+    msg.to_cobs_frame(&mut dst);
+    let mut decoder = Decoder::with_sentinel(0);
+    let frame = decoder.decode(&mut dst).unwrap().expect("one complete frame");
+
+    // Chop the payload so a varint promises more bytes than remain.
+    let result = Msg::from_cobs_frame(&frame[..frame.len() - 2]);
+    assert!(matches!(result, Err(CobsError::TruncatedFrame { .. })));
+}
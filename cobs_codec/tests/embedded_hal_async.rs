@@ -0,0 +1,32 @@
+#![cfg(feature = "embedded-hal-async")]
+
+use cobs_codec::embedded_io_async::{CobsEmbeddedAsyncFixedReader, CobsEmbeddedAsyncFixedWriter};
+use futures_executor::block_on;
+
+#[test]
+fn round_trips_a_frame_through_fixed_capacity_buffers() {
+    let mut dst: Vec<u8> = Vec::new();
+    block_on(async {
+        let mut writer = CobsEmbeddedAsyncFixedWriter::<_, 32>::new(0, &mut dst);
+        writer.write_frame(b"hello").await.unwrap();
+    });
+
+    block_on(async {
+        let mut reader = CobsEmbeddedAsyncFixedReader::<_, 32>::new(0, &dst[..]);
+        let frame = reader.read_frame().await.unwrap().unwrap();
+        assert_eq!(&frame[..], b"hello");
+        assert!(reader.read_frame().await.unwrap().is_none());
+    });
+}
+
+#[test]
+fn reports_buffer_too_small_for_an_oversized_frame() {
+    let mut dst: Vec<u8> = Vec::new();
+    block_on(async {
+        let mut writer = CobsEmbeddedAsyncFixedWriter::<_, 4>::new(0, &mut dst);
+        assert!(matches!(
+            writer.write_frame(b"way too long").await,
+            Err(cobs_codec::FixedFrameError::BufferTooSmall)
+        ));
+    });
+}
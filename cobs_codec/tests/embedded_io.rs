@@ -0,0 +1,37 @@
+#![cfg(feature = "embedded-io")]
+
+use cobs_codec::embedded_io::{CobsEmbeddedReader, CobsEmbeddedWriter};
+use cobs_codec::encode;
+
+#[test]
+fn writes_framed_data_to_the_inner_writer() {
+    let mut dst: Vec<u8> = Vec::new();
+    let mut writer = CobsEmbeddedWriter::new(0, &mut dst);
+    writer.write_frame(b"one").unwrap();
+    writer.write_frame(b"two").unwrap();
+
+    let mut expected = Vec::new();
+    encode(0, b"one", &mut expected);
+    encode(0, b"two", &mut expected);
+    assert_eq!(dst, expected);
+}
+
+#[test]
+fn reads_frames_one_at_a_time_from_the_inner_reader() {
+    let mut captured = Vec::new();
+    encode(0, b"one", &mut captured);
+    encode(0, b"two", &mut captured);
+
+    let mut reader = CobsEmbeddedReader::new(0, &captured[..]);
+
+    let mut buf = Vec::new();
+    assert!(reader.read_frame(&mut buf).unwrap());
+    assert_eq!(buf, b"one");
+
+    buf.clear();
+    assert!(reader.read_frame(&mut buf).unwrap());
+    assert_eq!(buf, b"two");
+
+    buf.clear();
+    assert!(!reader.read_frame(&mut buf).unwrap());
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "embedded-io-async")]
+
+use cobs_codec::embedded_io_async::{CobsEmbeddedAsyncReader, CobsEmbeddedAsyncWriter};
+use cobs_codec::encode;
+use futures_executor::block_on;
+
+#[test]
+fn writes_framed_data_to_the_inner_writer() {
+    let mut dst: Vec<u8> = Vec::new();
+    block_on(async {
+        let mut writer = CobsEmbeddedAsyncWriter::new(0, &mut dst);
+        writer.write_frame(b"one").await.unwrap();
+        writer.write_frame(b"two").await.unwrap();
+    });
+
+    let mut expected = Vec::new();
+    encode(0, b"one", &mut expected);
+    encode(0, b"two", &mut expected);
+    assert_eq!(dst, expected);
+}
+
+#[test]
+fn reads_frames_one_at_a_time_from_the_inner_reader() {
+    let mut captured = Vec::new();
+    encode(0, b"one", &mut captured);
+    encode(0, b"two", &mut captured);
+
+    block_on(async {
+        let mut reader = CobsEmbeddedAsyncReader::new(0, &captured[..]);
+
+        let mut buf = Vec::new();
+        assert!(reader.read_frame(&mut buf).await.unwrap());
+        assert_eq!(buf, b"one");
+
+        buf.clear();
+        assert!(reader.read_frame(&mut buf).await.unwrap());
+        assert_eq!(buf, b"two");
+
+        buf.clear();
+        assert!(!reader.read_frame(&mut buf).await.unwrap());
+    });
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "tokio")]
+
+use cobs_codec::bytes::BytesMut;
+use cobs_codec::{CobsError, Decoder, EmptyFrames};
+use tokio_util::codec;
+
+fn framed_with_a_keepalive_between() -> BytesMut {
+    let mut dst = Vec::new();
+    cobs_codec::encode(0, b"one", &mut dst);
+    dst.push(0); // a bare keep-alive delimiter
+    cobs_codec::encode(0, b"two", &mut dst);
+    BytesMut::from(&dst[..])
+}
+
+#[test]
+fn yields_the_empty_frame_by_default() {
+    let mut dst = framed_with_a_keepalive_between();
+    let mut decoder = Decoder::with_sentinel(0);
+
+    assert_eq!(codec::Decoder::decode(&mut decoder, &mut dst).unwrap().unwrap(), b"one".as_ref());
+    assert_eq!(codec::Decoder::decode(&mut decoder, &mut dst).unwrap().unwrap(), b"".as_ref());
+    assert_eq!(codec::Decoder::decode(&mut decoder, &mut dst).unwrap().unwrap(), b"two".as_ref());
+}
+
+#[test]
+fn skips_the_empty_frame_silently() {
+    let mut dst = framed_with_a_keepalive_between();
+    let mut decoder = Decoder::with_sentinel(0).with_empty_frames(EmptyFrames::Skip);
+
+    assert_eq!(codec::Decoder::decode(&mut decoder, &mut dst).unwrap().unwrap(), b"one".as_ref());
+    assert_eq!(codec::Decoder::decode(&mut decoder, &mut dst).unwrap().unwrap(), b"two".as_ref());
+}
+
+#[test]
+fn reports_an_error_for_the_empty_frame() {
+    let mut dst = framed_with_a_keepalive_between();
+    let mut decoder = Decoder::with_sentinel(0).with_empty_frames(EmptyFrames::Error);
+
+    assert_eq!(codec::Decoder::decode(&mut decoder, &mut dst).unwrap().unwrap(), b"one".as_ref());
+    let err = codec::Decoder::decode(&mut decoder, &mut dst).unwrap_err();
+    assert!(matches!(err, CobsError::EmptyFrame { .. }));
+    assert_eq!(codec::Decoder::decode(&mut decoder, &mut dst).unwrap().unwrap(), b"two".as_ref());
+}
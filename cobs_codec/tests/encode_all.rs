@@ -0,0 +1,20 @@
+#![cfg(feature = "tokio")]
+
+use cobs_codec::bytes::BytesMut;
+use cobs_codec::Encoder;
+
+#[test]
+fn matches_encoding_each_frame_individually() {
+    let encoder = Encoder::with_sentinel(0);
+    let frames: [&[u8]; 3] = [b"one", b"", b"three"];
+
+    let mut expected = BytesMut::new();
+    for frame in frames {
+        encoder.encode_frame(frame, &mut expected);
+    }
+
+    let mut actual = BytesMut::new();
+    encoder.encode_all(frames, &mut actual);
+
+    assert_eq!(actual, expected);
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "tokio")]
+
+use cobs_codec::bytes::BytesMut;
+use cobs_codec::{max_encoded_len, Encoder};
+
+#[test]
+fn reserves_the_full_encoded_length_up_front() {
+    let payload = vec![1u8; 64 * 1024];
+    let encoder = Encoder::with_sentinel(0);
+
+    let mut dst = BytesMut::new();
+    encoder.encode_frame(&payload, &mut dst);
+
+    // A single reservation sized for the worst case should cover the whole
+    // frame plus delimiter without ever reallocating mid-encode.
+    let mut probe = BytesMut::with_capacity(max_encoded_len(payload.len()) + 1);
+    let capacity_before = probe.capacity();
+    encoder.encode_frame(&payload, &mut probe);
+    assert_eq!(probe.capacity(), capacity_before);
+}
+
+#[test]
+fn encode_frame_into_reserves_up_front_too() {
+    let payload = vec![1u8; 64 * 1024];
+    let encoder = Encoder::with_sentinel(0);
+
+    let mut dst = Vec::with_capacity(max_encoded_len(payload.len()) + 1);
+    let capacity_before = dst.capacity();
+    encoder.encode_frame_into(&payload, &mut dst);
+    assert_eq!(dst.capacity(), capacity_before);
+}
@@ -0,0 +1,71 @@
+use cobs_codec::{decode, Encoder};
+
+fn round_trips(payload: &[u8]) {
+    let mut dst = Vec::new();
+    Encoder::with_sentinel(0).encode_frame_into(payload, &mut dst);
+    let frame = &dst[..dst.len() - 1];
+    assert_eq!(decode(0, frame).unwrap(), payload);
+}
+
+#[test]
+fn round_trips_an_empty_payload() {
+    round_trips(b"");
+}
+
+#[test]
+fn round_trips_a_payload_with_embedded_zeros() {
+    round_trips(b"a\0b\0\0c");
+}
+
+#[test]
+fn round_trips_a_payload_spanning_the_254_byte_group_boundary() {
+    round_trips(&[1u8; 254]);
+    round_trips(&[1u8; 255]);
+    round_trips(&[1u8; 600]);
+}
+
+#[test]
+fn matches_the_non_zero_sentinel_encoding() {
+    let payload = b"hello\0world";
+    let mut dst = Vec::new();
+    Encoder::with_sentinel(0xAA).encode_frame_into(payload, &mut dst);
+    let frame = &dst[..dst.len() - 1];
+    assert_eq!(decode(0xAA, frame).unwrap(), payload);
+}
+
+/// A sentinel-free payload short enough for one group with room to spare
+/// takes a fast path that skips the general algorithm's group bookkeeping;
+/// it must still produce exactly the single-group encoding: a length-prefixed
+/// code byte, the payload, and the delimiter, all XORed with the sentinel.
+#[test]
+fn sentinel_free_short_payload_is_a_single_group() {
+    for sentinel in [0u8, 1, 0xAA, 0xFF] {
+        for len in [0usize, 1, 100, 253] {
+            let payload: Vec<u8> = (0..len).map(|i| (i % 255 + 1) as u8).collect();
+
+            let mut dst = Vec::new();
+            Encoder::with_sentinel(sentinel).encode_frame_into(&payload, &mut dst);
+
+            let mut expected: Vec<u8> = vec![(len as u8 + 1) ^ sentinel];
+            expected.extend(payload.iter().map(|&b| b ^ sentinel));
+            expected.push(sentinel);
+            assert_eq!(dst, expected, "sentinel {sentinel} len {len}");
+        }
+    }
+}
+
+/// At exactly 254 bytes (the largest a group can hold), the payload still
+/// needs the general algorithm's trailing empty group to mark that no more
+/// data follows — the fast path above must not kick in here.
+#[test]
+fn sentinel_free_payload_at_the_group_boundary_gets_a_trailing_empty_group() {
+    let payload = [1u8; 254];
+    let mut dst = Vec::new();
+    Encoder::with_sentinel(0).encode_frame_into(&payload, &mut dst);
+
+    let mut expected = vec![255u8];
+    expected.extend_from_slice(&payload);
+    expected.push(1); // trailing empty group
+    expected.push(0); // delimiter
+    assert_eq!(dst, expected);
+}
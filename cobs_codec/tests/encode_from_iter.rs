@@ -0,0 +1,41 @@
+use cobs_codec::{decode, Encoder};
+
+#[test]
+fn matches_encoding_the_collected_payload() {
+    let payload: Vec<u8> = (0..=10u8).collect();
+
+    let mut from_iter = Vec::new();
+    Encoder::with_sentinel(0).encode_from_iter_into(payload.iter().copied(), &mut from_iter);
+
+    let mut whole = Vec::new();
+    Encoder::with_sentinel(0).encode_frame_into(&payload, &mut whole);
+
+    assert_eq!(from_iter, whole);
+}
+
+#[test]
+fn round_trips_a_payload_spanning_multiple_groups() {
+    let payload: Vec<u8> = (0..600u32).map(|i| (i % 255) as u8 + 1).collect();
+
+    let mut dst = Vec::new();
+    Encoder::with_sentinel(0).encode_from_iter_into(payload.iter().copied(), &mut dst);
+    let frame = &dst[..dst.len() - 1];
+
+    assert_eq!(decode(0, frame).unwrap(), payload);
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn encode_from_iter_matches_the_vec_primitive() {
+    use cobs_codec::bytes::BytesMut;
+
+    let payload = b"hello world";
+
+    let mut into_bytes = BytesMut::new();
+    Encoder::with_sentinel(0).encode_from_iter(payload.iter().copied(), &mut into_bytes);
+
+    let mut into_vec = Vec::new();
+    Encoder::with_sentinel(0).encode_from_iter_into(payload.iter().copied(), &mut into_vec);
+
+    assert_eq!(&into_bytes[..], &into_vec[..]);
+}
@@ -0,0 +1,26 @@
+use cobs_codec::{encode, encode_to_slice, max_encoded_len, BufferTooSmall};
+
+#[test]
+fn matches_the_allocating_encoder_for_various_payloads() {
+    for data in [
+        &b""[..],
+        &b"\0"[..],
+        &b"hello"[..],
+        &[0u8; 10][..],
+        &[1u8; 300][..],
+    ] {
+        let mut framed = Vec::new();
+        encode(0, data, &mut framed);
+
+        let mut dst = vec![0u8; max_encoded_len(data.len()) + 1];
+        let n = encode_to_slice(0, data, &mut dst).unwrap();
+        assert_eq!(&dst[..n], &framed[..]);
+    }
+}
+
+#[test]
+fn reports_buffer_too_small_instead_of_writing_a_partial_frame() {
+    let data = [1u8, 2, 3, 4, 5];
+    let mut dst = [0u8; 3];
+    assert_eq!(encode_to_slice(0, &data, &mut dst), Err(BufferTooSmall));
+}
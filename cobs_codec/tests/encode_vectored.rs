@@ -0,0 +1,42 @@
+use cobs_codec::{decode, Encoder};
+
+fn concatenated(chunks: &[&[u8]]) -> Vec<u8> {
+    chunks.concat()
+}
+
+#[test]
+fn matches_encoding_the_concatenated_chunks() {
+    let chunks: [&[u8]; 2] = [b"hello ", b"world"];
+
+    let mut vectored = Vec::new();
+    Encoder::with_sentinel(0).encode_vectored_into(chunks, &mut vectored);
+
+    let mut whole = Vec::new();
+    Encoder::with_sentinel(0).encode_frame_into(&concatenated(&chunks), &mut whole);
+
+    assert_eq!(vectored, whole);
+}
+
+#[test]
+fn round_trips_a_zero_byte_split_across_chunk_boundaries() {
+    let chunks: [&[u8]; 3] = [b"a\0", b"", b"\0b"];
+
+    let mut dst = Vec::new();
+    Encoder::with_sentinel(0).encode_vectored_into(chunks, &mut dst);
+    let frame = &dst[..dst.len() - 1];
+
+    assert_eq!(decode(0, frame).unwrap(), concatenated(&chunks));
+}
+
+#[test]
+fn round_trips_a_group_rollover_split_across_chunk_boundaries() {
+    let first = [1u8; 200];
+    let second = [2u8; 200];
+    let chunks: [&[u8]; 2] = [&first, &second];
+
+    let mut dst = Vec::new();
+    Encoder::with_sentinel(0).encode_vectored_into(chunks, &mut dst);
+    let frame = &dst[..dst.len() - 1];
+
+    assert_eq!(decode(0, frame).unwrap(), concatenated(&chunks));
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "tokio")]
+
+use cobs_codec::bytes::{Bytes, BytesMut};
+use cobs_codec::Encoder;
+use tokio_util::codec::Encoder as _;
+
+#[test]
+fn slice_and_bytes_items_match_the_owned_vec_encoding() {
+    let payload = vec![0, 1, 2, 0, 0, 255];
+
+    let mut via_vec = BytesMut::new();
+    Encoder::with_sentinel(0).encode(payload.clone(), &mut via_vec).unwrap();
+
+    let mut via_slice = BytesMut::new();
+    Encoder::with_sentinel(0).encode(payload.as_slice(), &mut via_slice).unwrap();
+
+    let mut via_bytes = BytesMut::new();
+    Encoder::with_sentinel(0)
+        .encode(Bytes::from(payload), &mut via_bytes)
+        .unwrap();
+
+    assert_eq!(via_vec, via_slice);
+    assert_eq!(via_vec, via_bytes);
+}
@@ -0,0 +1,43 @@
+use cobs_codec::escape::{EscapedDecoder, EscapedEncoder};
+
+const XON: u8 = 0x11;
+const XOFF: u8 = 0x13;
+const ESCAPE: u8 = 0x10;
+
+fn round_trips(payload: &[u8]) {
+    let encoder = EscapedEncoder::new(0, ESCAPE, &[XON, XOFF]);
+    let decoder = EscapedDecoder::new(0, ESCAPE);
+
+    let mut dst = Vec::new();
+    encoder.encode_frame_into(payload, &mut dst);
+
+    let frame = &dst[..dst.len() - 1];
+    assert_eq!(decoder.decode_frame(frame).unwrap(), payload);
+}
+
+#[test]
+fn round_trips_a_payload_without_any_reserved_bytes() {
+    round_trips(b"hello world");
+}
+
+#[test]
+fn round_trips_a_payload_containing_flow_control_bytes() {
+    round_trips(&[1, XON, 2, XOFF, 3, 0, XON, XOFF]);
+}
+
+#[test]
+fn the_encoded_frame_never_contains_a_reserved_byte_outside_the_delimiter() {
+    let encoder = EscapedEncoder::new(0, ESCAPE, &[XON, XOFF]);
+    let mut dst = Vec::new();
+    encoder.encode_frame_into(&[1, XON, 2, XOFF, 3], &mut dst);
+
+    let body = &dst[..dst.len() - 1];
+    assert!(!body.contains(&XON));
+    assert!(!body.contains(&XOFF));
+    assert_eq!(*dst.last().unwrap(), 0);
+}
+
+#[test]
+fn round_trips_a_payload_containing_the_escape_byte_itself() {
+    round_trips(&[ESCAPE, 1, ESCAPE, ESCAPE, 2]);
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "tokio")]
+
+use cobs_codec::bytes::BytesMut;
+use cobs_codec::{DecoderEvent, Encoder, EventDecoder};
+use tokio_util::codec;
+
+#[test]
+fn reports_a_frame_decoded_event() {
+    let encoder = Encoder::with_sentinel(0);
+    let mut dst = BytesMut::new();
+    encoder.encode_frame(b"hello", &mut dst);
+    let stuffed_bytes = dst.len() - 1; // delimiter excluded
+
+    let mut events = Vec::new();
+    let mut decoder = EventDecoder::with_sentinel(0, |e| events.push(e));
+    let frame = codec::Decoder::decode(&mut decoder, &mut dst).unwrap().unwrap();
+
+    assert_eq!(frame, b"hello".as_ref());
+    assert_eq!(events, vec![DecoderEvent::FrameDecoded { payload_len: 5, stuffed_bytes }]);
+}
+
+#[test]
+fn reports_resync_and_discard_events_before_the_next_good_frame() {
+    let mut dst = BytesMut::new();
+    dst.extend_from_slice(&[2, 0]); // code byte claims a data byte that never arrives
+    let encoder = Encoder::with_sentinel(0);
+    encoder.encode_frame(b"two", &mut dst);
+
+    let mut events = Vec::new();
+    let mut decoder = EventDecoder::with_sentinel(0, |e| events.push(e)).with_resync(true);
+    let frame = codec::Decoder::decode(&mut decoder, &mut dst).unwrap().unwrap();
+
+    assert_eq!(frame, b"two".as_ref());
+    assert_eq!(
+        events,
+        vec![
+            DecoderEvent::ResyncStarted,
+            DecoderEvent::BytesDiscarded { len: 2 },
+            DecoderEvent::FrameDecoded { payload_len: 3, stuffed_bytes: 4 },
+        ]
+    );
+}
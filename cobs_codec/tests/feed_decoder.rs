@@ -0,0 +1,91 @@
+#![cfg(feature = "postcard")]
+
+use cobs_codec::typed::{FeedDecoder, FeedResult};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Demo {
+    a: u32,
+    b: u8,
+}
+
+#[test]
+fn feeds_two_frames_arriving_in_one_chunk() {
+    // Build two COBS-framed postcard messages back to back.
+    let mut buf = Vec::new();
+    let ser = postcard::to_allocvec(&Demo { a: 10, b: 20 }).unwrap();
+    cobs_codec::encode(0, &ser, &mut buf);
+    let ser2 = postcard::to_allocvec(&Demo { a: 4242, b: 9 }).unwrap();
+    cobs_codec::encode(0, &ser2, &mut buf);
+
+    let mut decoder = FeedDecoder::<64>::with_sentinel(0);
+
+    let remaining = match decoder.feed::<Demo>(&buf) {
+        FeedResult::Success { data, remaining } => {
+            assert_eq!(data, Demo { a: 10, b: 20 });
+            remaining
+        }
+        _ => panic!("expected the first message to deserialize"),
+    };
+
+    match decoder.feed::<Demo>(remaining) {
+        FeedResult::Success { data, remaining } => {
+            assert_eq!(data, Demo { a: 4242, b: 9 });
+            assert!(remaining.is_empty());
+        }
+        _ => panic!("expected the second message to deserialize"),
+    }
+}
+
+#[test]
+fn feeds_one_chunk_at_a_time() {
+    let ser = postcard::to_allocvec(&Demo { a: 1, b: 2 }).unwrap();
+    let mut framed = Vec::new();
+    cobs_codec::encode(0, &ser, &mut framed);
+
+    let mut decoder = FeedDecoder::<64>::with_sentinel(0);
+    let mut window = &framed[..];
+
+    loop {
+        window = match decoder.feed::<Demo>(window) {
+            FeedResult::Consumed => break,
+            FeedResult::Success { data, remaining } => {
+                assert_eq!(data, Demo { a: 1, b: 2 });
+                remaining
+            }
+            FeedResult::OverFull(_) | FeedResult::DeserError(_) => panic!("unexpected result"),
+        };
+        if window.is_empty() {
+            break;
+        }
+    }
+}
+
+#[test]
+fn reports_overfull_instead_of_panicking() {
+    let ser = postcard::to_allocvec(&Demo { a: u32::MAX, b: 2 }).unwrap();
+    let mut framed = Vec::new();
+    cobs_codec::encode(0, &ser, &mut framed);
+    assert!(framed.len() > 4);
+
+    let mut decoder = FeedDecoder::<4>::with_sentinel(0);
+    assert!(matches!(
+        decoder.feed::<Demo>(&framed),
+        FeedResult::OverFull(_)
+    ));
+}
+
+#[test]
+fn reports_deser_error_for_a_malformed_payload() {
+    // A lone 0xFF byte is a validly-framed COBS payload, but not a valid
+    // postcard encoding of `Demo` (its varint `a` promises more bytes than
+    // follow).
+    let mut framed = Vec::new();
+    cobs_codec::encode(0, &[0xFF], &mut framed);
+
+    let mut decoder = FeedDecoder::<64>::with_sentinel(0);
+    assert!(matches!(
+        decoder.feed::<Demo>(&framed),
+        FeedResult::DeserError(_)
+    ));
+}
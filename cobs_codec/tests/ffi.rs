@@ -0,0 +1,44 @@
+#![cfg(feature = "ffi")]
+
+use cobs_codec::ffi::{cobs_decode, cobs_decoder_feed, cobs_decoder_free, cobs_decoder_new, cobs_encode};
+
+#[test]
+fn round_trips_a_payload_through_the_slice_functions() {
+    let payload = b"hello world";
+    let mut frame = [0u8; 32];
+    let written = unsafe { cobs_encode(0, payload.as_ptr(), payload.len(), frame.as_mut_ptr(), frame.len()) };
+    assert!(written > 0);
+    let written = written as usize;
+
+    let mut decoded = [0u8; 32];
+    // The trailing delimiter isn't part of the frame content.
+    let n = unsafe {
+        cobs_decode(0, frame.as_ptr(), written - 1, decoded.as_mut_ptr(), decoded.len())
+    };
+    assert!(n > 0);
+    assert_eq!(&decoded[..n as usize], payload);
+}
+
+#[test]
+fn reports_an_undersized_destination_buffer() {
+    let payload = [1u8; 16];
+    let mut frame = [0u8; 4];
+    let written = unsafe { cobs_encode(0, payload.as_ptr(), payload.len(), frame.as_mut_ptr(), frame.len()) };
+    assert_eq!(written, -1);
+}
+
+#[test]
+fn decoder_handle_yields_a_frame_once_the_sentinel_arrives() {
+    let decoder = cobs_decoder_new(0);
+    let mut dst = [0u8; 16];
+
+    for &b in &[6u8, b'h', b'e', b'l', b'l', b'o'] {
+        let n = unsafe { cobs_decoder_feed(decoder, b, dst.as_mut_ptr(), dst.len()) };
+        assert_eq!(n, 0);
+    }
+    let n = unsafe { cobs_decoder_feed(decoder, 0, dst.as_mut_ptr(), dst.len()) };
+    assert_eq!(n, 5);
+    assert_eq!(&dst[..5], b"hello");
+
+    unsafe { cobs_decoder_free(decoder) };
+}
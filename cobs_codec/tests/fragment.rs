@@ -0,0 +1,90 @@
+use cobs_codec::fragment::{FragmentingEncoder, ReassemblingDecoder};
+use cobs_codec::{decode, CobsError};
+
+fn feed(sentinel: u8, dst: &[u8]) -> Option<Vec<u8>> {
+    let mut reassembler = ReassemblingDecoder::with_sentinel(sentinel);
+    let mut result = None;
+    for frame in dst.split_inclusive(|&b| b == sentinel) {
+        let frame = &frame[..frame.len() - 1];
+        if let Some(payload) = reassembler.decode_frame(frame).unwrap() {
+            assert!(result.is_none(), "reassembled more than one message");
+            result = Some(payload);
+        }
+    }
+    result
+}
+
+#[test]
+fn round_trips_a_payload_smaller_than_the_mtu() {
+    let mut dst = Vec::new();
+    FragmentingEncoder::new(0, 240).encode_into(b"hello", &mut dst);
+
+    let frame = &dst[..dst.len() - 1];
+    let fragment = decode(0, frame).unwrap();
+    assert_eq!(fragment, [&[0, 0][..], b"hello"].concat());
+    assert_eq!(feed(0, &dst).unwrap(), b"hello");
+}
+
+#[test]
+fn round_trips_a_payload_requiring_several_fragments() {
+    let payload: Vec<u8> = (0..600).map(|i| (i % 256) as u8).collect();
+
+    let mut dst = Vec::new();
+    FragmentingEncoder::new(0, 240).encode_into(&payload, &mut dst);
+
+    assert_eq!(dst.iter().filter(|&&b| b == 0).count(), 3);
+    assert_eq!(feed(0, &dst).unwrap(), payload);
+}
+
+#[test]
+fn round_trips_an_empty_payload() {
+    let mut dst = Vec::new();
+    FragmentingEncoder::new(0, 240).encode_into(b"", &mut dst);
+    assert_eq!(feed(0, &dst).unwrap(), b"");
+}
+
+#[test]
+fn rejects_a_dropped_fragment() {
+    let payload: Vec<u8> = vec![1u8; 600];
+    let mut dst = Vec::new();
+    FragmentingEncoder::new(0, 240).encode_into(&payload, &mut dst);
+
+    // Drop the first fragment (up to and including its delimiter).
+    let first_frame_len = dst.iter().position(|&b| b == 0).unwrap() + 1;
+    let truncated = &dst[first_frame_len..];
+
+    let mut reassembler = ReassemblingDecoder::with_sentinel(0);
+    let mut frames = truncated.split_inclusive(|&b| b == 0);
+    let frame = frames.next().unwrap();
+    let frame = &frame[..frame.len() - 1];
+    match reassembler.decode_frame(frame) {
+        Err(CobsError::FragmentGap { expected: 0, found: 1 }) => {}
+        other => panic!("expected a fragment gap error, got {other:?}"),
+    }
+}
+
+#[test]
+fn a_dropped_last_fragment_does_not_also_lose_the_next_message() {
+    let a: Vec<u8> = vec![1u8; 600];
+    let mut a_frames_buf = Vec::new();
+    FragmentingEncoder::new(0, 240).encode_into(&a, &mut a_frames_buf);
+    let a_frames: Vec<&[u8]> = a_frames_buf
+        .split_inclusive(|&b| b == 0)
+        .map(|f| &f[..f.len() - 1])
+        .collect();
+    assert_eq!(a_frames.len(), 3);
+
+    let mut reassembler = ReassemblingDecoder::with_sentinel(0);
+    // Feed only the first two of message A's three fragments; the last one
+    // is dropped, leaving the reassembler mid-message.
+    assert!(reassembler.decode_frame(a_frames[0]).unwrap().is_none());
+    assert!(reassembler.decode_frame(a_frames[1]).unwrap().is_none());
+
+    // Message B arrives as a single (seq 0) fragment; it must reassemble on
+    // the first try instead of being eaten as an out-of-order continuation
+    // of the abandoned message A.
+    let mut b_frame_buf = Vec::new();
+    FragmentingEncoder::new(0, 240).encode_into(b"hello", &mut b_frame_buf);
+    let b_frame = &b_frame_buf[..b_frame_buf.len() - 1];
+    assert_eq!(reassembler.decode_frame(b_frame).unwrap(), Some(b"hello".to_vec()));
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "tokio")]
+
+use cobs_codec::bytes::BytesMut;
+use cobs_codec::{Decoder, Encoder};
+
+#[test]
+fn reports_no_frame_in_progress_on_an_empty_or_terminated_buffer() {
+    let decoder = Decoder::with_sentinel(0);
+    assert!(!decoder.is_mid_frame(b""));
+    assert_eq!(decoder.buffered_len(b""), 0);
+
+    let mut dst = BytesMut::new();
+    Encoder::with_sentinel(0).encode_frame(b"hello", &mut dst);
+    assert!(!decoder.is_mid_frame(&dst));
+    assert_eq!(decoder.buffered_len(&dst), 0);
+}
+
+#[test]
+fn reports_a_frame_in_progress_on_a_partial_buffer() {
+    let decoder = Decoder::with_sentinel(0);
+    let mut dst = BytesMut::new();
+    Encoder::with_sentinel(0).encode_frame(b"hello", &mut dst);
+    dst.truncate(dst.len() - 1); // drop the trailing delimiter
+
+    assert!(decoder.is_mid_frame(&dst));
+    assert_eq!(decoder.buffered_len(&dst), dst.len());
+}
+
+#[test]
+fn reset_clears_the_buffer_but_keeps_the_decoders_configuration() {
+    let decoder = Decoder::with_sentinel(0).with_resync(true).with_stats(true);
+    let mut dst = BytesMut::new();
+    Encoder::with_sentinel(0).encode_frame(b"hello", &mut dst);
+    dst.truncate(dst.len() - 1);
+
+    decoder.reset(&mut dst);
+
+    assert!(dst.is_empty());
+    assert!(!decoder.is_mid_frame(&dst));
+}
@@ -0,0 +1,29 @@
+#![cfg(all(feature = "tokio", feature = "tokio-io", feature = "futures"))]
+
+use cobs_codec::{framed, framed_with_sentinel};
+use futures_executor::block_on;
+use futures_util::{SinkExt, StreamExt};
+
+#[test]
+fn framed_round_trips_over_a_duplex_stream_on_the_default_sentinel() {
+    let (a, b) = tokio::io::duplex(64);
+    block_on(async {
+        let mut a = framed(a);
+        let mut b = framed(b);
+
+        a.send(b"hello".to_vec()).await.unwrap();
+        assert_eq!(&b.next().await.unwrap().unwrap()[..], b"hello");
+    });
+}
+
+#[test]
+fn framed_with_sentinel_frames_on_the_given_sentinel() {
+    let (a, b) = tokio::io::duplex(64);
+    block_on(async {
+        let mut a = framed_with_sentinel(a, 0xAA);
+        let mut b = framed_with_sentinel(b, 0xAA);
+
+        a.send(b"hello".to_vec()).await.unwrap();
+        assert_eq!(&b.next().await.unwrap().unwrap()[..], b"hello");
+    });
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "tokio-io")]
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use cobs_codec::tokio_io::FramedWriter;
+use tokio::io::AsyncWrite;
+
+#[derive(Default)]
+struct CountingWriter {
+    data: Vec<u8>,
+    flushes: usize,
+}
+
+impl AsyncWrite for CountingWriter {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        self.data.extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        self.flushes += 1;
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[tokio::test]
+async fn flushes_after_every_frame_by_default() {
+    let mut writer = FramedWriter::new(0, CountingWriter::default());
+    writer.write_frame(b"a").await.unwrap();
+    writer.write_frame(b"b").await.unwrap();
+
+    assert_eq!(writer.get_ref().flushes, 2);
+}
+
+#[tokio::test]
+async fn batches_writes_until_an_explicit_flush_when_auto_flush_is_disabled() {
+    let mut writer = FramedWriter::new(0, CountingWriter::default()).with_auto_flush(false);
+    writer.write_frame(b"a").await.unwrap();
+    writer.write_frame(b"b").await.unwrap();
+    assert_eq!(writer.get_ref().flushes, 0);
+
+    writer.flush().await.unwrap();
+    assert_eq!(writer.get_ref().flushes, 1);
+    assert_eq!(writer.into_inner().data.len(), 6); // two single-byte frames, each 3 bytes on the wire
+}
@@ -0,0 +1,33 @@
+use cobs_codec::{encode_vec, frames, CobsError};
+
+#[test]
+fn yields_every_frame_in_a_concatenated_buffer() {
+    let mut dump = Vec::new();
+    dump.extend_from_slice(&encode_vec(0, b"one"));
+    dump.extend_from_slice(&encode_vec(0, b"two"));
+    dump.extend_from_slice(&encode_vec(0, b""));
+    dump.extend_from_slice(&encode_vec(0, b"three"));
+
+    let decoded: Result<Vec<_>, _> = frames(0, &dump).collect();
+    assert_eq!(
+        decoded.unwrap(),
+        vec![b"one".to_vec(), b"two".to_vec(), b"".to_vec(), b"three".to_vec()]
+    );
+}
+
+#[test]
+fn empty_input_yields_no_frames() {
+    assert_eq!(frames(0, &[]).count(), 0);
+}
+
+#[test]
+fn stops_after_the_first_malformed_frame() {
+    let mut dump = encode_vec(0, b"good");
+    dump.extend_from_slice(&[0xFF, 1, 0]); // invalid code byte mid-frame
+    dump.extend_from_slice(&encode_vec(0, b"unreached"));
+
+    let mut it = frames(0, &dump);
+    assert_eq!(it.next().unwrap().unwrap(), b"good");
+    assert!(matches!(it.next(), Some(Err(CobsError::UnexpectedSentinel { .. }))));
+    assert!(it.next().is_none());
+}
@@ -0,0 +1,44 @@
+use cobs_codec::{encode_vec, frames_with_meta, CobsError, FrameMeta};
+
+#[test]
+fn reports_stream_offset_and_lengths_for_each_frame() {
+    let one = encode_vec(0, b"one");
+    let two = encode_vec(0, b"two");
+    let mut dump = Vec::new();
+    dump.extend_from_slice(&one);
+    dump.extend_from_slice(&two);
+
+    let decoded: Result<Vec<_>, _> = frames_with_meta(0, &dump).collect();
+    let decoded = decoded.unwrap();
+
+    assert_eq!(
+        decoded,
+        vec![
+            (
+                b"one".to_vec(),
+                FrameMeta { stream_offset: 0, encoded_len: 3, stuffed_bytes: one.len() - 1 }
+            ),
+            (
+                b"two".to_vec(),
+                FrameMeta { stream_offset: one.len(), encoded_len: 3, stuffed_bytes: two.len() - 1 }
+            ),
+        ]
+    );
+}
+
+#[test]
+fn empty_input_yields_no_frames() {
+    assert_eq!(frames_with_meta(0, &[]).count(), 0);
+}
+
+#[test]
+fn stops_after_the_first_malformed_frame() {
+    let mut dump = encode_vec(0, b"good");
+    dump.extend_from_slice(&[0xFF, 1, 0]); // invalid code byte mid-frame
+
+    let mut it = frames_with_meta(0, &dump);
+    let (payload, meta) = it.next().unwrap().unwrap();
+    assert_eq!(payload, b"good");
+    assert_eq!(meta.stream_offset, 0);
+    assert!(matches!(it.next(), Some(Err(CobsError::UnexpectedSentinel { .. }))));
+}
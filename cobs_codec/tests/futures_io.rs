@@ -0,0 +1,43 @@
+#![cfg(feature = "futures")]
+
+use cobs_codec::encode;
+use cobs_codec::futures_io::{CobsAsyncReader, CobsAsyncWriter};
+use futures_executor::block_on;
+use futures_util::io::{AllowStdIo, Cursor};
+
+#[test]
+fn writes_framed_data_to_the_inner_async_writer() {
+    let mut dst = Vec::new();
+    block_on(async {
+        let mut writer = CobsAsyncWriter::new(0, AllowStdIo::new(&mut dst));
+        writer.write_frame(b"one").await.unwrap();
+        writer.write_frame(b"two").await.unwrap();
+    });
+
+    let mut expected = Vec::new();
+    encode(0, b"one", &mut expected);
+    encode(0, b"two", &mut expected);
+    assert_eq!(dst, expected);
+}
+
+#[test]
+fn reads_frames_one_at_a_time_from_the_inner_async_reader() {
+    let mut captured = Vec::new();
+    encode(0, b"one", &mut captured);
+    encode(0, b"two", &mut captured);
+
+    block_on(async {
+        let mut reader = CobsAsyncReader::new(0, Cursor::new(captured));
+
+        let mut buf = Vec::new();
+        assert!(reader.read_frame(&mut buf).await.unwrap());
+        assert_eq!(buf, b"one");
+
+        buf.clear();
+        assert!(reader.read_frame(&mut buf).await.unwrap());
+        assert_eq!(buf, b"two");
+
+        buf.clear();
+        assert!(!reader.read_frame(&mut buf).await.unwrap());
+    });
+}
@@ -0,0 +1,103 @@
+#![cfg(feature = "heapless")]
+
+use cobs_codec::heapless::{decode_heapless, encode_heapless, FixedDecoder, FixedDecoderError, PushDecoder};
+use cobs_codec::DecodeToSliceError;
+
+#[test]
+fn round_trips_through_fixed_capacity_buffers() {
+    let payload = [0u8, 1, 2, 0, 0, 255];
+
+    let framed = encode_heapless::<16>(0, &payload).unwrap();
+    let decoded = decode_heapless::<16>(0, &framed[..framed.len() - 1]).unwrap();
+    assert_eq!(&decoded[..], &payload[..]);
+}
+
+#[test]
+fn encode_reports_buffer_too_small_instead_of_truncating() {
+    let payload = [1u8; 64];
+    assert!(encode_heapless::<4>(0, &payload).is_err());
+}
+
+#[test]
+fn push_decoder_reassembles_frames_fed_one_byte_at_a_time() {
+    let mut framed = Vec::new();
+    cobs_codec::encode(0, b"hi", &mut framed);
+    cobs_codec::encode(0, b"there", &mut framed);
+
+    let mut decoder = PushDecoder::<16>::with_sentinel(0);
+    let results = decoder.push(&framed);
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(&results[0].as_ref().unwrap()[..], b"hi");
+    assert_eq!(&results[1].as_ref().unwrap()[..], b"there");
+}
+
+#[test]
+fn push_decoder_reports_overflow_instead_of_growing() {
+    let mut decoder = PushDecoder::<4>::with_sentinel(0);
+    for &b in b"hello" {
+        if let Some(result) = decoder.feed(b) {
+            assert!(matches!(result, Err(DecodeToSliceError::BufferTooSmall)));
+            return;
+        }
+    }
+    panic!("expected an overflow before the sentinel");
+}
+
+#[test]
+fn fixed_decoder_reassembles_frames_fed_one_byte_at_a_time() {
+    let mut framed = Vec::new();
+    cobs_codec::encode(0, b"hi", &mut framed);
+    cobs_codec::encode(0, b"there", &mut framed);
+
+    let mut decoder = FixedDecoder::<0, 16>::new();
+    let results = decoder.push(&framed);
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(&results[0].as_ref().unwrap()[..], b"hi");
+    assert_eq!(&results[1].as_ref().unwrap()[..], b"there");
+}
+
+#[test]
+fn fixed_decoder_reports_frame_too_large_instead_of_growing() {
+    let mut decoder = FixedDecoder::<0, 4>::new();
+    for &b in b"hello" {
+        if let Some(result) = decoder.feed(b) {
+            assert!(matches!(result, Err(FixedDecoderError::FrameTooLarge)));
+            return;
+        }
+    }
+    panic!("expected an overflow before the sentinel");
+}
+
+#[test]
+fn push_decoder_reports_overflow_once_then_resumes_cleanly() {
+    let mut decoder = PushDecoder::<4>::with_sentinel(0);
+
+    let mut oversized: Vec<u8> = b"0123456789".to_vec();
+    oversized.push(0);
+    let results = decoder.push(&oversized);
+    assert_eq!(results.len(), 1, "expected exactly one error for the oversized frame");
+    assert!(matches!(results[0], Err(DecodeToSliceError::BufferTooSmall)));
+
+    let mut framed = Vec::new();
+    cobs_codec::encode(0, b"hi", &mut framed);
+    let results = decoder.push(&framed);
+    assert_eq!(&results[0].as_ref().unwrap()[..], b"hi");
+}
+
+#[test]
+fn fixed_decoder_reports_frame_too_large_once_then_resumes_cleanly() {
+    let mut decoder = FixedDecoder::<0, 4>::new();
+
+    let mut oversized: Vec<u8> = b"0123456789".to_vec();
+    oversized.push(0);
+    let results = decoder.push(&oversized);
+    assert_eq!(results.len(), 1, "expected exactly one error for the oversized frame");
+    assert!(matches!(results[0], Err(FixedDecoderError::FrameTooLarge)));
+
+    let mut framed = Vec::new();
+    cobs_codec::encode(0, b"hi", &mut framed);
+    let results = decoder.push(&framed);
+    assert_eq!(&results[0].as_ref().unwrap()[..], b"hi");
+}
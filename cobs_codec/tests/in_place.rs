@@ -0,0 +1,13 @@
+use cobs_codec::{encode, Decoder};
+
+#[test]
+fn decodes_in_place_over_the_stuffed_buffer() {
+    let payload = [0u8, 1, 2, 0, 0, 255, 254, 0];
+    let mut framed = Vec::new();
+    encode(0, &payload, &mut framed);
+    framed.truncate(framed.len() - 1); // drop the trailing sentinel
+
+    let decoder = Decoder::with_sentinel(0);
+    let len = decoder.decode_in_place(&mut framed).unwrap();
+    assert_eq!(&framed[..len], &payload[..]);
+}
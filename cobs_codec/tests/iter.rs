@@ -0,0 +1,53 @@
+use cobs_codec::iter::{decode_iter, encode_iter};
+
+fn encode_to_vec(sentinel: u8, src: &[u8]) -> Vec<u8> {
+    encode_iter(sentinel, src.iter().copied()).collect()
+}
+
+#[test]
+fn matches_the_allocating_encoder() {
+    for payload in [&b""[..], b"hello", b"\0\0\0", &[1u8; 300], &[0u8; 300]] {
+        let mut expected = Vec::new();
+        cobs_codec::encode(0, payload, &mut expected);
+        assert_eq!(encode_to_vec(0, payload), expected, "payload: {payload:?}");
+    }
+}
+
+#[test]
+fn matches_the_allocating_decoder() {
+    for payload in [&b""[..], b"hello", b"\0\0\0", &[1u8; 300], &[0u8; 300]] {
+        let mut framed = Vec::new();
+        cobs_codec::encode(0, payload, &mut framed);
+
+        let decoded: Result<Vec<u8>, _> =
+            decode_iter(0, framed.iter().copied()).collect();
+        assert_eq!(decoded.unwrap(), payload, "payload: {payload:?}");
+    }
+}
+
+#[test]
+fn decode_iter_stops_at_the_delimiter_leaving_the_rest_for_the_next_frame() {
+    let mut framed = Vec::new();
+    cobs_codec::encode(0, b"one", &mut framed);
+    cobs_codec::encode(0, b"two", &mut framed);
+
+    let mut bytes = framed.into_iter();
+    let first: Result<Vec<u8>, _> = decode_iter(0, &mut bytes).collect();
+    assert_eq!(first.unwrap(), b"one");
+
+    let second: Result<Vec<u8>, _> = decode_iter(0, &mut bytes).collect();
+    assert_eq!(second.unwrap(), b"two");
+}
+
+#[test]
+fn decode_iter_reports_a_truncated_frame() {
+    let mut framed = Vec::new();
+    cobs_codec::encode(0, b"hello", &mut framed);
+    framed.pop(); // drop the trailing delimiter
+
+    let decoded: Result<Vec<u8>, _> = decode_iter(0, framed.into_iter()).collect();
+    assert!(matches!(
+        decoded.unwrap_err(),
+        cobs_codec::CobsError::TruncatedFrame { .. }
+    ));
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "tokio")]
+
+use cobs_codec::bytes::BytesMut;
+use cobs_codec::{Decoder, Encoder};
+use tokio_util::codec;
+
+#[test]
+fn prepends_the_sentinel_before_each_frame() {
+    let encoder = Encoder::with_sentinel(0).with_leading_delimiter(true);
+    let mut dst = Vec::new();
+    encoder.encode_frame_into(b"hi", &mut dst);
+
+    let mut expected = vec![0u8];
+    cobs_codec::encode(0, b"hi", &mut expected);
+    assert_eq!(dst, expected);
+}
+
+#[test]
+fn decoder_tolerates_the_empty_frame_between_adjacent_delimiters() {
+    let encoder = Encoder::with_sentinel(0).with_leading_delimiter(true);
+    let mut dst = BytesMut::new();
+    encoder.encode_frame(b"one", &mut dst);
+    encoder.encode_frame(b"two", &mut dst);
+
+    let mut decoder = Decoder::with_sentinel(0);
+
+    // Leading delimiter of "one".
+    assert_eq!(codec::Decoder::decode(&mut decoder, &mut dst).unwrap().unwrap(), b"".as_ref());
+    assert_eq!(codec::Decoder::decode(&mut decoder, &mut dst).unwrap().unwrap(), b"one".as_ref());
+    // "one"'s trailing delimiter and "two"'s leading delimiter are adjacent.
+    assert_eq!(codec::Decoder::decode(&mut decoder, &mut dst).unwrap().unwrap(), b"".as_ref());
+    assert_eq!(codec::Decoder::decode(&mut decoder, &mut dst).unwrap().unwrap(), b"two".as_ref());
+    assert!(codec::Decoder::decode(&mut decoder, &mut dst).unwrap().is_none());
+}
@@ -0,0 +1,26 @@
+use cobs_codec::{encode, encoded_len, max_encoded_len};
+
+#[test]
+fn encoded_len_matches_actual_stuffed_length() {
+    for data in [
+        &b""[..],
+        &b"\0"[..],
+        &b"hello"[..],
+        &[0u8; 10][..],
+        &[1u8; 300][..],
+    ] {
+        let mut framed = Vec::new();
+        encode(0, data, &mut framed);
+        // `framed` also carries the trailing delimiter byte.
+        assert_eq!(encoded_len(data), framed.len() - 1);
+        assert!(encoded_len(data) <= max_encoded_len(data.len()));
+    }
+}
+
+#[test]
+fn max_encoded_len_is_exact_for_sentinel_free_input() {
+    let data = vec![1u8; 254];
+    let mut framed = Vec::new();
+    encode(0, &data, &mut framed);
+    assert_eq!(max_encoded_len(data.len()), framed.len() - 1);
+}
@@ -0,0 +1,50 @@
+use cobs_codec::{Decoder, Encoder};
+
+#[test]
+fn rolls_over_more_often_with_a_smaller_max_block() {
+    let payload = [1u8; 200];
+    let encoder = Encoder::with_sentinel(0).with_max_block(64);
+    let mut dst = Vec::new();
+    encoder.encode_frame_into(&payload, &mut dst);
+
+    // 64-byte groups (63 data bytes each) need 4 groups to cover 200 bytes,
+    // so 4 code bytes appear instead of the single one standard COBS would
+    // use for a payload this short.
+    let frame = &dst[..dst.len() - 1];
+    let code_bytes = frame.iter().filter(|&&b| b == 64).count();
+    assert_eq!(code_bytes, 3);
+
+    let decoded = Decoder::with_sentinel(0)
+        .with_max_block(64)
+        .decode_frame(frame)
+        .unwrap();
+    assert_eq!(decoded, payload);
+}
+
+#[test]
+fn mismatched_max_block_fails_to_decode_correctly() {
+    let payload = [1u8; 200];
+    let mut dst = Vec::new();
+    Encoder::with_sentinel(0)
+        .with_max_block(64)
+        .encode_frame_into(&payload, &mut dst);
+
+    let frame = &dst[..dst.len() - 1];
+    // Destuffing with the standard 254 cap misreads where groups end, so the
+    // result doesn't round-trip back to the original payload.
+    let decoded = Decoder::with_sentinel(0).decode_frame(frame).unwrap();
+    assert_ne!(decoded, payload);
+}
+
+#[test]
+fn round_trips_a_payload_with_embedded_zeros_under_a_small_max_block() {
+    let payload = b"aaaaaaaaaa\0bbbbbbbbbb\0cccccccccc";
+    let mut dst = Vec::new();
+    Encoder::with_sentinel(0)
+        .with_max_block(8)
+        .encode_frame_into(payload, &mut dst);
+
+    let frame = &dst[..dst.len() - 1];
+    let decoded = Decoder::with_sentinel(0).with_max_block(8).decode_frame(frame).unwrap();
+    assert_eq!(decoded, payload);
+}
@@ -0,0 +1,20 @@
+#![cfg(feature = "tokio")]
+
+use cobs_codec::bytes::BytesMut;
+use cobs_codec::{CobsError, Decoder, Encoder};
+use tokio_util::codec::Decoder as _;
+
+#[test]
+fn oversized_frame_is_rejected_and_discarded() {
+    let mut dst = BytesMut::new();
+    Encoder::with_sentinel(0).encode_frame(&[0u8; 10], &mut dst);
+    Encoder::with_sentinel(0).encode_frame(b"ok", &mut dst);
+
+    let mut decoder = Decoder::with_sentinel(0).with_max_frame_len(4);
+    let err = decoder.decode(&mut dst).unwrap_err();
+    assert!(matches!(err, CobsError::FrameTooLong { limit: 4 }));
+
+    // The oversized frame was discarded; the next one decodes normally.
+    let frame = decoder.decode(&mut dst).unwrap().expect("second frame");
+    assert_eq!(&frame[..], b"ok");
+}
@@ -0,0 +1,107 @@
+#![cfg(feature = "metrics")]
+
+use cobs_codec::{Decoder, Encoder};
+use metrics::{Counter, CounterFn, Histogram, HistogramFn, Key, KeyName, Metadata, Recorder, SharedString, Unit};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+#[derive(Default)]
+struct AtomicCounter(AtomicU64);
+
+impl CounterFn for AtomicCounter {
+    fn increment(&self, value: u64) {
+        self.0.fetch_add(value, Ordering::Relaxed);
+    }
+
+    fn absolute(&self, value: u64) {
+        self.0.store(value, Ordering::Relaxed);
+    }
+}
+
+#[derive(Default)]
+struct SampleHistogram(Mutex<Vec<f64>>);
+
+impl HistogramFn for SampleHistogram {
+    fn record(&self, value: f64) {
+        self.0.lock().unwrap().push(value);
+    }
+}
+
+#[derive(Default)]
+struct TestRecorder {
+    counters: Mutex<HashMap<String, Arc<AtomicCounter>>>,
+    histograms: Mutex<HashMap<String, Arc<SampleHistogram>>>,
+}
+
+impl Recorder for TestRecorder {
+    fn describe_counter(&self, _key: KeyName, _unit: Option<Unit>, _description: SharedString) {}
+    fn describe_gauge(&self, _key: KeyName, _unit: Option<Unit>, _description: SharedString) {}
+    fn describe_histogram(&self, _key: KeyName, _unit: Option<Unit>, _description: SharedString) {}
+
+    fn register_counter(&self, key: &Key, _metadata: &Metadata<'_>) -> Counter {
+        let mut counters = self.counters.lock().unwrap();
+        let counter = counters
+            .entry(key.name().to_string())
+            .or_insert_with(|| Arc::new(AtomicCounter::default()))
+            .clone();
+        Counter::from_arc(counter)
+    }
+
+    fn register_gauge(&self, _key: &Key, _metadata: &Metadata<'_>) -> metrics::Gauge {
+        metrics::Gauge::noop()
+    }
+
+    fn register_histogram(&self, key: &Key, _metadata: &Metadata<'_>) -> Histogram {
+        let mut histograms = self.histograms.lock().unwrap();
+        let histogram = histograms
+            .entry(key.name().to_string())
+            .or_insert_with(|| Arc::new(SampleHistogram::default()))
+            .clone();
+        Histogram::from_arc(histogram)
+    }
+}
+
+impl TestRecorder {
+    fn count(&self, name: &str) -> u64 {
+        self.counters
+            .lock()
+            .unwrap()
+            .get(name)
+            .map(|c| c.0.load(Ordering::Relaxed))
+            .unwrap_or(0)
+    }
+
+    fn samples(&self, name: &str) -> usize {
+        self.histograms
+            .lock()
+            .unwrap()
+            .get(name)
+            .map(|h| h.0.lock().unwrap().len())
+            .unwrap_or(0)
+    }
+}
+
+#[test]
+fn reports_encode_decode_and_error_counters() {
+    let recorder = TestRecorder::default();
+
+    metrics::with_local_recorder(&recorder, || {
+        let encoder = Encoder::with_sentinel(0);
+        let mut hello = Vec::new();
+        encoder.encode_frame_into(b"hello", &mut hello);
+        let mut hi = Vec::new();
+        encoder.encode_frame_into(b"hi", &mut hi);
+
+        let decoder = Decoder::with_sentinel(0);
+        decoder.decode_frame(&hello[..hello.len() - 1]).unwrap();
+
+        // Malformed: the code byte claims two data bytes but only one follows.
+        assert!(decoder.decode_frame(&[3, 5]).is_err());
+    });
+
+    assert_eq!(recorder.count("frames_encoded"), 2);
+    assert_eq!(recorder.count("frames_decoded"), 1);
+    assert_eq!(recorder.count("decode_errors"), 1);
+    assert_eq!(recorder.samples("frame_size"), 3);
+}
@@ -0,0 +1,30 @@
+use cobs_codec::Encoder;
+
+#[test]
+fn omits_the_trailing_sentinel_when_disabled() {
+    let encoder = Encoder::with_sentinel(0).with_delimiter(false);
+    let mut dst = Vec::new();
+    encoder.encode_frame_into(b"hello", &mut dst);
+
+    assert!(!dst.contains(&0));
+
+    let mut expected = Vec::new();
+    cobs_codec::encode(0, b"hello", &mut expected);
+    expected.pop(); // the delimiter byte
+    assert_eq!(dst, expected);
+}
+
+#[test]
+fn caller_can_place_a_single_delimiter_between_batched_frames() {
+    let encoder = Encoder::with_sentinel(0).with_delimiter(false);
+    let mut dst = Vec::new();
+    encoder.encode_frame_into(b"one", &mut dst);
+    dst.push(0);
+    encoder.encode_frame_into(b"two", &mut dst);
+    dst.push(0);
+
+    let mut expected = Vec::new();
+    cobs_codec::encode(0, b"one", &mut expected);
+    cobs_codec::encode(0, b"two", &mut expected);
+    assert_eq!(dst, expected);
+}
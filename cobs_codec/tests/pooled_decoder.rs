@@ -0,0 +1,58 @@
+#![cfg(feature = "tokio")]
+
+use cobs_codec::bytes::BytesMut;
+use cobs_codec::{BufferPool, PooledDecoder};
+use tokio_util::codec::Decoder as _;
+
+#[test]
+fn reuses_a_released_buffer_for_the_next_frame() {
+    let mut decoder = PooledDecoder::with_sentinel(0);
+
+    let mut src = BytesMut::from(&b"\x06hello\x00"[..]);
+    let first = decoder.decode(&mut src).unwrap().unwrap();
+    assert_eq!(&first[..], b"hello");
+    let reused_ptr = first.as_ptr();
+    decoder.release(first);
+    assert_eq!(decoder.pooled_buffers(), 1);
+
+    let mut src = BytesMut::from(&b"\x06world\x00"[..]);
+    let second = decoder.decode(&mut src).unwrap().unwrap();
+    assert_eq!(&second[..], b"world");
+    assert_eq!(second.as_ptr(), reused_ptr);
+    assert_eq!(decoder.pooled_buffers(), 0);
+}
+
+#[test]
+fn decodes_a_frame_spanning_multiple_groups() {
+    let mut decoder = PooledDecoder::with_sentinel(0);
+
+    let mut framed = Vec::new();
+    cobs_codec::encode(0, b"a\0b\0\0c", &mut framed);
+    let mut src = BytesMut::from(&framed[..]);
+    let payload = decoder.decode(&mut src).unwrap().unwrap();
+    assert_eq!(&payload[..], b"a\0b\0\0c");
+}
+
+#[test]
+fn a_malformed_frame_still_returns_its_buffer_to_the_pool() {
+    let mut decoder = PooledDecoder::with_sentinel(0);
+
+    // A lone code byte of 5 promises 4 more data bytes that never arrive.
+    let mut src = BytesMut::from(&b"\x05\x00"[..]);
+    assert!(decoder.decode(&mut src).is_err());
+    assert_eq!(decoder.pooled_buffers(), 1);
+}
+
+#[test]
+fn acquire_returns_a_fresh_buffer_once_the_pool_is_empty() {
+    let mut pool = BufferPool::new();
+    assert!(pool.is_empty());
+
+    let a = pool.acquire();
+    let b = pool.acquire();
+    assert_eq!(pool.len(), 0);
+
+    pool.release(a);
+    pool.release(b);
+    assert_eq!(pool.len(), 2);
+}
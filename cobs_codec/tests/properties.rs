@@ -0,0 +1,77 @@
+use cobs_codec::sans_io::PushDecoder;
+use cobs_codec::{max_encoded_len, Decoder, Encoder};
+use proptest::prelude::*;
+
+/// Split `data` into pieces whose lengths cycle through `sizes` (falling back
+/// to one byte at a time if `sizes` is empty), so a `Decoder` sees the same
+/// frame delivered in arbitrarily different chunk boundaries.
+fn split_into_chunks<'a>(data: &'a [u8], sizes: &[usize]) -> Vec<&'a [u8]> {
+    let mut chunks = Vec::new();
+    let mut rest = data;
+    let mut i = 0;
+    while !rest.is_empty() {
+        let size = sizes.get(i % sizes.len().max(1)).copied().unwrap_or(1).max(1);
+        let take = size.min(rest.len());
+        let (chunk, tail) = rest.split_at(take);
+        chunks.push(chunk);
+        rest = tail;
+        i += 1;
+    }
+    chunks
+}
+
+proptest! {
+    #[test]
+    fn roundtrip_is_identity_for_every_sentinel(payload in proptest::collection::vec(any::<u8>(), 0..256)) {
+        for sentinel in 0u8..=255 {
+            let mut frame = Vec::new();
+            Encoder::with_sentinel(sentinel).encode_frame_into(&payload, &mut frame);
+            frame.pop(); // drop the trailing delimiter
+
+            let decoded = Decoder::with_sentinel(sentinel).decode_frame(&frame).unwrap();
+            prop_assert_eq!(&decoded, &payload);
+        }
+    }
+
+    #[test]
+    fn encoded_content_never_contains_the_sentinel(payload in proptest::collection::vec(any::<u8>(), 0..256)) {
+        for sentinel in 0u8..=255 {
+            let mut frame = Vec::new();
+            Encoder::with_sentinel(sentinel).encode_frame_into(&payload, &mut frame);
+            frame.pop(); // drop the trailing delimiter
+
+            prop_assert!(!frame.contains(&sentinel));
+        }
+    }
+
+    #[test]
+    fn encoded_length_stays_within_the_worst_case_bound(payload in proptest::collection::vec(any::<u8>(), 0..256)) {
+        for sentinel in 0u8..=255 {
+            let mut frame = Vec::new();
+            Encoder::with_sentinel(sentinel).encode_frame_into(&payload, &mut frame);
+            frame.pop(); // drop the trailing delimiter
+
+            prop_assert!(frame.len() <= max_encoded_len(payload.len()));
+        }
+    }
+
+    #[test]
+    fn split_delivery_in_random_chunks_still_decodes(
+        payload in proptest::collection::vec(any::<u8>(), 0..256),
+        chunk_sizes in proptest::collection::vec(1usize..7, 1..20),
+    ) {
+        for sentinel in 0u8..=255 {
+            let mut frame = Vec::new();
+            Encoder::with_sentinel(sentinel).encode_frame_into(&payload, &mut frame);
+
+            let mut decoder = PushDecoder::with_sentinel(sentinel);
+            let mut frames = Vec::new();
+            for chunk in split_into_chunks(&frame, &chunk_sizes) {
+                for result in decoder.push(chunk) {
+                    frames.push(result.unwrap());
+                }
+            }
+            prop_assert_eq!(frames, vec![payload.clone()]);
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use cobs_codec::encode;
+use cobs_codec::reader::CobsReader;
+
+#[test]
+fn reads_frames_one_at_a_time_from_a_captured_stream() {
+    let mut captured = Vec::new();
+    encode(0, b"one", &mut captured);
+    encode(0, b"two", &mut captured);
+
+    let mut reader = CobsReader::new(0, &captured[..]);
+
+    let mut buf = Vec::new();
+    assert!(reader.read_frame(&mut buf).unwrap());
+    assert_eq!(buf, b"one");
+
+    buf.clear();
+    assert!(reader.read_frame(&mut buf).unwrap());
+    assert_eq!(buf, b"two");
+
+    buf.clear();
+    assert!(!reader.read_frame(&mut buf).unwrap());
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn malformed_frame_surfaces_as_invalid_data_error() {
+    let corrupt = [0xFF, 1, 2, 0];
+    let mut reader = CobsReader::new(0, &corrupt[..]);
+    let mut buf = Vec::new();
+    let err = reader.read_frame(&mut buf).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
@@ -0,0 +1,35 @@
+use cobs_codec::reduced::{DecoderR, EncoderR};
+
+fn roundtrip(data: &[u8]) {
+    let encoder = EncoderR::with_sentinel(0);
+    let mut framed = Vec::new();
+    encoder.encode_frame_into(data, &mut framed);
+    framed.truncate(framed.len() - 1); // drop the trailing sentinel
+
+    let decoder = DecoderR::with_sentinel(0);
+    let decoded = decoder.decode_frame(&framed).unwrap();
+    assert_eq!(decoded, data);
+}
+
+#[test]
+fn round_trips_arbitrary_payloads() {
+    roundtrip(b"");
+    roundtrip(b"\0");
+    roundtrip(b"hello, world");
+    roundtrip(&[0, 1, 2, 0, 0, 255, 254]);
+    roundtrip(&[1u8; 300]);
+}
+
+#[test]
+fn saves_a_byte_when_the_final_data_byte_permits_it() {
+    // The last byte (200) is >= the code it would otherwise require, so
+    // COBS/R folds it into the code slot.
+    let data = [1u8, 2, 3, 200];
+    let encoder = EncoderR::with_sentinel(0);
+    let mut framed = Vec::new();
+    encoder.encode_frame_into(&data, &mut framed);
+
+    let mut plain = Vec::new();
+    cobs_codec::encode(0, &data, &mut plain);
+    assert_eq!(framed.len(), plain.len() - 1);
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "tokio")]
+
+use cobs_codec::bytes::BytesMut;
+use cobs_codec::{Encoder, ReportingDecoder};
+use tokio_util::codec;
+
+#[test]
+fn yields_good_frames_as_ok() {
+    let encoder = Encoder::with_sentinel(0);
+    let mut dst = BytesMut::new();
+    encoder.encode_frame(b"hello", &mut dst);
+
+    let mut decoder = ReportingDecoder::with_sentinel(0);
+    assert_eq!(
+        codec::Decoder::decode(&mut decoder, &mut dst).unwrap().unwrap().unwrap(),
+        b"hello".as_ref()
+    );
+}
+
+#[test]
+fn reports_a_malformed_frame_without_ending_the_stream() {
+    let mut dst = BytesMut::new();
+    dst.extend_from_slice(&[2, 0]); // code byte claims a data byte that never arrives
+    let encoder = Encoder::with_sentinel(0);
+    encoder.encode_frame(b"two", &mut dst);
+
+    let mut decoder = ReportingDecoder::with_sentinel(0);
+    assert!(codec::Decoder::decode(&mut decoder, &mut dst).unwrap().unwrap().is_err());
+    assert_eq!(
+        codec::Decoder::decode(&mut decoder, &mut dst).unwrap().unwrap().unwrap(),
+        b"two".as_ref()
+    );
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "tokio")]
+
+use cobs_codec::bytes::BytesMut;
+use cobs_codec::{Decoder, Encoder};
+use tokio_util::codec::Decoder as _;
+
+#[test]
+fn resync_skips_a_corrupt_frame_and_keeps_decoding() {
+    let mut dst = BytesMut::new();
+    // A corrupt frame: a code byte that jumps past the delimiter.
+    dst.extend_from_slice(&[0xFF, 1, 2, 0]);
+    Encoder::with_sentinel(0).encode_frame(b"ok", &mut dst);
+
+    let mut decoder = Decoder::with_sentinel(0).with_resync(true);
+    let frame = decoder.decode(&mut dst).unwrap().expect("resynced frame");
+    assert_eq!(&frame[..], b"ok");
+    assert_eq!(decoder.discarded_bytes(), 4);
+}
+
+#[test]
+fn without_resync_the_corrupt_frame_is_an_error() {
+    let mut dst = BytesMut::new();
+    dst.extend_from_slice(&[0xFF, 1, 2, 0]);
+    Encoder::with_sentinel(0).encode_frame(b"ok", &mut dst);
+
+    let mut decoder = Decoder::with_sentinel(0);
+    assert!(decoder.decode(&mut dst).is_err());
+    // The corrupt frame's bytes were dropped from `src` just like with resync
+    // on, so `discarded_bytes` reflects that even though no resync happened...
+    assert_eq!(decoder.discarded_bytes(), 4);
+    // ...and the stream has already self-healed: the next call decodes the
+    // following well-formed frame without needing any recovery from the caller.
+    let frame = decoder.decode(&mut dst).unwrap().expect("frame after the error");
+    assert_eq!(&frame[..], b"ok");
+}
+
+#[test]
+fn skip_to_next_frame_drops_up_to_and_including_the_sentinel() {
+    let mut dst = BytesMut::new();
+    dst.extend_from_slice(&[9, 9, 9, 0]);
+    Encoder::with_sentinel(0).encode_frame(b"ok", &mut dst);
+
+    let mut decoder = Decoder::with_sentinel(0);
+    assert_eq!(decoder.skip_to_next_frame(&mut dst), 4);
+    assert_eq!(decoder.discarded_bytes(), 4);
+
+    let frame = decoder.decode(&mut dst).unwrap().expect("frame after the skip");
+    assert_eq!(&frame[..], b"ok");
+}
+
+#[test]
+fn skip_to_next_frame_with_no_sentinel_yet_drops_everything_seen_so_far() {
+    let mut dst = BytesMut::new();
+    dst.extend_from_slice(&[9, 9, 9]);
+
+    let mut decoder = Decoder::with_sentinel(0);
+    assert_eq!(decoder.skip_to_next_frame(&mut dst), 3);
+    assert!(dst.is_empty());
+}
@@ -0,0 +1,40 @@
+use cobs_codec::{CobsError, Decoder, Encoder};
+
+#[test]
+fn well_formed_frame_salvages_the_whole_payload_with_no_error() {
+    let mut framed = Vec::new();
+    Encoder::with_sentinel(0).encode_frame_into(b"hello", &mut framed);
+    let frame = &framed[..framed.len() - 1];
+
+    let salvage = Decoder::with_sentinel(0).decode_frame_lossy(frame);
+    assert_eq!(salvage.payload, b"hello");
+    assert!(salvage.error.is_none());
+}
+
+#[test]
+fn truncated_group_salvages_the_bytes_that_arrived() {
+    let mut framed = Vec::new();
+    Encoder::with_sentinel(0).encode_frame_into(b"hello world", &mut framed);
+    let frame = &framed[..framed.len() - 1];
+    // Drop the last two data bytes, leaving the group's length prefix
+    // promising more bytes than are actually present.
+    let truncated = &frame[..frame.len() - 2];
+
+    let salvage = Decoder::with_sentinel(0).decode_frame_lossy(truncated);
+    assert_eq!(salvage.payload, b"hello wor");
+    assert!(matches!(salvage.error, Some(CobsError::UnexpectedSentinel { .. })));
+}
+
+#[test]
+fn invalid_code_byte_salvages_the_groups_decoded_before_it() {
+    let mut framed = Vec::new();
+    Encoder::with_sentinel(0).encode_frame_into(b"ab", &mut framed);
+    Encoder::with_sentinel(0).encode_frame_into(b"cd", &mut framed);
+    // Splice a zero code byte in between the two groups' encodings.
+    let mut frame = framed[..framed.len() - 1].to_vec();
+    frame.insert(3, 0);
+
+    let salvage = Decoder::with_sentinel(0).decode_frame_lossy(&frame);
+    assert_eq!(salvage.payload, b"ab\0");
+    assert!(matches!(salvage.error, Some(CobsError::InvalidCodeByte { .. })));
+}
@@ -0,0 +1,33 @@
+use cobs_codec::sans_io::PushDecoder;
+use cobs_codec::encode;
+
+#[test]
+fn feeds_one_byte_at_a_time() {
+    let payload = b"hi";
+    let mut framed = Vec::new();
+    encode(0, payload, &mut framed);
+
+    let mut decoder = PushDecoder::with_sentinel(0);
+    let mut frames = Vec::new();
+    for &b in &framed {
+        if let Some(frame) = decoder.feed(b) {
+            frames.push(frame.unwrap());
+        }
+    }
+    assert_eq!(frames, vec![payload.to_vec()]);
+    assert_eq!(decoder.pending_len(), 0);
+}
+
+#[test]
+fn push_collects_every_frame_in_a_chunk() {
+    let mut framed = Vec::new();
+    encode(0, b"one", &mut framed);
+    encode(0, b"two", &mut framed);
+
+    let mut decoder = PushDecoder::with_sentinel(0);
+    let frames = decoder.push(&framed);
+    assert_eq!(
+        frames.into_iter().map(Result::unwrap).collect::<Vec<_>>(),
+        vec![b"one".to_vec(), b"two".to_vec()]
+    );
+}
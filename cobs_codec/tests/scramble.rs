@@ -0,0 +1,46 @@
+use cobs_codec::scramble::{ScrambledCobsCodec, XorWhitening};
+
+#[test]
+fn xor_whitening_round_trips() {
+    let mut codec = ScrambledCobsCodec::new(0, XorWhitening::new(&[0xA5, 0x3C, 0xFF]));
+    let mut framed = Vec::new();
+    codec.encode_frame(b"hello", &mut framed);
+
+    let decoded = codec.decode_frame(&framed[..framed.len() - 1]).unwrap();
+    assert_eq!(decoded, b"hello");
+}
+
+#[test]
+fn the_delimiter_is_left_untouched_by_the_transform() {
+    let mut codec = ScrambledCobsCodec::new(0, XorWhitening::new(&[0xFF]));
+    let mut framed = Vec::new();
+    codec.encode_frame(b"hello", &mut framed);
+
+    assert_eq!(*framed.last().unwrap(), 0);
+}
+
+#[test]
+fn a_user_closure_can_be_used_as_the_transform() {
+    let mut codec = ScrambledCobsCodec::new(0, |buf: &mut [u8]| {
+        for b in buf {
+            *b ^= 0x42;
+        }
+    });
+    let mut framed = Vec::new();
+    codec.encode_frame(b"hello world", &mut framed);
+
+    let decoded = codec.decode_frame(&framed[..framed.len() - 1]).unwrap();
+    assert_eq!(decoded, b"hello world");
+}
+
+#[test]
+fn an_empty_keystream_leaves_bytes_unchanged() {
+    let mut plain = Vec::new();
+    cobs_codec::encode(0, b"hello", &mut plain);
+
+    let mut codec = ScrambledCobsCodec::new(0, XorWhitening::new(&[]));
+    let mut scrambled = Vec::new();
+    codec.encode_frame(b"hello", &mut scrambled);
+
+    assert_eq!(scrambled, plain);
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "serde")]
+
+use cobs_codec::Decoder;
+#[cfg(feature = "tokio")]
+use cobs_codec::{bytes::BytesMut, Encoder};
+#[cfg(feature = "tokio")]
+use tokio_util::codec;
+
+#[test]
+fn round_trips_decoder_state_through_json() {
+    let decoder = Decoder::with_sentinel(0xAA).with_strict(true).with_max_block(64);
+
+    let json = serde_json::to_string(&decoder).unwrap();
+    let restored: Decoder = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(decoder, restored);
+}
+
+#[test]
+#[cfg(feature = "tokio")]
+fn checkpoint_preserves_options_and_counters_across_restart() {
+    let mut decoder = Decoder::with_sentinel(0)
+        .with_resync(true)
+        .with_max_frame_len(64)
+        .with_stats(true);
+    let mut dst = BytesMut::new();
+    Encoder::with_sentinel(0).encode_frame(b"hello", &mut dst);
+    codec::Decoder::decode(&mut decoder, &mut dst).unwrap().unwrap();
+
+    let json = serde_json::to_string(&decoder).unwrap();
+    let restored: Decoder = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(decoder, restored);
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "serialport")]
+
+use std::io::{self, Read};
+
+use cobs_codec::encode;
+use cobs_codec::serialport::{recv_frame, send_frame};
+use cobs_codec::CobsError;
+
+#[test]
+fn sends_a_frame_matching_plain_encode() {
+    let mut framed = Vec::new();
+    encode(0, b"hello", &mut framed);
+
+    let mut dst = Vec::new();
+    send_frame(0, &mut dst, b"hello").unwrap();
+    assert_eq!(dst, framed);
+}
+
+#[test]
+fn receives_a_frame_from_bytes_already_on_hand() {
+    let mut framed = Vec::new();
+    encode(0, b"hello", &mut framed);
+
+    let frame = recv_frame(0, &mut &framed[..]).unwrap();
+    assert_eq!(frame, b"hello");
+}
+
+/// A reader that yields a fixed prefix and then times out forever, standing
+/// in for a serial port whose peer stopped sending mid-frame.
+struct StallsAfter<'a> {
+    remaining: &'a [u8],
+}
+
+impl Read for StallsAfter<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.remaining.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::TimedOut, "no data"));
+        }
+        let n = self.remaining.read(buf)?;
+        Ok(n)
+    }
+}
+
+#[test]
+fn reports_stalled_instead_of_a_raw_timeout_error() {
+    let mut reader = StallsAfter {
+        remaining: &[5, b'h', b'i'], // no delimiter follows
+    };
+    let err = recv_frame(0, &mut reader).unwrap_err();
+    assert!(matches!(err, CobsError::Stalled { buffered: 3 }));
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "tokio")]
+
+use cobs_codec::bytes::BytesMut;
+use cobs_codec::{Decoder, Encoder};
+use tokio_util::codec::Decoder as _;
+
+#[test]
+fn buffer_shrinks_once_drained_past_the_threshold() {
+    let mut dst = BytesMut::new();
+    Encoder::with_sentinel(0).encode_frame(&[1u8; 4096], &mut dst);
+    Encoder::with_sentinel(0).encode_frame(b"ok", &mut dst);
+
+    let mut decoder = Decoder::with_sentinel(0).with_shrink_after(1024);
+
+    let big = decoder.decode(&mut dst).unwrap().expect("big frame");
+    assert_eq!(big.len(), 4096);
+    assert!(dst.capacity() > 1024);
+
+    let small = decoder.decode(&mut dst).unwrap().expect("small frame");
+    assert_eq!(&small[..], b"ok");
+
+    // `dst` is now empty; the next scan for a delimiter finds none and trips
+    // the shrink.
+    assert!(decoder.decode(&mut dst).unwrap().is_none());
+    assert!(dst.capacity() <= 1024);
+}
+
+#[test]
+fn buffer_is_left_alone_below_the_threshold() {
+    let mut dst = BytesMut::new();
+    Encoder::with_sentinel(0).encode_frame(b"ok", &mut dst);
+    let capacity_before = dst.capacity();
+
+    let mut decoder = Decoder::with_sentinel(0).with_shrink_after(1024);
+    let frame = decoder.decode(&mut dst).unwrap().expect("frame");
+    assert_eq!(&frame[..], b"ok");
+
+    assert!(decoder.decode(&mut dst).unwrap().is_none());
+    // Below the threshold, `dst`'s allocation is left as `BytesMut` would
+    // otherwise leave it - the shrink never kicks in.
+    assert!(dst.capacity() <= capacity_before);
+}
+
+#[test]
+fn without_shrink_after_the_buffer_keeps_its_capacity() {
+    let mut dst = BytesMut::new();
+    Encoder::with_sentinel(0).encode_frame(&[1u8; 4096], &mut dst);
+    Encoder::with_sentinel(0).encode_frame(b"ok", &mut dst);
+
+    let mut decoder = Decoder::with_sentinel(0);
+    decoder.decode(&mut dst).unwrap().expect("big frame");
+    decoder.decode(&mut dst).unwrap().expect("small frame");
+    assert!(decoder.decode(&mut dst).unwrap().is_none());
+    assert!(dst.capacity() > 1024);
+}
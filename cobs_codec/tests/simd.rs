@@ -0,0 +1,28 @@
+#![cfg(feature = "simd")]
+
+use cobs_codec::{decode, encode};
+
+#[test]
+fn round_trips_runs_spanning_multiple_254_byte_groups() {
+    let mut payload = Vec::new();
+    payload.extend(std::iter::repeat_n(1u8, 600));
+    payload.push(0);
+    payload.extend(std::iter::repeat_n(2u8, 254));
+    payload.push(0);
+    payload.push(0);
+    payload.extend(1..=10u8);
+
+    let mut framed = Vec::new();
+    encode(0, &payload, &mut framed);
+    let decoded = decode(0, &framed[..framed.len() - 1]).unwrap();
+    assert_eq!(decoded, payload);
+}
+
+#[test]
+fn round_trips_sentinel_free_payload() {
+    let payload: Vec<u8> = (1..=255u8).collect();
+    let mut framed = Vec::new();
+    encode(0, &payload, &mut framed);
+    let decoded = decode(0, &framed[..framed.len() - 1]).unwrap();
+    assert_eq!(decoded, payload);
+}
@@ -0,0 +1,15 @@
+use cobs_codec::{decode, encode};
+
+#[test]
+fn round_trips_without_encoder_or_decoder() {
+    let payload = [0u8, 1, 2, 0, 0, 255, 254];
+    let mut framed = Vec::new();
+    encode(0, &payload, &mut framed);
+
+    // Terminated by the sentinel, with none appearing mid-frame.
+    assert_eq!(*framed.last().unwrap(), 0);
+    assert!(!framed[..framed.len() - 1].contains(&0));
+
+    let decoded = decode(0, &framed[..framed.len() - 1]).unwrap();
+    assert_eq!(decoded, payload);
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "tokio")]
+
+use cobs_codec::bytes::BytesMut;
+use cobs_codec::{Decoder, Encoder};
+use tokio_util::codec;
+
+#[test]
+fn is_none_until_enabled() {
+    let encoder = Encoder::with_sentinel(0);
+    let decoder = Decoder::with_sentinel(0);
+    assert!(encoder.stats().is_none());
+    assert!(decoder.stats().is_none());
+}
+
+#[test]
+fn counts_encoded_frames() {
+    let mut encoder = Encoder::with_sentinel(0).with_stats(true);
+    let mut dst = BytesMut::new();
+    codec::Encoder::encode(&mut encoder, b"hello".as_ref(), &mut dst).unwrap();
+    codec::Encoder::encode(&mut encoder, b"hi".as_ref(), &mut dst).unwrap();
+
+    let stats = encoder.stats().unwrap();
+    assert_eq!(stats.frames, 2);
+    assert_eq!(stats.payload_bytes, 5 + 2);
+    assert_eq!(stats.stuffed_bytes, dst.len());
+}
+
+#[test]
+fn counts_decoded_and_malformed_frames() {
+    let mut dst = BytesMut::new();
+    Encoder::with_sentinel(0).encode_frame(b"one", &mut dst);
+    dst.extend_from_slice(&[2, 0]); // malformed: code byte claims a missing data byte
+    Encoder::with_sentinel(0).encode_frame(b"two", &mut dst);
+
+    let mut decoder = Decoder::with_sentinel(0).with_resync(true).with_stats(true);
+
+    assert_eq!(codec::Decoder::decode(&mut decoder, &mut dst).unwrap().unwrap(), b"one".as_ref());
+    assert_eq!(codec::Decoder::decode(&mut decoder, &mut dst).unwrap().unwrap(), b"two".as_ref());
+
+    let stats = decoder.stats().unwrap();
+    assert_eq!(stats.frames, 2);
+    assert_eq!(stats.payload_bytes, 3 + 3);
+    assert_eq!(stats.malformed_frames, 1);
+    assert_eq!(stats.resync_events, 1);
+}
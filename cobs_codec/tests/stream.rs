@@ -0,0 +1,21 @@
+use cobs_codec::stream::StreamEncoder;
+use cobs_codec::{decode, encode};
+
+#[test]
+fn chunked_writes_match_a_single_encode_call() {
+    let payload = [0u8, 1, 2, 0, 0, 255, 254];
+
+    let mut encoder = StreamEncoder::with_sentinel(0);
+    let mut framed = Vec::new();
+    encoder.start_frame();
+    encoder.write(&payload[..2], &mut framed);
+    encoder.write(&payload[2..], &mut framed);
+    encoder.finish(&mut framed);
+
+    let mut expected = Vec::new();
+    encode(0, &payload, &mut expected);
+    assert_eq!(framed, expected);
+
+    let decoded = decode(0, &framed[..framed.len() - 1]).unwrap();
+    assert_eq!(decoded, payload);
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "stream-ext")]
+
+use cobs_codec::bytes::BytesMut;
+use cobs_codec::encode;
+use cobs_codec::stream_ext::{CobsStreamExt, CobsTransportExt};
+use futures_executor::block_on;
+use futures_util::{stream, SinkExt, StreamExt};
+
+#[test]
+#[cfg(feature = "tokio-io")]
+fn cobs_framed_round_trips_over_a_duplex_stream() {
+    let (a, b) = tokio::io::duplex(64);
+    block_on(async {
+        let mut a = a.cobs_framed::<0>();
+        let mut b = b.cobs_framed::<0>();
+
+        a.send(b"hello".to_vec()).await.unwrap();
+        assert_eq!(&b.next().await.unwrap().unwrap()[..], b"hello");
+    });
+}
+
+#[test]
+fn cobs_decode_destuffs_frames_out_of_arbitrarily_chopped_chunks() {
+    let mut captured = Vec::new();
+    encode(0, b"one", &mut captured);
+    encode(0, b"two", &mut captured);
+
+    // Split mid-frame, unrelated to either frame's boundary, to exercise the
+    // buffering across `Stream` items.
+    let split_at = captured.len() / 2;
+    let chunks = vec![
+        BytesMut::from(&captured[..split_at]),
+        BytesMut::from(&captured[split_at..]),
+    ];
+
+    let decoded: Vec<_> = block_on(stream::iter(chunks).cobs_decode(0).collect());
+    let decoded: Result<Vec<_>, _> = decoded.into_iter().collect();
+    let decoded = decoded.unwrap();
+
+    assert_eq!(decoded, vec![BytesMut::from(&b"one"[..]), BytesMut::from(&b"two"[..])]);
+}
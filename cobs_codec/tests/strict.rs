@@ -0,0 +1,35 @@
+use cobs_codec::{CobsError, Decoder};
+
+#[test]
+fn accepts_canonical_frames() {
+    let mut framed = Vec::new();
+    cobs_codec::encode(0, b"hello", &mut framed);
+    let frame = &framed[..framed.len() - 1];
+
+    let decoder = Decoder::with_sentinel(0).with_strict(true);
+    assert_eq!(decoder.decode_frame(frame).unwrap(), b"hello");
+}
+
+#[test]
+fn rejects_a_frame_missing_the_trailing_empty_group_at_the_254_boundary() {
+    // Canonical encoding of 254 sentinel-free bytes is [0xFF, <254 bytes>, 0x01]:
+    // the trailing empty group is what `Encoder` actually emits, but omitting
+    // it still destuffs to the same payload.
+    let payload = [1u8; 254];
+    let mut framed = Vec::new();
+    cobs_codec::encode(0, &payload, &mut framed);
+    let canonical_frame = &framed[..framed.len() - 1];
+    assert_eq!(canonical_frame.last(), Some(&1u8)); // the trailing empty group's code byte
+
+    let lenient = Decoder::with_sentinel(0);
+    let short_frame = &canonical_frame[..canonical_frame.len() - 1];
+    assert_eq!(lenient.decode_frame(short_frame).unwrap(), payload);
+    assert_eq!(lenient.decode_frame(canonical_frame).unwrap(), payload);
+
+    let strict = Decoder::with_sentinel(0).with_strict(true);
+    assert!(matches!(
+        strict.decode_frame(short_frame).unwrap_err(),
+        CobsError::NonCanonicalEncoding { .. }
+    ));
+    assert_eq!(strict.decode_frame(canonical_frame).unwrap(), payload);
+}
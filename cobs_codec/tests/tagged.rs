@@ -0,0 +1,41 @@
+use cobs_codec::tagged::{TaggedDecoder, TaggedEncoder};
+use cobs_codec::CobsError;
+
+fn frames(dst: &[u8], sentinel: u8) -> Vec<Vec<u8>> {
+    dst.split_inclusive(|&b| b == sentinel)
+        .map(|frame| frame[..frame.len() - 1].to_vec())
+        .collect()
+}
+
+#[test]
+fn demultiplexes_frames_by_their_tag() {
+    let encoder = TaggedEncoder::new(0);
+    let mut dst = Vec::new();
+    encoder.encode_frame_into(1, b"ping", &mut dst);
+    encoder.encode_frame_into(2, b"pong", &mut dst);
+
+    let decoder = TaggedDecoder::new(0);
+    let encoded = frames(&dst, 0);
+    assert_eq!(decoder.decode_frame(&encoded[0]).unwrap(), (1, b"ping".to_vec()));
+    assert_eq!(decoder.decode_frame(&encoded[1]).unwrap(), (2, b"pong".to_vec()));
+}
+
+#[test]
+fn allows_an_empty_payload_after_the_tag() {
+    let encoder = TaggedEncoder::new(0);
+    let mut dst = Vec::new();
+    encoder.encode_frame_into(7, b"", &mut dst);
+
+    let decoder = TaggedDecoder::new(0);
+    let frame = &dst[..dst.len() - 1];
+    assert_eq!(decoder.decode_frame(frame).unwrap(), (7, Vec::new()));
+}
+
+#[test]
+fn rejects_a_frame_with_no_tag_byte() {
+    let decoder = TaggedDecoder::new(0);
+    match decoder.decode_frame(&[]) {
+        Err(CobsError::TruncatedFrame { .. }) => {}
+        other => panic!("expected a truncated-frame error, got {other:?}"),
+    }
+}
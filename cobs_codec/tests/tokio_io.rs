@@ -0,0 +1,41 @@
+#![cfg(feature = "tokio-io")]
+
+use cobs_codec::encode;
+use cobs_codec::tokio_io::{read_frame, write_frame};
+use futures_executor::block_on;
+
+#[test]
+fn writes_a_single_terminated_frame() {
+    let mut dst = Vec::new();
+    block_on(async {
+        write_frame(0, &mut dst, b"hello").await.unwrap();
+    });
+
+    let mut expected = Vec::new();
+    encode(0, b"hello", &mut expected);
+    assert_eq!(dst, expected);
+}
+
+#[test]
+fn reads_one_frame_and_stops_at_its_delimiter() {
+    let mut captured = Vec::new();
+    encode(0, b"one", &mut captured);
+    encode(0, b"two", &mut captured);
+
+    block_on(async {
+        let mut reader = &captured[..];
+        assert_eq!(read_frame(0, &mut reader).await.unwrap(), b"one");
+        assert_eq!(read_frame(0, &mut reader).await.unwrap(), b"two");
+    });
+}
+
+#[test]
+fn round_trips_a_request_and_response_over_a_shared_buffer() {
+    block_on(async {
+        let mut channel = Vec::new();
+        write_frame(0xAA, &mut channel, b"ping").await.unwrap();
+
+        let mut reader = &channel[..];
+        assert_eq!(read_frame(0xAA, &mut reader).await.unwrap(), b"ping");
+    });
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "tokio-time")]
+
+use std::time::Duration;
+
+use cobs_codec::encode;
+use cobs_codec::tokio_io::read_frame_with_timeout;
+use cobs_codec::CobsError;
+use tokio::io::{duplex, AsyncWriteExt};
+
+#[tokio::test]
+async fn reads_a_frame_that_arrives_in_time() {
+    let mut framed = Vec::new();
+    encode(0, b"hello", &mut framed);
+
+    let (mut writer, mut reader) = duplex(64);
+    writer.write_all(&framed).await.unwrap();
+
+    let frame = read_frame_with_timeout(0, &mut reader, Duration::from_millis(50))
+        .await
+        .unwrap();
+    assert_eq!(frame, b"hello");
+}
+
+#[tokio::test(start_paused = true)]
+async fn discards_a_partial_frame_that_stalls() {
+    let (mut writer, mut reader) = duplex(64);
+    writer.write_all(&[5, b'h', b'i']).await.unwrap(); // no delimiter follows
+
+    let err = read_frame_with_timeout(0, &mut reader, Duration::from_millis(50))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CobsError::Stalled { buffered: 3 }));
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "tracing")]
+
+use cobs_codec::{Decoder, Encoder};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span;
+use tracing::{Event, Metadata};
+
+#[derive(Default)]
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.0 = format!("{value:?}");
+        }
+    }
+}
+
+struct Capture(Arc<Mutex<Vec<String>>>);
+
+impl tracing::Subscriber for Capture {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _span: &span::Attributes<'_>) -> span::Id {
+        span::Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        self.0.lock().unwrap().push(visitor.0);
+    }
+
+    fn enter(&self, _span: &span::Id) {}
+    fn exit(&self, _span: &span::Id) {}
+}
+
+#[test]
+fn emits_events_for_encode_decode_resync_and_errors() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let subscriber = Capture(events.clone());
+
+    tracing::subscriber::with_default(subscriber, || {
+        let encoder = Encoder::with_sentinel(0);
+        let mut dst = Vec::new();
+        encoder.encode_frame_into(b"hello", &mut dst);
+
+        let decoder = Decoder::with_sentinel(0);
+        let frame = &dst[..dst.len() - 1];
+        decoder.decode_frame(frame).unwrap();
+
+        // Malformed: the code byte claims two data bytes but only one follows.
+        assert!(decoder.decode_frame(&[3, 5]).is_err());
+    });
+
+    let events = events.lock().unwrap();
+    assert!(events.iter().any(|m| m.contains("encoded cobs frame")));
+    assert!(events.iter().any(|m| m.contains("decoded cobs frame")));
+    assert!(events.iter().any(|m| m.contains("cobs frame decode failed")));
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "postcard")]
+
+use cobs_codec::bytes::BytesMut;
+use cobs_codec::typed::{TypedCobsCodec, TypedCobsError};
+use serde::{Deserialize, Serialize};
+use tokio_util::codec::{Decoder, Encoder};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Msg {
+    id: u32,
+    label: String,
+}
+
+#[test]
+fn round_trips_typed_frames() {
+    let mut dst = BytesMut::new();
+    let mut codec = TypedCobsCodec::<Msg>::with_sentinel(0);
+
+    codec
+        .encode(
+            Msg {
+                id: 1,
+                label: "one".to_string(),
+            },
+            &mut dst,
+        )
+        .unwrap();
+    codec
+        .encode(
+            Msg {
+                id: 2,
+                label: "two".to_string(),
+            },
+            &mut dst,
+        )
+        .unwrap();
+
+    assert_eq!(
+        codec.decode(&mut dst).unwrap().unwrap(),
+        Msg {
+            id: 1,
+            label: "one".to_string()
+        }
+    );
+    assert_eq!(
+        codec.decode(&mut dst).unwrap().unwrap(),
+        Msg {
+            id: 2,
+            label: "two".to_string()
+        }
+    );
+    assert!(codec.decode(&mut dst).unwrap().is_none());
+}
+
+#[test]
+fn malformed_postcard_payload_is_reported() {
+    // A lone 0xFF byte is a validly-framed COBS payload, but not a valid
+    // postcard encoding of `Msg` (its varint `id` promises more bytes than
+    // follow).
+    let mut framed = Vec::new();
+    cobs_codec::encode(0, &[0xFF], &mut framed);
+    let mut dst = BytesMut::from(&framed[..]);
+
+    let mut codec = TypedCobsCodec::<Msg>::with_sentinel(0);
+    let err = codec.decode(&mut dst).unwrap_err();
+    assert!(matches!(err, TypedCobsError::Postcard(_)));
+}
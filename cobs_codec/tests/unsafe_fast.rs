@@ -0,0 +1,30 @@
+#![cfg(feature = "unsafe-fast")]
+
+use cobs_codec::{decode, encode, CobsError};
+
+#[test]
+fn round_trips_payloads_spanning_multiple_254_byte_groups() {
+    let mut payload = Vec::new();
+    payload.extend(std::iter::repeat_n(1u8, 600));
+    payload.push(0);
+    payload.extend(std::iter::repeat_n(2u8, 254));
+    payload.push(0);
+    payload.push(0);
+    payload.extend(1..=10u8);
+
+    let mut framed = Vec::new();
+    encode(0, &payload, &mut framed);
+    let decoded = decode(0, &framed[..framed.len() - 1]).unwrap();
+    assert_eq!(decoded, payload);
+}
+
+#[test]
+fn still_rejects_a_code_byte_claiming_more_bytes_than_the_frame_has() {
+    // Code byte `5` promises a 4-byte group, but only 2 bytes follow.
+    let data = [5u8, 1, 2];
+    assert!(matches!(
+        decode(0, &data),
+        Err(CobsError::UnexpectedSentinel { offset: 3 })
+    ));
+}
+
@@ -0,0 +1,25 @@
+use cobs_codec::{encode, CobsError, Decoder};
+
+#[test]
+fn validates_a_well_formed_frame_without_touching_it() {
+    let payload = [0u8, 1, 2, 0, 0, 255, 254, 0];
+    let mut framed = Vec::new();
+    encode(0, &payload, &mut framed);
+    framed.truncate(framed.len() - 1); // drop the trailing sentinel
+    let original = framed.clone();
+
+    let decoder = Decoder::with_sentinel(0);
+    let len = decoder.validate(&framed).unwrap();
+
+    assert_eq!(len, payload.len());
+    assert_eq!(framed, original);
+}
+
+#[test]
+fn rejects_a_frame_claiming_more_bytes_than_it_has() {
+    let decoder = Decoder::with_sentinel(0);
+    assert!(matches!(
+        decoder.validate(&[5, 1, 2]),
+        Err(CobsError::UnexpectedSentinel { offset: 3 })
+    ));
+}
@@ -0,0 +1,27 @@
+use cobs_codec::{decode, decode_vec, encode, encode_vec};
+
+#[test]
+fn encode_vec_matches_the_allocating_out_param_encoder() {
+    for data in [&b""[..], &b"\0"[..], &b"hello, world"[..], &[1u8; 300][..]] {
+        let mut framed = Vec::new();
+        encode(0, data, &mut framed);
+        assert_eq!(encode_vec(0, data), framed);
+    }
+}
+
+#[test]
+fn decode_vec_is_an_alias_for_decode() {
+    let payload = b"hello, world";
+    let framed = encode_vec(0, payload);
+    let frame = &framed[..framed.len() - 1];
+    assert_eq!(decode_vec(0, frame).unwrap(), decode(0, frame).unwrap());
+    assert_eq!(decode_vec(0, frame).unwrap(), payload);
+}
+
+#[test]
+fn round_trips_through_encode_vec_and_decode_vec() {
+    let payload = [0u8, 1, 2, 0, 0, 255, 254];
+    let framed = encode_vec(0xAA, &payload);
+    let frame = &framed[..framed.len() - 1];
+    assert_eq!(decode_vec(0xAA, frame).unwrap(), payload);
+}
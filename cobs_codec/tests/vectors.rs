@@ -0,0 +1,40 @@
+#![cfg(feature = "vectors")]
+
+use cobs_codec::vectors::{self, Vector};
+
+const CORPUS_JSON: &str = include_str!("../../interop/vectors.json");
+
+#[test]
+fn checked_in_corpus_parses() {
+    let vectors = vectors::from_json(CORPUS_JSON).unwrap();
+    assert!(!vectors.is_empty());
+}
+
+#[test]
+fn checked_in_corpus_verifies() {
+    for vector in vectors::from_json(CORPUS_JSON).unwrap() {
+        vector.verify().unwrap_or_else(|e| {
+            panic!("vector {vector:?} failed to verify: {e}");
+        });
+    }
+}
+
+#[test]
+fn default_corpus_matches_the_checked_in_corpus() {
+    let checked_in = vectors::from_json(CORPUS_JSON).unwrap();
+    assert_eq!(checked_in, vectors::default_corpus());
+}
+
+#[test]
+fn verify_catches_a_tampered_vector() {
+    let mut vector = vectors::default_corpus().remove(2);
+    vector.payload_hex = "ff".into();
+    assert!(vector.verify().is_err());
+
+    let bad = Vector {
+        sentinel: 0,
+        payload_hex: "zz".into(),
+        encoded_hex: "0100".into(),
+    };
+    assert!(bad.verify().is_err());
+}
@@ -0,0 +1,25 @@
+#![cfg(feature = "wasm")]
+
+// `to_js_err`'s `JsValue::from_str` aborts on a non-wasm32 host (wasm-bindgen
+// only implements it for the real wasm32 ABI), so the error path can't be
+// exercised here — only under `wasm-bindgen-test` on an actual wasm32 target.
+// The happy paths below don't touch it.
+
+use cobs_codec::wasm::{decode_frame, encode_frame, StreamDecoder};
+
+#[test]
+fn round_trips_a_payload_through_the_one_shot_functions() {
+    let frame = encode_frame(0, b"hello world");
+    // The trailing delimiter isn't part of the frame content.
+    let decoded = decode_frame(0, &frame[..frame.len() - 1]).unwrap();
+    assert_eq!(decoded, b"hello world");
+}
+
+#[test]
+fn stream_decoder_yields_a_frame_once_the_sentinel_arrives() {
+    let mut decoder = StreamDecoder::new(0);
+    for &b in &[6u8, b'h', b'e', b'l', b'l', b'o'] {
+        assert_eq!(decoder.feed(b).unwrap(), None);
+    }
+    assert_eq!(decoder.feed(0).unwrap(), Some(b"hello".to_vec()));
+}
@@ -0,0 +1,42 @@
+use cobs_codec::word::{decode16, encode16, Decoder16, Encoder16};
+
+fn round_trips(sentinel: u16, payload: &[u16]) {
+    let mut dst = Vec::new();
+    encode16(sentinel, payload, &mut dst);
+    let frame = &dst[..dst.len() - 1];
+    assert_eq!(decode16(sentinel, frame).unwrap(), payload);
+}
+
+#[test]
+fn round_trips_an_empty_payload() {
+    round_trips(0, &[]);
+}
+
+#[test]
+fn round_trips_a_payload_with_embedded_zero_words() {
+    round_trips(0, &[1, 2, 0, 3, 0, 0, 4]);
+}
+
+#[test]
+fn round_trips_a_payload_spanning_the_group_boundary() {
+    let payload: Vec<u16> = (0..70_000u32).map(|i| (i % 0xFFFE + 1) as u16).collect();
+    round_trips(0, &payload);
+}
+
+#[test]
+fn matches_the_non_zero_sentinel_encoding() {
+    let payload = [0x1234, 0, 0x5678];
+    let mut dst = Vec::new();
+    Encoder16::with_sentinel(0xAAAA).encode_frame_into(&payload, &mut dst);
+    let frame = &dst[..dst.len() - 1];
+    assert_eq!(Decoder16::with_sentinel(0xAAAA).decode_frame(frame).unwrap(), payload);
+}
+
+#[test]
+fn never_emits_the_sentinel_word_mid_frame() {
+    let payload: Vec<u16> = (0..1000u32).map(|i| i as u16).collect();
+    let mut dst = Vec::new();
+    encode16(0x4242, &payload, &mut dst);
+    assert!(!dst[..dst.len() - 1].contains(&0x4242));
+    assert_eq!(*dst.last().unwrap(), 0x4242);
+}
@@ -0,0 +1,44 @@
+use std::io::Write;
+
+use cobs_codec::decode;
+use cobs_codec::writer::{encode_frame, CobsWriter};
+
+#[test]
+fn chunked_writes_produce_one_terminated_frame() {
+    let mut dst = Vec::new();
+    let mut writer = CobsWriter::new(0, &mut dst);
+    writer.write_all(&[1, 2, 0]).unwrap();
+    writer.write_all(&[0, 3]).unwrap();
+    writer.end_frame().unwrap();
+
+    assert_eq!(dst.last(), Some(&0));
+    let decoded = decode(0, &dst[..dst.len() - 1]).unwrap();
+    assert_eq!(decoded, vec![1, 2, 0, 0, 3]);
+}
+
+#[test]
+fn end_frame_starts_a_fresh_frame() {
+    let mut dst = Vec::new();
+    let mut writer = CobsWriter::new(0, &mut dst);
+    writer.write_all(b"one").unwrap();
+    writer.end_frame().unwrap();
+    writer.write_all(b"two").unwrap();
+    writer.end_frame().unwrap();
+
+    let mut frames = dst.split(|&b| b == 0).filter(|f| !f.is_empty());
+    assert_eq!(decode(0, frames.next().unwrap()).unwrap(), b"one");
+    assert_eq!(decode(0, frames.next().unwrap()).unwrap(), b"two");
+}
+
+#[test]
+fn encode_frame_streams_a_reader_into_one_terminated_frame() {
+    let payload: Vec<u8> = (0..=10u8).collect();
+    let mut src = payload.as_slice();
+    let mut dst = Vec::new();
+
+    let copied = encode_frame(0, &mut src, &mut dst).unwrap();
+
+    assert_eq!(copied, payload.len() as u64);
+    assert_eq!(dst.last(), Some(&0));
+    assert_eq!(decode(0, &dst[..dst.len() - 1]).unwrap(), payload);
+}
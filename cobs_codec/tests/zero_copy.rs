@@ -0,0 +1,52 @@
+#![cfg(feature = "tokio")]
+
+use cobs_codec::bytes::BytesMut;
+use cobs_codec::{CobsError, Decoder};
+
+#[test]
+fn sentinel_free_frame_borrows_the_source_allocation() {
+    let payload = b"hello, world";
+    let mut framed = BytesMut::new();
+    cobs_codec::Encoder::with_sentinel(0).encode_frame(payload, &mut framed);
+
+    let backing_ptr = framed.as_ptr();
+    let decoder = Decoder::with_sentinel(0);
+    let decoded = decoder.decode_zero_copy(&mut framed).unwrap().unwrap();
+
+    assert_eq!(&decoded[..], payload);
+    // The decoded bytes still point into the original allocation: no copy.
+    assert!(std::ptr::eq(decoded.as_ptr(), unsafe { backing_ptr.add(1) }));
+}
+
+#[test]
+fn frame_needing_destuffing_still_decodes_correctly() {
+    let payload = [0u8, 1, 2, 0, 0, 255];
+    let mut framed = BytesMut::new();
+    cobs_codec::Encoder::with_sentinel(0).encode_frame(&payload, &mut framed);
+
+    let decoder = Decoder::with_sentinel(0);
+    let decoded = decoder.decode_zero_copy(&mut framed).unwrap().unwrap();
+    assert_eq!(&decoded[..], &payload[..]);
+}
+
+#[test]
+fn strict_mode_rejects_a_non_canonical_single_group_frame_too() {
+    // Same non-canonical frame `tests/strict.rs` proves `decode_frame` rejects
+    // under `with_strict(true)`: a single 254-byte group missing its trailing
+    // empty group still destuffs to the same payload, but isn't the frame
+    // `Encoder` would have produced. The zero-copy fast path must not bypass
+    // that check just because the frame happens to be shaped as one group.
+    let payload = [1u8; 254];
+    let mut framed = Vec::new();
+    cobs_codec::encode(0, &payload, &mut framed);
+    let canonical_frame = &framed[..framed.len() - 1];
+    let short_frame = &canonical_frame[..canonical_frame.len() - 1];
+
+    let mut src = BytesMut::from(short_frame);
+    src.extend_from_slice(&[0]);
+    let decoder = Decoder::with_sentinel(0).with_strict(true);
+    assert!(matches!(
+        decoder.decode_zero_copy(&mut src).unwrap_err(),
+        CobsError::NonCanonicalEncoding { .. }
+    ));
+}
@@ -0,0 +1,35 @@
+use cobs_codec::zpe::{DecoderZpe, EncoderZpe};
+
+fn roundtrip(data: &[u8]) {
+    let encoder = EncoderZpe::with_sentinel(0);
+    let mut framed = Vec::new();
+    encoder.encode_frame_into(data, &mut framed);
+    framed.truncate(framed.len() - 1); // drop the trailing sentinel
+
+    let decoder = DecoderZpe::with_sentinel(0);
+    let decoded = decoder.decode_frame(&framed).unwrap();
+    assert_eq!(decoded, data);
+}
+
+#[test]
+fn round_trips_arbitrary_payloads() {
+    roundtrip(b"");
+    roundtrip(b"\0");
+    roundtrip(b"hello, world");
+    roundtrip(&[0, 1, 2, 0, 0, 255, 254]);
+    roundtrip(&[0u8; 300]);
+    roundtrip(&[1u8; 300]);
+}
+
+#[test]
+fn long_zero_runs_beat_plain_cobs() {
+    let data = [0u8; 300];
+    let encoder = EncoderZpe::with_sentinel(0);
+    let mut zpe_framed = Vec::new();
+    encoder.encode_frame_into(&data, &mut zpe_framed);
+
+    let mut plain_framed = Vec::new();
+    cobs_codec::encode(0, &data, &mut plain_framed);
+
+    assert!(zpe_framed.len() < plain_framed.len());
+}
@@ -0,0 +1,109 @@
+//! Companion derive for `cobs_codec`. See [`CobsFrame`].
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Type};
+
+/// Derive `to_cobs_frame` / `from_cobs_frame` for a struct with named fields.
+///
+/// Each field is serialized in declaration order: fixed-size scalars as their
+/// little-endian bytes, and `Vec<u8>` / `String` fields length-prefixed with an
+/// LEB128 varint. The flat buffer is then COBS-framed with
+/// [`cobs_codec::Encoder`]; decoding reverses the process on a frame yielded by
+/// [`cobs_codec::Decoder`].
+#[proc_macro_derive(CobsFrame)]
+pub fn derive_cobs_frame(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            _ => {
+                return compile_error(name, "CobsFrame requires named fields");
+            }
+        },
+        _ => {
+            return compile_error(name, "CobsFrame can only be derived for structs");
+        }
+    };
+
+    let mut writes = Vec::new();
+    let mut reads = Vec::new();
+    let mut idents = Vec::new();
+    for field in fields {
+        let ident = field.ident.as_ref().expect("named field");
+        idents.push(ident);
+        match classify(&field.ty) {
+            FieldKind::Bytes => {
+                writes.push(quote! {
+                    ::cobs_codec::frame::write_var_bytes(&mut __flat, &self.#ident);
+                });
+                reads.push(quote! {
+                    let #ident = __reader.read_var_bytes()?;
+                });
+            }
+            FieldKind::Text => {
+                writes.push(quote! {
+                    ::cobs_codec::frame::write_var_bytes(&mut __flat, self.#ident.as_bytes());
+                });
+                reads.push(quote! {
+                    let #ident = __reader.read_string()?;
+                });
+            }
+            FieldKind::Scalar => {
+                writes.push(quote! {
+                    ::cobs_codec::frame::Scalar::write_le(&self.#ident, &mut __flat);
+                });
+                reads.push(quote! {
+                    let #ident = ::cobs_codec::frame::Scalar::read_le(&mut __reader)?;
+                });
+            }
+        }
+    }
+
+    let expanded = quote! {
+        impl #name {
+            /// Serialize `self` into a single COBS frame appended to `dst`.
+            pub fn to_cobs_frame(&self, dst: &mut ::cobs_codec::bytes::BytesMut) {
+                let mut __flat: ::std::vec::Vec<u8> = ::std::vec::Vec::new();
+                #(#writes)*
+                let __encoder = ::cobs_codec::Encoder::with_sentinel(0);
+                __encoder.encode_frame(&__flat, dst);
+            }
+
+            /// Reconstruct `Self` from the payload a `Decoder` yielded.
+            pub fn from_cobs_frame(payload: &[u8]) -> ::core::result::Result<Self, ::cobs_codec::CobsError> {
+                let mut __reader = ::cobs_codec::frame::Reader::new(payload);
+                #(#reads)*
+                ::core::result::Result::Ok(Self { #(#idents),* })
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+enum FieldKind {
+    Scalar,
+    Bytes,
+    Text,
+}
+
+/// Classify a field by the last segment of its type path.
+fn classify(ty: &Type) -> FieldKind {
+    if let Type::Path(path) = ty {
+        if let Some(segment) = path.path.segments.last() {
+            return match segment.ident.to_string().as_str() {
+                "Vec" => FieldKind::Bytes,
+                "String" => FieldKind::Text,
+                _ => FieldKind::Scalar,
+            };
+        }
+    }
+    FieldKind::Scalar
+}
+
+fn compile_error(name: &syn::Ident, message: &str) -> TokenStream {
+    syn::Error::new(name.span(), message).to_compile_error().into()
+}
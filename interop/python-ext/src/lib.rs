@@ -0,0 +1,63 @@
+//! pyo3 bindings for `cobs_codec`'s [`Encoder`]/[`Decoder`], so this repo's
+//! Python test tooling can frame and deframe large corpora in-process instead
+//! of shelling out to the Rust CLI once per frame.
+
+// `#[pymethods]`'s expansion reuses `decode_frame`'s own span for a generated
+// `.into()` on its `PyResult`, so clippy blames our source for a conversion
+// the macro introduces.
+#![allow(clippy::useless_conversion)]
+
+use cobs_codec::{CobsError, Decoder, Encoder};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// Map a [`CobsError`] to a Python `ValueError`, same message and byte
+/// offset the Rust CLI's own diagnostics report.
+fn to_py_err(err: CobsError) -> PyErr {
+    PyValueError::new_err(format!("{err} at byte offset {}", err.offset()))
+}
+
+/// Stuffs frames on a runtime-selectable sentinel. Mirrors
+/// `cobs_codec::Encoder`'s sentinel semantics.
+#[pyclass(name = "Encoder")]
+struct PyEncoder(Encoder);
+
+#[pymethods]
+impl PyEncoder {
+    #[new]
+    fn new(sentinel: u8) -> Self {
+        Self(Encoder::with_sentinel(sentinel))
+    }
+
+    /// Stuff `data` and return a single terminated frame.
+    fn encode_frame(&self, data: &[u8]) -> Vec<u8> {
+        let mut dst = Vec::new();
+        self.0.encode_frame_into(data, &mut dst);
+        dst
+    }
+}
+
+/// Destuffs frames on a runtime-selectable sentinel. Mirrors
+/// `cobs_codec::Decoder`'s sentinel semantics.
+#[pyclass(name = "Decoder")]
+struct PyDecoder(Decoder);
+
+#[pymethods]
+impl PyDecoder {
+    #[new]
+    fn new(sentinel: u8) -> Self {
+        Self(Decoder::with_sentinel(sentinel))
+    }
+
+    /// Destuff a single frame's content (without the trailing delimiter).
+    fn decode_frame(&self, frame: &[u8]) -> PyResult<Vec<u8>> {
+        self.0.decode_frame(frame).map_err(to_py_err)
+    }
+}
+
+#[pymodule]
+fn cobs_python(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyEncoder>()?;
+    m.add_class::<PyDecoder>()?;
+    Ok(())
+}
@@ -1,54 +1,1288 @@
+use base64::Engine;
 use bytes::BytesMut;
-use clap::{Parser, Subcommand};
-use cobs_codec::{Decoder, Encoder};
+use clap::{Parser, Subcommand, ValueEnum};
+use cobs_codec::vectors::{self, Vector};
+use cobs_codec::{CobsError, CodecStats, Decoder, Encoder};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
 use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
 use tokio_util::codec::Decoder as DecoderTrait;
 use tokio_util::codec::Encoder as EncoderTrait;
 
-// SENTINEL value can be set at compile time via environment variable:
-// SENTINEL=10 cargo build
-const SENTINEL: u8 = match env!("SENTINEL_VALUE").as_bytes() {
-    [b] => *b - b'0',
-    [b1, b2] => (*b1 - b'0') * 10 + (*b2 - b'0'),
-    [b1, b2, b3] => (*b1 - b'0') * 100 + (*b2 - b'0') * 10 + (*b3 - b'0'),
-    _ => 0,
-};
+/// Size of each chunk pulled from the input in streaming mode.
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// Text encoding applied to file/stdin input and stdout output, so frames can
+/// be pasted into or read from a terminal without piping through xxd/base64.
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Bytes as-is; the default, for files and pipes.
+    Raw,
+    /// Whitespace-tolerant lowercase/uppercase hex text.
+    Hex,
+    /// Standard (RFC 4648) base64 text.
+    Base64,
+}
+
+/// Wire format each JSON record is serialized to before it's wrapped in a
+/// COBS frame by `encode-json` (and read back out by `decode-json`).
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum RecordFormat {
+    /// Compact binary encoding via postcard; the default.
+    Postcard,
+    /// CBOR, for interop with tooling that already speaks it.
+    Cbor,
+}
+
+/// A JSON value with a fixed shape known at compile time, unlike
+/// `serde_json::Value`, whose `Deserialize` impl requires the wire format to
+/// describe its own type tags (`deserialize_any`) — a requirement postcard
+/// deliberately doesn't support. Records are converted to and from this type
+/// at the JSON boundary so the same framing code works for both formats.
+#[derive(Serialize, Deserialize)]
+enum Record {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Record>),
+    Object(Vec<(String, Record)>),
+}
+
+impl From<serde_json::Value> for Record {
+    fn from(value: serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::Null => Record::Null,
+            serde_json::Value::Bool(b) => Record::Bool(b),
+            serde_json::Value::Number(n) => Record::Number(n.as_f64().unwrap_or(0.0)),
+            serde_json::Value::String(s) => Record::String(s),
+            serde_json::Value::Array(a) => Record::Array(a.into_iter().map(Record::from).collect()),
+            serde_json::Value::Object(o) => {
+                Record::Object(o.into_iter().map(|(k, v)| (k, Record::from(v))).collect())
+            }
+        }
+    }
+}
+
+impl From<Record> for serde_json::Value {
+    fn from(record: Record) -> Self {
+        match record {
+            Record::Null => serde_json::Value::Null,
+            Record::Bool(b) => serde_json::Value::Bool(b),
+            Record::Number(n) => serde_json::Number::from_f64(n)
+                .map(serde_json::Value::Number)
+                .unwrap_or(serde_json::Value::Null),
+            Record::String(s) => serde_json::Value::String(s),
+            Record::Array(a) => serde_json::Value::Array(a.into_iter().map(Into::into).collect()),
+            Record::Object(o) => serde_json::Value::Object(o.into_iter().map(|(k, v)| (k, v.into())).collect()),
+        }
+    }
+}
+
+/// Serialize `record` to `format`'s wire encoding.
+fn serialize_record(format: RecordFormat, record: &Record) -> Result<Vec<u8>, CobsError> {
+    match format {
+        RecordFormat::Postcard => {
+            postcard::to_allocvec(record).map_err(|e| input_error(format!("postcard: {e}")))
+        }
+        RecordFormat::Cbor => {
+            let mut out = Vec::new();
+            ciborium::into_writer(record, &mut out).map_err(|e| input_error(format!("cbor: {e}")))?;
+            Ok(out)
+        }
+    }
+}
+
+/// Deserialize a `format`-encoded record.
+fn deserialize_record(format: RecordFormat, bytes: &[u8]) -> Result<Record, CobsError> {
+    match format {
+        RecordFormat::Postcard => {
+            postcard::from_bytes(bytes).map_err(|e| input_error(format!("postcard: {e}")))
+        }
+        RecordFormat::Cbor => ciborium::from_reader(bytes).map_err(|e| input_error(format!("cbor: {e}"))),
+    }
+}
+
+/// Decode `input` from `format` into the raw bytes the codec operates on.
+fn decode_input(format: Format, input: &[u8]) -> Result<Vec<u8>, CobsError> {
+    match format {
+        Format::Raw => Ok(input.to_vec()),
+        Format::Hex => {
+            let digits: String = input.iter().filter(|b| !b.is_ascii_whitespace()).map(|&b| b as char).collect();
+            if !digits.len().is_multiple_of(2) {
+                return Err(input_error("odd number of hex digits".into()));
+            }
+            (0..digits.len())
+                .step_by(2)
+                .map(|i| {
+                    u8::from_str_radix(&digits[i..i + 2], 16)
+                        .map_err(|e| input_error(format!("invalid hex digit: {e}")))
+                })
+                .collect()
+        }
+        Format::Base64 => base64::engine::general_purpose::STANDARD
+            .decode(input.iter().filter(|b| !b.is_ascii_whitespace()).copied().collect::<Vec<u8>>())
+            .map_err(|e| input_error(format!("invalid base64: {e}"))),
+    }
+}
+
+/// Render `output` as `format` for writing to stdout.
+fn encode_output(format: Format, output: &[u8]) -> Vec<u8> {
+    match format {
+        Format::Raw => output.to_vec(),
+        Format::Hex => {
+            let mut s = String::with_capacity(output.len() * 2 + 1);
+            for b in output {
+                s.push_str(&format!("{b:02x}"));
+            }
+            s.push('\n');
+            s.into_bytes()
+        }
+        Format::Base64 => {
+            let mut s = base64::engine::general_purpose::STANDARD.encode(output);
+            s.push('\n');
+            s.into_bytes()
+        }
+    }
+}
+
+/// Parse a byte count given as a plain number or with a `k`/`m` suffix
+/// (`64k` = 65536, `1m` = 1048576), case-insensitive.
+fn parse_size(s: &str) -> Result<usize, String> {
+    let (digits, multiplier) = match s.strip_suffix(['k', 'K']) {
+        Some(digits) => (digits, 1024),
+        None => match s.strip_suffix(['m', 'M']) {
+            Some(digits) => (digits, 1024 * 1024),
+            None => (s, 1),
+        },
+    };
+    digits
+        .parse::<usize>()
+        .map(|n| n * multiplier)
+        .map_err(|e| format!("invalid size `{s}`: {e}"))
+}
+
+/// Parse a sentinel byte given as decimal (`10`) or `0x`-prefixed hex (`0x5A`).
+fn parse_sentinel(s: &str) -> Result<u8, String> {
+    let value = if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        u8::from_str_radix(hex, 16)
+    } else {
+        s.parse::<u8>()
+    };
+    value.map_err(|e| format!("invalid sentinel `{s}`: {e}"))
+}
+
+/// The record delimiter `--split-on` chops encode's input into before
+/// framing each piece as its own COBS frame.
+#[derive(Clone, Copy)]
+enum SplitOn {
+    Newline,
+    Byte(u8),
+}
+
+fn parse_split_on(s: &str) -> Result<SplitOn, String> {
+    if s == "newline" {
+        Ok(SplitOn::Newline)
+    } else {
+        parse_sentinel(s).map(SplitOn::Byte)
+    }
+}
+
+/// Chop `input` into records on `split_on`, dropping a single trailing
+/// delimiter so a file ending in the usual final newline doesn't produce a
+/// spurious empty trailing frame.
+fn split_records(input: &[u8], split_on: SplitOn) -> Vec<Vec<u8>> {
+    let delimiter = match split_on {
+        SplitOn::Newline => b'\n',
+        SplitOn::Byte(b) => b,
+    };
+    let input = input.strip_suffix(&[delimiter]).unwrap_or(input);
+    input.split(|&b| b == delimiter).map(<[u8]>::to_vec).collect()
+}
 
 #[derive(Parser)]
 struct Cli {
+    /// Delimiter byte used to frame the COBS stream (decimal or `0x`-prefixed hex).
+    ///
+    /// Fully runtime: `Encoder`/`Decoder::with_sentinel` take this as a plain
+    /// `u8` argument, so one build of this binary covers every delimiter —
+    /// there's no compile-time `SENTINEL` const or env var to rebuild against.
+    #[arg(long, global = true, default_value = "0", value_parser = parse_sentinel)]
+    sentinel: u8,
+
+    /// Process the input incrementally, keeping memory bounded for unbounded pipes.
+    #[arg(long, global = true)]
+    stream: bool,
+
+    /// In --stream mode, don't flush stdout after every decoded frame.
+    ///
+    /// The default flushes per frame so a live source (e.g. a serial port
+    /// fed through socat) shows up immediately instead of sitting in a
+    /// buffer; pass this to trade that liveness for throughput.
+    #[arg(long, global = true)]
+    no_flush: bool,
+
+    /// Text encoding of the input (only valid with --raw --stream off).
+    #[arg(long, global = true, value_enum, default_value_t = Format::Raw)]
+    input_format: Format,
+
+    /// Text encoding to write the output in (only valid with --stream off).
+    #[arg(long, global = true, value_enum, default_value_t = Format::Raw)]
+    output_format: Format,
+
+    /// Read from this file instead of stdin (or the positional paths).
+    ///
+    /// Piping binary data through a shell corrupts frames on Windows, so
+    /// this is the reliable path for test data there; the positional
+    /// `paths` argument on encode/decode still accepts globs and multiple
+    /// files and takes precedence if both are given.
+    #[arg(long, global = true)]
+    input: Option<PathBuf>,
+
+    /// Write to this file instead of stdout.
+    #[arg(long, global = true)]
+    output: Option<PathBuf>,
+
+    /// Print frames processed, input/output byte counts, stuffing overhead,
+    /// and decode errors encountered to stderr once processing finishes.
+    #[arg(long, global = true)]
+    stats: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
 
 #[derive(Subcommand)]
 enum Commands {
-    Decode,
-    Encode,
+    Decode {
+        /// Input files or glob patterns, read in sorted order; stdin when omitted.
+        paths: Vec<String>,
+
+        /// Skip past malformed frames instead of aborting on the first one,
+        /// printing a count of how many were dropped. For bulk-decoding
+        /// captured real-world dumps (e.g. a serial port log) with glitches
+        /// scattered through them.
+        #[arg(long)]
+        lenient: bool,
+
+        /// Instead of discarding a malformed or truncated frame outright,
+        /// emit the payload bytes destuffed before the failure and report it
+        /// as partial on stderr. For forensic analysis of corrupted
+        /// captures, where a partial payload beats none. Not valid with
+        /// --stream; takes precedence over --lenient.
+        #[arg(long)]
+        salvage: bool,
+    },
+    Encode {
+        /// Input files or glob patterns, read in sorted order; stdin when omitted.
+        paths: Vec<String>,
+
+        /// Split the input into multiple records before encoding, emitting
+        /// one COBS frame per record instead of treating the whole input as
+        /// a single frame. `newline` splits on `\n`; anything else is parsed
+        /// as a single delimiter byte (decimal or `0x`-prefixed hex), the
+        /// same as `--sentinel`. Not valid with `--stream`.
+        #[arg(long, value_parser = parse_split_on)]
+        split_on: Option<SplitOn>,
+    },
+    /// Read NDJSON records from stdin (or files), serialize each with
+    /// `--format`, and emit one COBS frame per record. Turns this binary
+    /// into a device-simulation source instead of raw bytes only.
+    EncodeJson {
+        /// Input files or glob patterns, read in sorted order; stdin when omitted.
+        paths: Vec<String>,
+
+        /// Wire format each record is serialized to before framing.
+        #[arg(long, value_enum, default_value_t = RecordFormat::Postcard)]
+        format: RecordFormat,
+    },
+    /// Decode a stream of COBS frames produced by `encode-json` (or a real
+    /// device speaking the same wire format) back into NDJSON on stdout.
+    DecodeJson {
+        /// Input files or glob patterns, read in sorted order; stdin when omitted.
+        paths: Vec<String>,
+
+        /// Wire format each frame's payload was serialized with.
+        #[arg(long, value_enum, default_value_t = RecordFormat::Postcard)]
+        format: RecordFormat,
+    },
+    /// Emit or check the `{sentinel, payload_hex, encoded_hex}` corpus other
+    /// language implementations under `interop/` validate against.
+    Vectors {
+        #[command(subcommand)]
+        action: VectorsAction,
+    },
+    /// Cross-check this crate against the other COBS implementations in the
+    /// dependency tree: encode a set of generated payloads with each
+    /// implementation and decode the result with every other, reporting a
+    /// pass/fail matrix. Replaces one-off interop shell scripts.
+    Interop,
+    /// Encode then decode a payload and confirm the roundtrip is identity;
+    /// a quick sanity check for a downstream project's CI pipeline.
+    Verify {
+        /// Instead of reading stdin once, generate this many random payloads
+        /// of random size (up to --max-size) and verify each.
+        #[arg(long)]
+        iterations: Option<usize>,
+
+        /// Largest size, in bytes, of a randomly generated payload.
+        #[arg(long, default_value_t = 4096)]
+        max_size: usize,
+    },
+    /// Roundtrip generated payloads through every one of the 256 possible
+    /// sentinel values, reporting any that fail. Catches sentinel-specific
+    /// edge cases a single fixed compile-time `SENTINEL` build can't.
+    Sweep {
+        /// Largest size, in bytes, of a generated payload.
+        #[arg(long, default_value_t = 4096)]
+        max_size: usize,
+
+        /// Seed the generator for a reproducible sweep; otherwise seeded
+        /// from the clock.
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Measure encode/decode throughput on this machine, for a quick sanity
+    /// check on target hardware without installing criterion.
+    Bench {
+        /// Payload size per iteration, e.g. `4096`, `64k`, `1m`.
+        #[arg(long, default_value = "64k", value_parser = parse_size)]
+        size: usize,
+
+        /// Number of encode/decode iterations to time.
+        #[arg(long, default_value_t = 1000)]
+        iters: usize,
+    },
+    /// Emit random payloads (raw or pre-encoded), for fuzzers, interop
+    /// scripts, and device-under-test harnesses that need sample frames.
+    Generate {
+        /// Number of payloads to emit.
+        count: usize,
+
+        /// Smallest payload size, in bytes.
+        #[arg(long, default_value_t = 0, value_parser = parse_size)]
+        min_size: usize,
+
+        /// Largest payload size, in bytes.
+        #[arg(long, default_value = "256", value_parser = parse_size)]
+        max_size: usize,
+
+        /// Fraction (0.0-1.0) of bytes that are the sentinel value, to
+        /// control how often stuffing kicks in; defaults to uniformly random
+        /// bytes (a natural ~1/256 density).
+        #[arg(long)]
+        sentinel_density: Option<f64>,
+
+        /// COBS-frame each payload (self-delimiting, pipeable straight into
+        /// `decode --stream`) instead of emitting the raw payload bytes.
+        #[arg(long)]
+        encode: bool,
+
+        /// Seed the generator for a reproducible sequence; otherwise seeded
+        /// from the clock.
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+}
+
+#[derive(Subcommand)]
+enum VectorsAction {
+    /// Print this crate's default corpus as pretty-printed JSON.
+    Export,
+    /// Verify every vector in a JSON corpus file against this crate's
+    /// Encoder/Decoder, reporting each failure and exiting non-zero if any.
+    Verify {
+        /// Corpus file to check; `interop/vectors.json` when omitted.
+        path: Option<PathBuf>,
+    },
 }
 
-fn main() {
+fn main() -> ExitCode {
     let cli = Cli::parse();
 
+    let result = match &cli.command {
+        Commands::Decode { paths, lenient, salvage } => match open_input(paths, cli.input.as_ref()) {
+            Err(err) => Err(err),
+            Ok(mut reader) => match open_output(cli.output.as_ref()) {
+                Err(err) => Err(err),
+                Ok(mut writer) => {
+                    if *salvage {
+                        if cli.stream {
+                            Err(input_error("--salvage requires --stream off".into()))
+                        } else if cli.input_format != Format::Raw || cli.output_format != Format::Raw {
+                            Err(input_error("--input-format/--output-format require --stream off".into()))
+                        } else {
+                            decode_salvage(cli.sentinel, &mut reader, &mut *writer)
+                        }
+                    } else if cli.stream {
+                        if cli.input_format != Format::Raw || cli.output_format != Format::Raw {
+                            Err(input_error("--input-format/--output-format require --stream off".into()))
+                        } else {
+                            stream_decode(
+                                cli.sentinel,
+                                !cli.no_flush,
+                                cli.stats,
+                                *lenient,
+                                &mut reader,
+                                &mut *writer,
+                            )
+                        }
+                    } else {
+                        let mut decoder = Decoder::with_sentinel(cli.sentinel)
+                            .with_stats(cli.stats || *lenient)
+                            .with_resync(*lenient);
+                        let mut output = BytesMut::new();
+                        let res = (|| {
+                            let input = decode_input(cli.input_format, &read_all(&mut reader)?)?;
+                            let mut src = BytesMut::from(&input[..]);
+                            let mut consumed = 0usize;
+                            loop {
+                                let before = src.len();
+                                match decoder.decode(&mut src).map_err(|e| e.offset_by(consumed))? {
+                                    Some(frame) => {
+                                        consumed += before - src.len();
+                                        output.extend_from_slice(&frame);
+                                    }
+                                    None => break,
+                                }
+                            }
+                            // Flush any trailing frame and surface a truncated tail
+                            // rather than silently dropping it, as --stream does.
+                            if let Some(frame) =
+                                decoder.decode_eof(&mut src).map_err(|e| e.offset_by(consumed))?
+                            {
+                                output.extend_from_slice(&frame);
+                            }
+                            Ok(())
+                        })();
+                        writer.write_all(&encode_output(cli.output_format, &output)).unwrap();
+                        if cli.stats {
+                            report_stats(decoder.stats().copied().unwrap_or_default());
+                        }
+                        report_lenient_drops(*lenient, &decoder);
+                        res
+                    }
+                }
+            },
+        },
+        Commands::Encode { paths, split_on } => match open_input(paths, cli.input.as_ref()) {
+            Err(err) => Err(err),
+            Ok(mut reader) => match open_output(cli.output.as_ref()) {
+                Err(err) => Err(err),
+                Ok(mut writer) => {
+                    if cli.stream {
+                        if cli.input_format != Format::Raw || cli.output_format != Format::Raw {
+                            Err(input_error("--input-format/--output-format require --stream off".into()))
+                        } else if split_on.is_some() {
+                            Err(input_error("--split-on requires --stream off".into()))
+                        } else if cli.stats {
+                            Err(input_error("--stats requires --stream off for encode".into()))
+                        } else {
+                            stream_encode(cli.sentinel, &mut reader, &mut *writer)
+                        }
+                    } else {
+                        match read_all(&mut reader).and_then(|raw| decode_input(cli.input_format, &raw)) {
+                            Err(err) => Err(err),
+                            Ok(input) => {
+                                let mut encoder = Encoder::with_sentinel(cli.sentinel).with_stats(cli.stats);
+                                let mut dst = BytesMut::new();
+                                let (res, stats) = match split_on {
+                                    Some(split_on) => {
+                                        let records = split_records(&input, *split_on);
+                                        let stats = CodecStats {
+                                            frames: records.len(),
+                                            payload_bytes: records.iter().map(Vec::len).sum(),
+                                            ..Default::default()
+                                        };
+                                        encoder.encode_all(records, &mut dst);
+                                        (Ok(()), CodecStats { stuffed_bytes: dst.len(), ..stats })
+                                    }
+                                    None => {
+                                        let res = encoder.encode(input, &mut dst);
+                                        (res, encoder.stats().copied().unwrap_or_default())
+                                    }
+                                };
+                                writer.write_all(&encode_output(cli.output_format, &dst)).unwrap();
+                                if cli.stats {
+                                    report_stats(stats);
+                                }
+                                res
+                            }
+                        }
+                    }
+                }
+            },
+        },
+        Commands::EncodeJson { paths, format } => match open_input(paths, cli.input.as_ref()) {
+            Err(err) => Err(err),
+            Ok(mut reader) => match open_output(cli.output.as_ref()) {
+                Err(err) => Err(err),
+                Ok(mut writer) => encode_json_command(cli.sentinel, *format, &mut reader, &mut *writer),
+            },
+        },
+        Commands::DecodeJson { paths, format } => match open_input(paths, cli.input.as_ref()) {
+            Err(err) => Err(err),
+            Ok(mut reader) => match open_output(cli.output.as_ref()) {
+                Err(err) => Err(err),
+                Ok(mut writer) => decode_json_command(cli.sentinel, *format, &mut reader, &mut *writer),
+            },
+        },
+        Commands::Vectors { action } => return vectors_command(action),
+        Commands::Interop => return interop_command(),
+        Commands::Verify { iterations, max_size } => {
+            return verify_command(cli.sentinel, *iterations, *max_size)
+        }
+        Commands::Sweep { max_size, seed } => return sweep_command(*seed, *max_size),
+        Commands::Bench { size, iters } => return bench_command(cli.sentinel, *size, *iters),
+        Commands::Generate {
+            count,
+            min_size,
+            max_size,
+            sentinel_density,
+            encode,
+            seed,
+        } => {
+            return generate_command(
+                cli.sentinel,
+                cli.output_format,
+                *count,
+                *min_size,
+                *max_size,
+                *sentinel_density,
+                *encode,
+                *seed,
+            )
+        }
+    };
+
+    io::stdout().flush().unwrap();
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            report(&err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// One COBS implementation under test: an encoder and a decoder over the
+/// wire frame, trailing delimiter included (the form all three crates agree
+/// on once corncobs's self-terminating output and cobs's XOR-by-sentinel
+/// trick are accounted for).
+struct Implementation {
+    name: &'static str,
+    /// Sentinels this implementation can frame with; corncobs hard-codes the
+    /// zero byte, unlike cobs_codec and cobs's runtime-selectable one.
+    sentinels: &'static [u8],
+    encode: fn(u8, &[u8]) -> Vec<u8>,
+    decode: fn(u8, &[u8]) -> Result<Vec<u8>, String>,
+}
+
+const IMPLEMENTATIONS: &[Implementation] = &[
+    Implementation {
+        name: "cobs_codec",
+        sentinels: &[0, 1, 0xAA, 0xFF],
+        encode: |sentinel, payload| {
+            let mut framed = Vec::new();
+            Encoder::with_sentinel(sentinel).encode_frame_into(payload, &mut framed);
+            framed
+        },
+        decode: |sentinel, frame| {
+            let frame = frame.strip_suffix(&[sentinel]).unwrap_or(frame);
+            Decoder::with_sentinel(sentinel)
+                .decode_frame(frame)
+                .map_err(|e| e.to_string())
+        },
+    },
+    Implementation {
+        name: "cobs",
+        sentinels: &[0, 1, 0xAA, 0xFF],
+        encode: |sentinel, payload| {
+            let mut framed = cobs_crate::encode_vec_with_sentinel(payload, sentinel);
+            framed.push(sentinel);
+            framed
+        },
+        decode: |sentinel, frame| {
+            cobs_crate::decode_vec_with_sentinel(frame, sentinel).map_err(|e| format!("{e:?}"))
+        },
+    },
+    Implementation {
+        name: "corncobs",
+        sentinels: &[0],
+        encode: |_sentinel, payload| {
+            let mut out = vec![0u8; corncobs::max_encoded_len(payload.len())];
+            let n = corncobs::encode_buf(payload, &mut out);
+            out.truncate(n);
+            out
+        },
+        decode: |_sentinel, frame| {
+            let mut out = vec![0u8; frame.len()];
+            let n = corncobs::decode_buf(frame, &mut out).map_err(|e| format!("{e:?}"))?;
+            out.truncate(n);
+            Ok(out)
+        },
+    },
+];
+
+/// Payloads exercising the same edge cases the crate's own test corpus does:
+/// empty, embedded sentinel-sized runs, a 254-byte block boundary, and a
+/// sentinel-free run.
+fn interop_payloads() -> Vec<Vec<u8>> {
+    vec![
+        Vec::new(),
+        vec![0],
+        b"hello, world".to_vec(),
+        vec![0, 1, 2, 0, 0, 255, 254],
+        vec![0u8; 300],
+        (0..=253u8).collect(),
+    ]
+}
+
+/// Encode every payload with every implementation that supports `sentinel`
+/// and decode the result with every other such implementation, printing a
+/// pass/fail matrix and returning failure if any pair disagrees.
+fn interop_command() -> ExitCode {
+    let payloads = interop_payloads();
+    let mut failures = 0;
+
+    for encoder in IMPLEMENTATIONS {
+        for decoder in IMPLEMENTATIONS {
+            for &sentinel in encoder.sentinels {
+                if !decoder.sentinels.contains(&sentinel) {
+                    continue;
+                }
+                let mut ok = true;
+                for payload in &payloads {
+                    let frame = (encoder.encode)(sentinel, payload);
+                    match (decoder.decode)(sentinel, &frame) {
+                        Ok(decoded) if &decoded == payload => {}
+                        Ok(decoded) => {
+                            ok = false;
+                            eprintln!(
+                                "{} -> {} (sentinel={sentinel}): payload {payload:?} decoded as {decoded:?}"
+                            , encoder.name, decoder.name);
+                        }
+                        Err(e) => {
+                            ok = false;
+                            eprintln!(
+                                "{} -> {} (sentinel={sentinel}): payload {payload:?} failed to decode: {e}"
+                            , encoder.name, decoder.name);
+                        }
+                    }
+                }
+                println!(
+                    "{:<10} -> {:<10} sentinel={sentinel:<3} {}",
+                    encoder.name,
+                    decoder.name,
+                    if ok { "PASS" } else { "FAIL" }
+                );
+                if !ok {
+                    failures += 1;
+                }
+            }
+        }
+    }
+
+    if failures == 0 {
+        ExitCode::SUCCESS
+    } else {
+        eprintln!("{failures} implementation pair(s) failed");
+        ExitCode::FAILURE
+    }
+}
+
+/// A minimal xorshift64* generator, seeded from the clock. Not
+/// cryptographic, but good enough to pick payload sizes/bytes for a CI
+/// self-check without pulling in a `rand` dependency.
+struct Rng(u64);
+
+impl Rng {
+    fn seeded() -> Self {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0);
+        Self::from_seed(nanos)
+    }
+
+    fn from_seed(seed: u64) -> Self {
+        Self(seed | 1)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// A float in `[0.0, 1.0)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        if bound == 0 {
+            0
+        } else {
+            (self.next_u64() as usize) % bound
+        }
+    }
+
+    fn payload(&mut self, max_size: usize) -> Vec<u8> {
+        let len = self.below(max_size + 1);
+        (0..len).map(|_| self.next_u64() as u8).collect()
+    }
+
+    /// A payload of `len` bytes, each the given `sentinel` with probability
+    /// `density` and otherwise a uniformly random non-sentinel byte.
+    fn payload_with_sentinel_density(&mut self, len: usize, sentinel: u8, density: f64) -> Vec<u8> {
+        (0..len)
+            .map(|_| {
+                if self.next_f64() < density {
+                    sentinel
+                } else {
+                    loop {
+                        let b = self.next_u64() as u8;
+                        if b != sentinel {
+                            break b;
+                        }
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+/// Encode then decode `payload` and report how the result diverges, if it
+/// does.
+fn roundtrip_diff(sentinel: u8, payload: &[u8]) -> Result<(), String> {
+    let mut framed = Vec::new();
+    Encoder::with_sentinel(sentinel).encode_frame_into(payload, &mut framed);
+    framed.pop(); // drop the trailing delimiter before decoding the bare frame
+
+    let decoded = Decoder::with_sentinel(sentinel)
+        .decode_frame(&framed)
+        .map_err(|e| format!("decode failed: {e}"))?;
+
+    if decoded == payload {
+        return Ok(());
+    }
+
+    let mismatch_at = decoded.iter().zip(payload).position(|(a, b)| a != b);
+    Err(format!(
+        "roundtrip mismatch: input {} bytes, output {} bytes, first differing byte at {}",
+        payload.len(),
+        decoded.len(),
+        mismatch_at.map_or_else(|| "n/a (length differs)".to_string(), |i| i.to_string()),
+    ))
+}
+
+fn verify_command(sentinel: u8, iterations: Option<usize>, max_size: usize) -> ExitCode {
+    let Some(iterations) = iterations else {
+        let mut payload = Vec::new();
+        if let Err(e) = io::stdin().read_to_end(&mut payload) {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+        return match roundtrip_diff(sentinel, &payload) {
+            Ok(()) => {
+                println!("ok: {} byte payload roundtrips", payload.len());
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("error: {e}");
+                ExitCode::FAILURE
+            }
+        };
+    };
+
+    let mut rng = Rng::seeded();
+    let mut failures = 0;
+    for i in 0..iterations {
+        let payload = rng.payload(max_size);
+        match roundtrip_diff(sentinel, &payload) {
+            Ok(()) => println!("ok   [{i}] {} bytes", payload.len()),
+            Err(e) => {
+                failures += 1;
+                eprintln!("FAIL [{i}] {} bytes: {e}", payload.len());
+            }
+        }
+    }
+    if failures == 0 {
+        ExitCode::SUCCESS
+    } else {
+        eprintln!("{failures} of {iterations} iterations failed");
+        ExitCode::FAILURE
+    }
+}
+
+/// Number of payloads generated per sentinel value, split between uniformly
+/// random bytes and payloads dense in the sentinel byte so the stuffing path
+/// gets exercised too, not just the sentinel-free common case.
+const SWEEP_PAYLOADS_PER_SENTINEL: usize = 8;
+
+/// Roundtrip generated payloads through every one of the 256 sentinel
+/// values, reporting each one that fails.
+fn sweep_command(seed: Option<u64>, max_size: usize) -> ExitCode {
+    let mut rng = seed.map_or_else(Rng::seeded, Rng::from_seed);
+    let mut failures = 0;
+
+    for sentinel in 0..=255u8 {
+        let mut ok = true;
+        for i in 0..SWEEP_PAYLOADS_PER_SENTINEL {
+            let len = rng.below(max_size + 1);
+            let payload = if i % 2 == 0 {
+                rng.payload_with_sentinel_density(len, sentinel, 0.5)
+            } else {
+                (0..len).map(|_| rng.next_u64() as u8).collect()
+            };
+            if let Err(e) = roundtrip_diff(sentinel, &payload) {
+                ok = false;
+                eprintln!("FAIL sentinel={sentinel} [{i}] {} bytes: {e}", payload.len());
+            }
+        }
+        if !ok {
+            failures += 1;
+        }
+    }
+
+    if failures == 0 {
+        println!("ok: all 256 sentinel values roundtrip cleanly");
+        ExitCode::SUCCESS
+    } else {
+        eprintln!("{failures} of 256 sentinel value(s) failed");
+        ExitCode::FAILURE
+    }
+}
+
+/// Report `bytes` processed in `elapsed` as MB/s (2^20 bytes per "MB").
+fn throughput_mb_per_s(bytes: u64, elapsed: std::time::Duration) -> f64 {
+    (bytes as f64 / (1024.0 * 1024.0)) / elapsed.as_secs_f64()
+}
+
+fn bench_command(sentinel: u8, size: usize, iters: usize) -> ExitCode {
+    if size == 0 || iters == 0 {
+        eprintln!("error: --size and --iters must both be non-zero");
+        return ExitCode::FAILURE;
+    }
+
+    // Sentinel-free so the encoded size is minimal and the loop below doesn't
+    // also end up measuring allocation churn from wildly varying frame sizes.
+    let payload: Vec<u8> = (0..size).map(|i| (i % 255 + 1) as u8).collect();
+    let total_bytes = (payload.len() * iters) as u64;
+
+    let encoder = Encoder::with_sentinel(sentinel);
+    let mut frame = Vec::new();
+    let start = std::time::Instant::now();
+    for _ in 0..iters {
+        frame.clear();
+        encoder.encode_frame_into(&payload, &mut frame);
+    }
+    let encode_elapsed = start.elapsed();
+
+    frame.pop(); // drop the trailing delimiter before decoding the bare frame
+    let decoder = Decoder::with_sentinel(sentinel);
+    let start = std::time::Instant::now();
+    for _ in 0..iters {
+        decoder.decode_frame(&frame).unwrap();
+    }
+    let decode_elapsed = start.elapsed();
+
+    println!("payload size: {} bytes, iterations: {iters}", payload.len());
+    println!(
+        "encode: {:.2} MB/s ({:.2?} total)",
+        throughput_mb_per_s(total_bytes, encode_elapsed),
+        encode_elapsed
+    );
+    println!(
+        "decode: {:.2} MB/s ({:.2?} total)",
+        throughput_mb_per_s(total_bytes, decode_elapsed),
+        decode_elapsed
+    );
+    ExitCode::SUCCESS
+}
+
+#[allow(clippy::too_many_arguments)]
+fn generate_command(
+    sentinel: u8,
+    output_format: Format,
+    count: usize,
+    min_size: usize,
+    max_size: usize,
+    sentinel_density: Option<f64>,
+    encode: bool,
+    seed: Option<u64>,
+) -> ExitCode {
+    if min_size > max_size {
+        eprintln!("error: --min-size must not exceed --max-size");
+        return ExitCode::FAILURE;
+    }
+
+    let mut rng = seed.map_or_else(Rng::seeded, Rng::from_seed);
+    let encoder = Encoder::with_sentinel(sentinel);
+    let mut frame = Vec::new();
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+
+    for _ in 0..count {
+        let len = min_size + rng.below(max_size - min_size + 1);
+        let payload = match sentinel_density {
+            Some(density) => rng.payload_with_sentinel_density(len, sentinel, density),
+            None => (0..len).map(|_| rng.next_u64() as u8).collect(),
+        };
+
+        let bytes: &[u8] = if encode {
+            frame.clear();
+            encoder.encode_frame_into(&payload, &mut frame);
+            &frame
+        } else {
+            &payload
+        };
+
+        if out.write_all(&encode_output(output_format, bytes)).is_err() {
+            return ExitCode::FAILURE;
+        }
+        if output_format == Format::Raw && !encode {
+            // Raw, un-encoded payloads have no self-delimiting framing of
+            // their own; separate them so the stream is at least splittable
+            // when the sentinel doesn't happen to appear in them.
+            if out.write_all(&[sentinel]).is_err() {
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+/// Read NDJSON records from `reader`, serialize each with `format`, and write
+/// one COBS frame per record to `writer`.
+fn encode_json_command(
+    sentinel: u8,
+    format: RecordFormat,
+    reader: &mut dyn Read,
+    writer: &mut dyn Write,
+) -> Result<(), CobsError> {
+    let input = read_all(reader)?;
+    let encoder = Encoder::with_sentinel(sentinel);
+    let mut dst = BytesMut::new();
+    for line in split_records(&input, SplitOn::Newline) {
+        if line.is_empty() {
+            continue;
+        }
+        let value: serde_json::Value =
+            serde_json::from_slice(&line).map_err(|e| input_error(format!("invalid JSON record: {e}")))?;
+        let serialized = serialize_record(format, &Record::from(value))?;
+        encoder.encode_frame(&serialized, &mut dst);
+    }
+    writer.write_all(&dst)?;
+    Ok(())
+}
+
+/// Decode a stream of COBS frames from `reader`, deserialize each payload
+/// with `format`, and write one NDJSON record per frame to `writer`.
+fn decode_json_command(
+    sentinel: u8,
+    format: RecordFormat,
+    reader: &mut dyn Read,
+    writer: &mut dyn Write,
+) -> Result<(), CobsError> {
+    let input = read_all(reader)?;
+    let mut decoder = Decoder::with_sentinel(sentinel);
+    let mut src = BytesMut::from(&input[..]);
+    while let Some(frame) = decoder.decode(&mut src)? {
+        let record = deserialize_record(format, &frame)?;
+        let value: serde_json::Value = record.into();
+        serde_json::to_writer(&mut *writer, &value).map_err(|e| input_error(format!("{e}")))?;
+        writer.write_all(b"\n")?;
+    }
+    Ok(())
+}
+
+fn vectors_command(action: &VectorsAction) -> ExitCode {
+    match action {
+        VectorsAction::Export => {
+            let json = vectors::to_json(&vectors::default_corpus()).expect("serializable corpus");
+            println!("{json}");
+            ExitCode::SUCCESS
+        }
+        VectorsAction::Verify { path } => {
+            let corpus: Vec<Vector> = match path {
+                Some(path) => {
+                    let json = match std::fs::read_to_string(path) {
+                        Ok(json) => json,
+                        Err(e) => {
+                            eprintln!("error: {}: {e}", path.display());
+                            return ExitCode::FAILURE;
+                        }
+                    };
+                    match vectors::from_json(&json) {
+                        Ok(corpus) => corpus,
+                        Err(e) => {
+                            eprintln!("error: {}: {e}", path.display());
+                            return ExitCode::FAILURE;
+                        }
+                    }
+                }
+                None => vectors::default_corpus(),
+            };
+
+            let mut failures = 0;
+            for vector in &corpus {
+                match vector.verify() {
+                    Ok(()) => println!("ok   sentinel={} payload={}", vector.sentinel, vector.payload_hex),
+                    Err(e) => {
+                        failures += 1;
+                        eprintln!(
+                            "FAIL sentinel={} payload={}: {e}",
+                            vector.sentinel, vector.payload_hex
+                        );
+                    }
+                }
+            }
+            if failures == 0 {
+                ExitCode::SUCCESS
+            } else {
+                eprintln!("{failures} of {} vectors failed", corpus.len());
+                ExitCode::FAILURE
+            }
+        }
+    }
+}
+
+/// Print a decode/encode diagnostic, pinpointing the offending stream offset
+/// for codec errors and the bare message for input/transport errors.
+fn report(err: &CobsError) {
+    match err {
+        CobsError::Io(e) => eprintln!("error: {e}"),
+        _ => eprintln!("error: {err} at byte offset {}", err.offset()),
+    }
+}
+
+/// Wrap an input-resolution failure as a [`CobsError`] so it flows through the
+/// same diagnostic path as codec errors.
+fn input_error(msg: String) -> CobsError {
+    io::Error::new(io::ErrorKind::InvalidInput, msg).into()
+}
+
+/// Print a `--stats` report: frames processed, input/output byte counts,
+/// stuffing overhead, and decode errors encountered.
+fn report_stats(stats: CodecStats) {
+    let overhead = if stats.payload_bytes == 0 {
+        0.0
+    } else {
+        (stats.stuffed_bytes as f64 - stats.payload_bytes as f64) / stats.payload_bytes as f64 * 100.0
+    };
+    eprintln!(
+        "frames={} payload_bytes={} stuffed_bytes={} overhead={overhead:.2}% errors={}",
+        stats.frames, stats.payload_bytes, stats.stuffed_bytes, stats.malformed_frames
+    );
+}
+
+/// Decode `reader` frame by frame, salvaging the destuffed prefix of any
+/// malformed or truncated frame instead of discarding it and reporting each
+/// one to stderr as it's found.
+fn decode_salvage(sentinel: u8, reader: &mut dyn Read, writer: &mut dyn Write) -> Result<(), CobsError> {
+    let input = read_all(reader)?;
+    let decoder = Decoder::with_sentinel(sentinel);
+    let body = input.strip_suffix(&[sentinel]).unwrap_or(&input);
+
+    let mut output = Vec::new();
+    let mut partial_frames = 0usize;
+    for (i, frame) in body.split(|&b| b == sentinel).enumerate() {
+        if frame.is_empty() {
+            continue;
+        }
+        let salvage = decoder.decode_frame_lossy(frame);
+        if let Some(err) = &salvage.error {
+            partial_frames += 1;
+            eprintln!("warning: frame {i} is partial ({err}), salvaged {} byte(s)", salvage.payload.len());
+        }
+        output.extend_from_slice(&salvage.payload);
+    }
+    writer.write_all(&output)?;
+
+    if partial_frames > 0 {
+        eprintln!("warning: {partial_frames} partial frame(s) salvaged");
+    }
+    Ok(())
+}
+
+/// Warn how many frames `--lenient` dropped, if any.
+fn report_lenient_drops(lenient: bool, decoder: &Decoder) {
+    if !lenient {
+        return;
+    }
+    let dropped = decoder.stats().map_or(0, |s| s.malformed_frames);
+    if dropped > 0 {
+        eprintln!(
+            "warning: dropped {dropped} malformed frame(s) ({} bytes)",
+            decoder.discarded_bytes()
+        );
+    }
+}
+
+/// Expand the given paths (glob patterns allowed) and chain them into a single
+/// byte source, falling back to `--input` and then stdin when no paths are
+/// given. Matched files are read in one globally sorted order; a pattern
+/// that matches nothing, an unreadable match, or a bad pattern is reported
+/// rather than silently skipped.
+fn open_input(paths: &[String], input: Option<&PathBuf>) -> Result<Box<dyn Read>, CobsError> {
+    if paths.is_empty() {
+        return match input {
+            Some(path) => Ok(Box::new(
+                File::open(path).map_err(|e| input_error(format!("{}: {e}", path.display())))?,
+            )),
+            None => Ok(Box::new(io::stdin())),
+        };
+    }
+
+    let mut files: Vec<PathBuf> = Vec::new();
+    for pattern in paths {
+        let entries =
+            glob::glob(pattern).map_err(|e| input_error(format!("invalid pattern `{pattern}`: {e}")))?;
+        let matched = files.len();
+        for entry in entries {
+            files.push(entry.map_err(|e| input_error(format!("{pattern}: {e}")))?);
+        }
+        if files.len() == matched {
+            return Err(input_error(format!("no files matched `{pattern}`")));
+        }
+    }
+
+    // Sort across every matched path so the files are read in one deterministic
+    // order, not merely sorted within each pattern.
+    files.sort();
+
+    let mut reader: Option<Box<dyn Read>> = None;
+    for path in files {
+        let file = File::open(&path).map_err(|e| input_error(format!("{}: {e}", path.display())))?;
+        reader = Some(match reader {
+            Some(prev) => Box::new(prev.chain(file)),
+            None => Box::new(file),
+        });
+    }
+    Ok(reader.unwrap_or_else(|| Box::new(io::empty())))
+}
+
+fn read_all(reader: &mut dyn Read) -> Result<Vec<u8>, CobsError> {
     let mut input = Vec::new();
-    io::stdin().read_to_end(&mut input).unwrap();
+    reader.read_to_end(&mut input)?;
+    Ok(input)
+}
 
-    match &cli.command {
-        Commands::Decode => {
-            let mut decoder = Decoder::<SENTINEL>::new();
-            let mut src = BytesMut::from(&input[..]);
-            let mut output = BytesMut::new();
-            while let Some(frame) = decoder.decode(&mut src).unwrap() {
-                output.extend_from_slice(&frame);
+/// Open `path` for writing (creating or truncating it), falling back to
+/// stdout when no path is given.
+fn open_output(path: Option<&PathBuf>) -> Result<Box<dyn Write>, CobsError> {
+    match path {
+        Some(path) => Ok(Box::new(
+            File::create(path).map_err(|e| input_error(format!("{}: {e}", path.display())))?,
+        )),
+        None => Ok(Box::new(io::stdout())),
+    }
+}
+
+/// Pull fixed-size chunks from the input, emitting each frame as soon as its
+/// sentinel boundary is seen and retaining only the unconsumed tail.
+fn stream_decode(
+    sentinel: u8,
+    flush_every_frame: bool,
+    stats: bool,
+    lenient: bool,
+    reader: &mut dyn Read,
+    out: &mut dyn Write,
+) -> Result<(), CobsError> {
+    let mut decoder = Decoder::with_sentinel(sentinel)
+        .with_stats(stats || lenient)
+        .with_resync(lenient);
+
+    let res = (|| {
+        // Bytes already drained from `src`; added to a buffer-relative error
+        // offset so diagnostics point at an absolute position in the stream
+        // rather than inside the current sliding window.
+        let mut consumed = 0usize;
+        let mut src = BytesMut::new();
+        let mut chunk = [0u8; CHUNK_SIZE];
+        loop {
+            let n = reader.read(&mut chunk)?;
+            if n == 0 {
+                break;
+            }
+            src.extend_from_slice(&chunk[..n]);
+            loop {
+                let before = src.len();
+                match decoder.decode(&mut src).map_err(|e| e.offset_by(consumed))? {
+                    Some(frame) => {
+                        consumed += before - src.len();
+                        out.write_all(&frame)?;
+                        if flush_every_frame {
+                            out.flush()?;
+                        }
+                    }
+                    None => break,
+                }
             }
-            io::stdout().write_all(&output).unwrap();
         }
-        Commands::Encode => {
-            let mut encoder = Encoder::<SENTINEL>::new();
-            let mut dst = BytesMut::new();
-            encoder.encode(input, &mut dst).unwrap();
-            io::stdout().write_all(&dst).unwrap();
+
+        // A partial trailing frame means the stream ended mid-frame; report it
+        // rather than silently dropping the unconsumed tail.
+        if let Some(frame) = decoder.decode_eof(&mut src).map_err(|e| e.offset_by(consumed))? {
+            out.write_all(&frame)?;
+            out.flush()?;
         }
+        Ok(())
+    })();
+
+    if stats {
+        report_stats(decoder.stats().copied().unwrap_or_default());
     }
+    report_lenient_drops(lenient, &decoder);
+    res
+}
 
-    io::stdout().flush().unwrap();
+/// Stuff a completed block (the non-zero run since the last delimiter) into the
+/// output in the sentinel transmission domain and reset it.
+fn emit_block<W: Write>(out: &mut W, block: &mut Vec<u8>, sentinel: u8) -> io::Result<()> {
+    let mut framed = Vec::with_capacity(block.len() + 1);
+    framed.push((block.len() as u8 + 1) ^ sentinel);
+    for &b in block.iter() {
+        framed.push(b ^ sentinel);
+    }
+    out.write_all(&framed)?;
+    block.clear();
+    Ok(())
+}
+
+/// Stream the input into a single COBS frame, stuffing incrementally so at most
+/// one code block (254 bytes) is held in memory regardless of input length.
+fn stream_encode(sentinel: u8, reader: &mut dyn Read, out: &mut dyn Write) -> Result<(), CobsError> {
+    let mut out = io::BufWriter::new(out);
+
+    let mut block: Vec<u8> = Vec::with_capacity(254);
+    let mut chunk = [0u8; CHUNK_SIZE];
+    loop {
+        let n = reader.read(&mut chunk)?;
+        if n == 0 {
+            break;
+        }
+        for &byte in &chunk[..n] {
+            if byte == 0 {
+                emit_block(&mut out, &mut block, sentinel)?;
+            } else {
+                block.push(byte);
+                if block.len() == 254 {
+                    emit_block(&mut out, &mut block, sentinel)?;
+                }
+            }
+        }
+    }
+
+    // Flush the trailing block and terminate the single frame with the sentinel.
+    emit_block(&mut out, &mut block, sentinel)?;
+    out.write_all(&[sentinel])?;
+    out.flush()?;
+    Ok(())
 }